@@ -0,0 +1,62 @@
+//! Measures time and heap allocations for highlighting a large Rust file,
+//! both on first parse and when a cached source is highlighted again.
+//! Only Rust-side allocations are counted; tree-sitter's own C allocations
+//! do not go through the global allocator.
+//!
+//! Run with `cargo bench --bench highlight`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use gitlogue::syntax::Highlighter;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const ITERATIONS: usize = 20;
+
+fn measure(label: &str, mut f: impl FnMut()) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed() / ITERATIONS as u32;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / ITERATIONS;
+    println!("{label:<24} {elapsed:>12?} {allocations:>10} allocs/iter");
+}
+
+fn main() {
+    let source = include_str!("../src/animation.rs").repeat(4);
+
+    let mut highlighter = Highlighter::new();
+    highlighter.set_language_from_path("large.rs");
+
+    // Give every call a distinct source so it has to parse
+    let mut edit = 0;
+    measure("highlight (changed)", || {
+        edit += 1;
+        let input = format!("{source}// edit {edit}\n");
+        std::hint::black_box(highlighter.highlight(&input));
+    });
+
+    measure("highlight (unchanged)", || {
+        std::hint::black_box(highlighter.highlight(&source));
+    });
+}
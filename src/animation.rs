@@ -1,44 +1,285 @@
 use std::cell::RefCell;
+use std::path::Path;
 use std::time::{Duration, Instant};
 
-use rand::Rng;
-use unicode_width::UnicodeWidthStr;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use unicode_width::UnicodeWidthChar;
 
-use crate::git::{CommitMetadata, DiffHunk, FileChange, FileStatus, LineChangeType};
-use crate::syntax::Highlighter;
+use crate::git::{
+    short_hash, CommitMetadata, DiffHunk, ExclusionReason, FileChange, FileStatus, LineChange,
+    LineChangeType,
+};
+use crate::narrator::{subject, FunNarrator, Narrator};
+use crate::syntax::{comment_delimiters, Highlighter};
+use crate::{FileOrder, TypingMode};
 
 // Duration multipliers relative to typing speed
-const CURSOR_MOVE_PAUSE: f64 = 0.5; // Cursor movement between lines (base speed)
 const CURSOR_MOVE_SHORT_MULTIPLIER: f64 = 1.0; // Speed for short distances (1-50 lines)
 const CURSOR_MOVE_MEDIUM_MULTIPLIER: f64 = 0.3; // Speed for medium distances (51-200 lines)
 const CURSOR_MOVE_LONG_MULTIPLIER: f64 = 0.05; // Speed for long distances (201+ lines)
 const MAX_SCROLL_STEPS: usize = 60; // Maximum animation steps for any scroll distance
 const MIN_LOG_STEPS: usize = 50; // Minimum steps for logarithmic scaling (aligned with SHORT threshold)
 const LOG_SCALE_FACTOR: f64 = 8.0; // Scaling factor for logarithmic step calculation
-const DELETE_LINE_PAUSE: f64 = 10.0; // After deleting a line
-const INSERT_LINE_PAUSE: f64 = 6.7; // After inserting a line
-const HUNK_PAUSE: f64 = 50.0; // Between hunks
-const CHECKOUT_PAUSE: f64 = 16.7; // After git checkout command
-const CHECKOUT_OUTPUT_PAUSE: f64 = 33.3; // After git checkout output
-const OPEN_FILE_FIRST_PAUSE: f64 = 33.3; // Before opening first file
-const OPEN_FILE_PAUSE: f64 = 50.0; // Before opening subsequent files
-const OPEN_CMD_PAUSE: f64 = 16.7; // After open command
-const FILE_SWITCH_PAUSE: f64 = 26.7; // After switching file
-const GIT_ADD_PAUSE: f64 = 33.3; // Before git add
-const GIT_ADD_CMD_PAUSE: f64 = 16.7; // After git add command
-const GIT_COMMIT_PAUSE: f64 = 26.7; // After git commit command
-const COMMIT_OUTPUT_PAUSE: f64 = 33.3; // After commit output
-const GIT_PUSH_PAUSE: f64 = 16.7; // After git push command
-const PUSH_OUTPUT_PAUSE: f64 = 10.0; // Between push output lines
-const PUSH_FINAL_PAUSE: f64 = 66.7; // After final push output
+const MAX_COLUMN_STEPS: usize = 16; // Maximum animation steps when moving along a line
+const WORD_PAUSE: f64 = 3.0; // After typing a word in word typing mode
+const PASTE_BURST_LINES: usize = 20; // Lines pasted per step in files above --fast-above
+const HUNK_PAUSE_REFERENCE_LINES: f64 = 10.0; // Changed lines in a hunk that get exactly the hunk pause
+const HUNK_PAUSE_MIN_FACTOR: f64 = 0.3; // Floor so one-line hunks still get a visible break
+const HUNK_PAUSE_MAX_FACTOR: f64 = 2.5; // Cap for very large hunks
+
+/// Editor text width until the editor pane reports its own: an 80-column pane
+/// less the border and padding (4), a 3-digit line number and its space (4)
+/// and the separator (2), as measured by `EditorPane::text_width`
+const DEFAULT_TEXT_WIDTH: usize = 80 - (4 + 3 + 1 + 2);
+
+/// Default number of terminal lines kept before the oldest are discarded
+pub const DEFAULT_MAX_TERMINAL_LINES: usize = 1000;
+
+/// Default time between cursor blinks in milliseconds
+pub const DEFAULT_CURSOR_BLINK_MS: u64 = 500;
+
+/// Default and allowed range for the render frame rate
+const DEFAULT_FPS: u64 = 120;
+const MIN_FPS: u64 = 5;
+const MAX_FPS: u64 = 240;
+
+/// Prefix of terminal lines that hold a typed command (rendered as commands, not output)
+pub const TERMINAL_PROMPT: &str = "~ ";
+
+/// Columns kept visible to the right of the cursor when scrolling horizontally
+const H_SCROLL_MARGIN: usize = 4;
+
+// How long the editor takes to ease to a new scroll position
+const SMOOTH_SCROLL_MS: u64 = 150;
+
+/// Default number of changed lines typed from an excluded lock file or huge
+/// diff before the rest is cut short; 0 skips them
+pub const DEFAULT_EXCLUDED_PREVIEW_LINES: usize = 0;
+
+/// Number of columns between tab stops in the editor
+pub const DEFAULT_TAB_WIDTH: usize = 4;
+
+/// Split text into words, each carrying its trailing whitespace
+fn split_words(chars: impl Iterator<Item = char>) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut in_trailing_space = false;
+    for ch in chars {
+        let is_space = ch.is_whitespace();
+        match words.last_mut() {
+            Some(word) if is_space || !in_trailing_space => word.push(ch),
+            _ => words.push(ch.to_string()),
+        }
+        in_trailing_space = is_space;
+    }
+    words
+}
+
+/// Share of the hunk pause taken after a hunk with `changed_lines` added or
+/// deleted lines: bigger hunks get a longer breath, within bounds
+fn hunk_pause_factor(changed_lines: usize) -> f64 {
+    (changed_lines as f64 / HUNK_PAUSE_REFERENCE_LINES)
+        .clamp(HUNK_PAUSE_MIN_FACTOR, HUNK_PAUSE_MAX_FACTOR)
+}
+
+/// The hunks up to the first `max_changes` added or deleted lines, cutting
+/// the last hunk short if needed, and how many changed lines were left out
+fn truncate_hunks(hunks: &[DiffHunk], max_changes: usize) -> (Vec<DiffHunk>, usize) {
+    let mut kept = Vec::new();
+    let mut changes = 0;
+    let mut remaining = 0;
+    for hunk in hunks {
+        let mut lines = Vec::new();
+        for line in &hunk.lines {
+            let is_change = !matches!(line.change_type, LineChangeType::Context);
+            if changes == max_changes {
+                remaining += usize::from(is_change);
+                continue;
+            }
+            changes += usize::from(is_change);
+            lines.push(line.clone());
+        }
+        if lines
+            .iter()
+            .any(|line| !matches!(line.change_type, LineChangeType::Context))
+        {
+            kept.push(DiffHunk {
+                lines,
+                ..hunk.clone()
+            });
+        }
+    }
+    (kept, remaining)
+}
+
+/// Turn CRLF line endings into LF, matching the lines `str::lines` yields
+fn normalize_line_endings(content: String) -> String {
+    if content.contains("\r\n") {
+        content.replace("\r\n", "\n")
+    } else {
+        content
+    }
+}
+
+/// How a modified line differs from its original: the number of characters
+/// shared at the start and end, and how many characters in between were removed
+#[derive(Debug, Clone, PartialEq)]
+struct LineEdit {
+    prefix: usize,
+    suffix: usize,
+    removed: usize,
+}
+
+/// Compare an old and new line and, if they share enough of a common prefix
+/// and suffix beyond their indentation, describe the change as a `LineEdit`
+fn line_edit(old: &str, new: &str) -> Option<LineEdit> {
+    let old: Vec<char> = old.trim_end_matches(['\n', '\r']).chars().collect();
+    let new: Vec<char> = new.trim_end_matches(['\n', '\r']).chars().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let suffix = old
+        .iter()
+        .rev()
+        .zip(new.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    // Shared indentation alone does not make two lines similar
+    let indentation = new.iter().take_while(|c| c.is_whitespace()).count();
+    let shared = (prefix + suffix).saturating_sub(indentation);
+    let longest = old.len().max(new.len()).saturating_sub(indentation);
+    if shared == 0 || shared * 2 < longest {
+        return None;
+    }
+
+    Some(LineEdit {
+        prefix,
+        suffix,
+        removed: old.len() - prefix - suffix,
+    })
+}
+
+/// Display width of `ch` drawn at display column `column`, with tabs
+/// expanding to the next tab stop
+pub fn char_display_width(ch: char, column: usize, tab_width: usize) -> usize {
+    if ch == '\t' {
+        let tab_width = tab_width.max(1);
+        tab_width - column % tab_width
+    } else {
+        ch.width().unwrap_or(0)
+    }
+}
+
+/// Display width of `text`, with tabs expanded
+pub fn text_display_width(text: &str, tab_width: usize) -> usize {
+    text.chars().fold(0, |column, ch| {
+        column + char_display_width(ch, column, tab_width)
+    })
+}
+
+/// Pause lengths, as multiples of the typing speed
+#[derive(Debug, Clone, PartialEq)]
+pub struct PacingConfig {
+    /// Global multiplier applied to every pause
+    pub scale: f64,
+    /// Cursor movement between lines (base speed)
+    pub cursor_move: f64,
+    /// Before deleting a mistyped character
+    pub typo_notice: f64,
+    /// After deleting a line
+    pub delete_line: f64,
+    /// After inserting a line
+    pub insert_line: f64,
+    /// Between hunks
+    pub hunk: f64,
+    /// After git checkout command
+    pub checkout: f64,
+    /// After git checkout output
+    pub checkout_output: f64,
+    /// Before opening first file
+    pub open_file_first: f64,
+    /// Before opening subsequent files
+    pub open_file: f64,
+    /// After open command
+    pub open_cmd: f64,
+    /// After switching file
+    pub file_switch: f64,
+    /// Before git add
+    pub git_add: f64,
+    /// After git add command
+    pub git_add_cmd: f64,
+    /// After git commit command
+    pub git_commit: f64,
+    /// After commit output
+    pub commit_output: f64,
+    /// After git push command
+    pub git_push: f64,
+    /// Between push output lines
+    pub push_output: f64,
+    /// After final push output
+    pub push_final: f64,
+    /// After the "Open File..." dialog appears
+    pub open_dialog: f64,
+}
+
+impl Default for PacingConfig {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            cursor_move: 0.5,
+            typo_notice: 8.0,
+            delete_line: 10.0,
+            insert_line: 6.7,
+            hunk: 50.0,
+            checkout: 16.7,
+            checkout_output: 33.3,
+            open_file_first: 33.3,
+            open_file: 50.0,
+            open_cmd: 16.7,
+            file_switch: 26.7,
+            git_add: 33.3,
+            git_add_cmd: 16.7,
+            git_commit: 26.7,
+            commit_output: 33.3,
+            git_push: 16.7,
+            push_output: 10.0,
+            push_final: 66.7,
+            open_dialog: 5.0,
+        }
+    }
+}
+
+/// Where a buffer line came from, shown in the editor's diff gutter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineOrigin {
+    /// Present before the current file's changes were applied
+    #[default]
+    Unchanged,
+    /// Inserted by the animation
+    Added,
+    /// An existing line edited in place
+    Modified,
+}
 
 /// Represents the current state of the editor buffer
 #[derive(Debug, Clone)]
 pub struct EditorBuffer {
     pub lines: Vec<String>,
+    /// Origin of each entry in `lines`
+    pub line_origins: Vec<LineOrigin>,
     pub cursor_line: usize,
     pub cursor_col: usize,
     pub scroll_offset: usize,
+    /// Offset actually shown, easing toward `scroll_offset` after a jump
+    pub display_scroll: f64,
+    /// Where the current scroll animation started and how far along it is
+    scroll_from: f64,
+    scroll_elapsed_ms: Option<u64>,
+    /// First visible display column when line wrapping is disabled
+    pub h_scroll_offset: usize,
     pub cached_highlights: Vec<crate::syntax::HighlightSpan>,
     /// Pre-calculated highlights for old and new content
     pub old_highlights: Vec<crate::syntax::HighlightSpan>,
@@ -46,7 +287,8 @@ pub struct EditorBuffer {
     /// Store old and new content for byte offset calculation
     pub old_content_lines: Vec<String>,
     pub new_content_lines: Vec<String>,
-    /// Pre-calculated byte offsets for each line (handles CRLF correctly)
+    /// Pre-calculated byte offsets for each line, counted with `\n` line
+    /// endings as the buffer holds lines without any `\r`
     pub old_content_line_offsets: Vec<usize>,
     pub new_content_line_offsets: Vec<usize>,
 }
@@ -55,9 +297,14 @@ impl EditorBuffer {
     pub fn new() -> Self {
         Self {
             lines: vec![String::new()],
+            line_origins: vec![LineOrigin::Unchanged],
             cursor_line: 0,
             cursor_col: 0,
             scroll_offset: 0,
+            display_scroll: 0.0,
+            scroll_from: 0.0,
+            scroll_elapsed_ms: None,
+            h_scroll_offset: 0,
             cached_highlights: Vec::new(),
             old_highlights: Vec::new(),
             new_highlights: Vec::new(),
@@ -69,17 +316,20 @@ impl EditorBuffer {
     }
 
     pub fn from_content(content: &str) -> Self {
-        let lines: Vec<String> = if content.is_empty() {
-            vec![String::new()]
-        } else {
-            content.lines().map(|s| s.to_string()).collect()
-        };
+        // An empty file has no lines at all, so typing a new file does not
+        // leave a stray blank line after the last one
+        let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
 
         Self {
+            line_origins: vec![LineOrigin::Unchanged; lines.len()],
             lines,
             cursor_line: 0,
             cursor_col: 0,
             scroll_offset: 0,
+            display_scroll: 0.0,
+            scroll_from: 0.0,
+            scroll_elapsed_ms: None,
+            h_scroll_offset: 0,
             cached_highlights: Vec::new(),
             old_highlights: Vec::new(),
             new_highlights: Vec::new(),
@@ -93,7 +343,9 @@ impl EditorBuffer {
     pub fn insert_char(&mut self, line: usize, col: usize, ch: char) {
         if line >= self.lines.len() {
             self.lines.resize(line + 1, String::new());
+            self.line_origins.resize(line + 1, LineOrigin::Added);
         }
+        self.mark_modified(line);
         let line_str = &mut self.lines[line];
 
         // Convert char index to byte index
@@ -106,35 +358,88 @@ impl EditorBuffer {
         line_str.insert(byte_idx, ch);
     }
 
+    pub fn delete_char(&mut self, line: usize, col: usize) {
+        if let Some(line_str) = self.lines.get_mut(line) {
+            if let Some((byte_idx, _)) = line_str.char_indices().nth(col) {
+                line_str.remove(byte_idx);
+            }
+        }
+        self.mark_modified(line);
+    }
+
     pub fn insert_line(&mut self, line: usize, content: String) {
         if line > self.lines.len() {
             self.lines.resize(line, String::new());
+            self.line_origins.resize(line, LineOrigin::Added);
         }
         self.lines.insert(line, content);
+        self.line_origins.insert(line, LineOrigin::Added);
     }
 
     pub fn delete_line(&mut self, line: usize) {
         if line < self.lines.len() {
             self.lines.remove(line);
+            self.line_origins.remove(line);
         }
         if self.lines.is_empty() {
             self.lines.push(String::new());
+            self.line_origins.push(LineOrigin::Unchanged);
+        }
+    }
+
+    /// First line to render: the eased scroll position, kept inside the buffer
+    pub fn display_offset(&self) -> usize {
+        (self.display_scroll.round() as usize).min(self.lines.len().saturating_sub(1))
+    }
+
+    /// Jump straight to the scroll target, cancelling any animation
+    fn settle_scroll(&mut self) {
+        self.display_scroll = self.scroll_offset as f64;
+        self.scroll_elapsed_ms = None;
+    }
+
+    pub fn line_origin(&self, line: usize) -> LineOrigin {
+        self.line_origins.get(line).copied().unwrap_or_default()
+    }
+
+    // Typing into an existing line turns it into an in-place edit; lines
+    // that were added stay added
+    fn mark_modified(&mut self, line: usize) {
+        if let Some(origin @ LineOrigin::Unchanged) = self.line_origins.get_mut(line) {
+            *origin = LineOrigin::Modified;
         }
     }
 }
 
 /// Individual animation step
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum AnimationStep {
     InsertChar {
         line: usize,
         col: usize,
         ch: char,
     },
+    /// Delete the character at `col` (used to correct typos)
+    DeleteChar {
+        line: usize,
+        col: usize,
+    },
+    /// Insert a whole word (and any trailing whitespace) at once
+    InsertWord {
+        line: usize,
+        col: usize,
+        text: String,
+    },
     InsertLine {
         line: usize,
         content: String,
     },
+    /// Insert several whole lines at once, starting at `line`
+    PasteLines {
+        line: usize,
+        lines: Vec<String>,
+    },
     DeleteLine {
         line: usize,
     },
@@ -190,13 +495,53 @@ pub struct AnimationEngine {
     speed_ms: u64,
     next_step_delay: u64,
     pause_until: Option<Instant>,
+    /// When playback was suspended by the user (None while running)
+    suspended_at: Option<Instant>,
     pub cursor_visible: bool,
     cursor_blink_timer: Instant,
+    /// When the scroll animation was last advanced
+    scroll_clock: Instant,
+    /// Time between cursor blinks in milliseconds (0 keeps the cursor visible)
+    cursor_blink_ms: u64,
     viewport_height: usize,
     content_width: usize,
+    /// Wrap long editor lines (otherwise scroll horizontally)
+    line_wrap: bool,
+    /// Columns between tab stops when rendering tabs
+    tab_width: usize,
+    /// Whether additions are typed character by character or word by word
+    typing_mode: TypingMode,
+    /// Chance (0.0-1.0) of mistyping a character and correcting it
+    typo_probability: f64,
+    /// Type the leading whitespace of added lines instead of pasting it
+    type_indentation: bool,
+    /// Type a comment naming the commit at the top of each file first
+    comment_banner: bool,
+    /// Animate commits being undone instead of written
+    reverse: bool,
+    /// Pause lengths between animation phases
+    pacing: PacingConfig,
+    /// Play the time-travel intro before each commit
+    intro: bool,
+    /// Erase deleted files and edit renamed ones in the editor
+    animate_deletes: bool,
+    /// Changed lines typed from an excluded file (0 only names the file)
+    excluded_preview_lines: usize,
+    /// Files animated per commit before the rest are summed up in one line
+    max_files: Option<usize>,
+    /// Files with more added lines than this paste them in bursts
+    fast_above: Option<usize>,
+    /// Whether the file whose steps are being generated pastes its additions
+    pasting: bool,
+    /// Order the files of a commit are animated in, matching the file tree
+    file_order: FileOrder,
+    /// Writes the terminal output around each commit (`--flavor`)
+    narrator: Box<dyn Narrator>,
     pub current_file_index: usize,
     pub current_file_path: Option<String>,
     pub terminal_lines: Vec<String>,
+    /// Maximum number of terminal lines retained (oldest lines are trimmed first)
+    max_terminal_lines: usize,
     pub active_pane: ActivePane,
     pub highlighter: RefCell<Highlighter>,
     /// Track cumulative line offset from old_content (insertions - deletions)
@@ -216,11 +561,18 @@ pub struct AnimationEngine {
     current_metadata: Option<CommitMetadata>,
     /// Pending metadata to be applied on ResetState
     pending_metadata: Option<CommitMetadata>,
+    /// Random source for typing speed variation
+    rng: StdRng,
 }
 
 impl AnimationEngine {
     pub fn new(speed_ms: u64) -> Self {
-        let target_fps: u64 = 120;
+        Self::with_fps(speed_ms, DEFAULT_FPS)
+    }
+
+    /// Create an engine rendering at `fps` frames per second (clamped to 5-240)
+    pub fn with_fps(speed_ms: u64, fps: u64) -> Self {
+        let target_fps = fps.clamp(MIN_FPS, MAX_FPS);
         let frame_interval_ms = 1000 / target_fps;
         let now = Instant::now();
         Self {
@@ -232,13 +584,35 @@ impl AnimationEngine {
             speed_ms,
             next_step_delay: speed_ms,
             pause_until: None,
+            suspended_at: None,
             cursor_visible: true,
             cursor_blink_timer: now,
-            viewport_height: 20, // Default, will be updated from UI
-            content_width: 80,   // Default, will be updated from UI
+            scroll_clock: now,
+            cursor_blink_ms: DEFAULT_CURSOR_BLINK_MS,
+            viewport_height: 20,               // Default, will be updated from UI
+            content_width: DEFAULT_TEXT_WIDTH, // Updated by the editor pane
+            line_wrap: true,
+            tab_width: DEFAULT_TAB_WIDTH,
+            typing_mode: TypingMode::Char,
+            typo_probability: 0.0,
+            type_indentation: false,
+            comment_banner: false,
+            reverse: false,
+            pacing: PacingConfig::default(),
+            intro: true,
+            animate_deletes: false,
+            excluded_preview_lines: DEFAULT_EXCLUDED_PREVIEW_LINES,
+            max_files: None,
+            fast_above: None,
+            pasting: false,
+            file_order: FileOrder::default(),
+            narrator: Box::new(FunNarrator {
+                glyphs: Default::default(),
+            }),
             current_file_index: 0,
             current_file_path: None,
             terminal_lines: Vec::new(),
+            max_terminal_lines: DEFAULT_MAX_TERMINAL_LINES,
             active_pane: ActivePane::Terminal, // Start with terminal (git checkout)
             highlighter: RefCell::new(Highlighter::new()),
             line_offset: 0,
@@ -249,15 +623,169 @@ impl AnimationEngine {
             dialog_typing_text: String::new(),
             current_metadata: None,
             pending_metadata: None,
+            rng: StdRng::from_os_rng(),
         }
     }
 
+    /// Change typing speed mid-playback, rescaling the delay of the pending step
+    pub fn set_speed(&mut self, speed_ms: u64) {
+        let speed_ms = speed_ms.max(1);
+        self.next_step_delay = (self.next_step_delay * speed_ms)
+            .checked_div(self.speed_ms)
+            .unwrap_or(speed_ms);
+        self.speed_ms = speed_ms;
+    }
+
+    /// Seed typing speed variation so timing is reproducible
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn speed_ms(&self) -> u64 {
+        self.speed_ms
+    }
+
+    pub fn set_max_terminal_lines(&mut self, max_lines: usize) {
+        self.max_terminal_lines = max_lines.max(1);
+        self.trim_terminal_lines();
+    }
+
+    pub fn frame_interval(&self) -> Duration {
+        Duration::from_millis(self.frame_interval_ms)
+    }
+
+    /// Resizing re-centers the cursor, so the scroll position never
+    /// points past what the new viewport can show
     pub fn set_viewport_height(&mut self, height: usize) {
-        self.viewport_height = height;
+        if height != self.viewport_height {
+            self.viewport_height = height;
+            self.update_scroll();
+        }
     }
 
+    /// Width of the editor's text area, as measured by the editor pane
     pub fn set_content_width(&mut self, width: usize) {
-        self.content_width = width;
+        if width != self.content_width {
+            self.content_width = width;
+            self.update_scroll();
+        }
+    }
+
+    /// Wrap long editor lines, or scroll horizontally to follow the cursor
+    pub fn set_line_wrap(&mut self, wrap: bool) {
+        self.line_wrap = wrap;
+        self.buffer.h_scroll_offset = 0;
+    }
+
+    pub fn line_wrap(&self) -> bool {
+        self.line_wrap
+    }
+
+    /// Takes effect from the next loaded commit
+    pub fn set_typing_mode(&mut self, typing_mode: TypingMode) {
+        self.typing_mode = typing_mode;
+    }
+
+    /// Occasionally mistype a character, then delete and retype it (char typing mode only).
+    /// Takes effect from the next loaded commit
+    pub fn set_typo_probability(&mut self, probability: f64) {
+        self.typo_probability = probability.clamp(0.0, 1.0);
+    }
+
+    /// Type the indentation of added lines key by key, rather than
+    /// inserting it in one step before typing the rest of the line
+    pub fn set_type_indentation(&mut self, type_indentation: bool) {
+        self.type_indentation = type_indentation;
+    }
+
+    /// Briefly type a comment naming the commit at the top of each file
+    /// before animating it. Takes effect from the next loaded commit
+    pub fn set_comment_banner(&mut self, comment_banner: bool) {
+        self.comment_banner = comment_banner;
+    }
+
+    /// Play commits backwards, un-writing their changes. Takes effect from the next loaded commit
+    pub fn set_reverse(&mut self, reverse: bool) {
+        self.reverse = reverse;
+    }
+
+    /// Blink the cursor every `blink_ms` milliseconds, or never if 0
+    pub fn set_cursor_blink(&mut self, blink_ms: u64) {
+        self.cursor_blink_ms = blink_ms;
+        if blink_ms == 0 {
+            self.cursor_visible = true;
+        }
+    }
+
+    /// Skip the time-travel intro when disabled. Takes effect from the next loaded commit
+    pub fn set_intro(&mut self, intro: bool) {
+        self.intro = intro;
+    }
+
+    /// Open deleted files to erase them, and renamed files with changes to
+    /// edit them, instead of only running `rm`/`mv`
+    pub fn set_animate_deletes(&mut self, animate_deletes: bool) {
+        self.animate_deletes = animate_deletes;
+    }
+
+    /// Type the first `lines` changed lines of excluded lock files and huge
+    /// diffs before moving on; 0 skips them entirely. Files excluded by the
+    /// user's ignore rules are always skipped.
+    pub fn set_excluded_preview(&mut self, lines: usize) {
+        self.excluded_preview_lines = lines;
+    }
+
+    /// Animate at most `max_files` files of each commit, in file tree order,
+    /// then name how many were left out. Takes effect from the next loaded commit
+    pub fn set_max_files(&mut self, max_files: Option<usize>) {
+        self.max_files = max_files;
+    }
+
+    /// Paste the additions of files with more than `lines` added lines in
+    /// bursts of `PASTE_BURST_LINES`. Takes effect from the next loaded commit
+    pub fn set_fast_above(&mut self, lines: Option<usize>) {
+        self.fast_above = lines;
+    }
+
+    pub fn max_files(&self) -> Option<usize> {
+        self.max_files
+    }
+
+    /// Takes effect from the next loaded commit
+    pub fn set_file_order(&mut self, file_order: FileOrder) {
+        self.file_order = file_order;
+    }
+
+    pub fn file_order(&self) -> FileOrder {
+        self.file_order
+    }
+
+    /// Change the terminal output printed around each commit
+    pub fn set_narrator(&mut self, narrator: Box<dyn Narrator>) {
+        self.narrator = narrator;
+    }
+
+    /// Takes effect from the next loaded commit
+    pub fn set_pacing(&mut self, pacing: PacingConfig) {
+        self.pacing = pacing;
+    }
+
+    /// Length of a pause of `multiplier` keystrokes, after global scaling
+    fn pause_ms(&self, multiplier: f64) -> u64 {
+        (self.speed_ms as f64 * multiplier * self.pacing.scale) as u64
+    }
+
+    pub fn set_tab_width(&mut self, tab_width: usize) {
+        self.tab_width = tab_width.max(1);
+    }
+
+    pub fn tab_width(&self) -> usize {
+        self.tab_width
+    }
+
+    /// Steps planned for the loaded commit, in playback order
+    pub fn steps(&self) -> &[AnimationStep] {
+        &self.steps
     }
 
     /// Get the current metadata being displayed
@@ -265,8 +793,11 @@ impl AnimationEngine {
         self.current_metadata.as_ref()
     }
 
+    /// Byte offset where each line starts, followed by one entry at the end
+    /// of the content, so a line past the last one maps to no highlights
+    /// whether or not the file ends in a newline
     fn calculate_line_offsets(content: &str) -> Vec<usize> {
-        std::iter::once(0)
+        let mut offsets: Vec<usize> = std::iter::once(0)
             .chain(content.bytes().enumerate().filter_map(|(i, b)| {
                 if b == b'\n' {
                     Some(i + 1)
@@ -274,7 +805,33 @@ impl AnimationEngine {
                     None
                 }
             }))
-            .collect()
+            .collect();
+        // A final newline already ends the table at the end of the content
+        if !content.is_empty() && !content.ends_with('\n') {
+            offsets.push(content.len());
+        }
+        offsets
+    }
+
+    /// The command typed at the last terminal prompt still on screen
+    pub fn current_command(&self) -> Option<&str> {
+        self.terminal_lines
+            .iter()
+            .rev()
+            .find_map(|line| line.strip_prefix(TERMINAL_PROMPT))
+    }
+
+    /// Append a terminal line, discarding the oldest lines beyond the cap
+    fn push_terminal_line(&mut self, line: String) {
+        self.terminal_lines.push(line);
+        self.trim_terminal_lines();
+    }
+
+    fn trim_terminal_lines(&mut self) {
+        if self.terminal_lines.len() > self.max_terminal_lines {
+            let excess = self.terminal_lines.len() - self.max_terminal_lines;
+            self.terminal_lines.drain(..excess);
+        }
     }
 
     /// Add a terminal command with typing animation
@@ -287,6 +844,14 @@ impl AnimationEngine {
 
     /// Load a commit and generate animation steps
     pub fn load_commit(&mut self, metadata: &CommitMetadata) {
+        let reversed;
+        let metadata = if self.reverse {
+            reversed = metadata.reversed();
+            &reversed
+        } else {
+            metadata
+        };
+
         // Store pending metadata to be applied on ResetState
         self.pending_metadata = Some(metadata.clone());
 
@@ -295,50 +860,61 @@ impl AnimationEngine {
         self.state = AnimationState::Playing;
         self.last_update = Instant::now();
         self.pause_until = None;
+        self.suspended_at = None;
 
-        // Time travel to commit date
-        let parent_hash = format!("{}^", &metadata.hash[..7]);
-        let datetime_str = metadata.date.format("%Y-%m-%d %H:%M:%S").to_string();
-        self.add_terminal_command(&format!("time-travel {}", datetime_str));
-        self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * CHECKOUT_PAUSE) as u64,
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: "⚡ Initializing temporal displacement field...".to_string(),
-        });
-        self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * CHECKOUT_OUTPUT_PAUSE * 0.5) as u64,
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: "✨ Warping through spacetime...".to_string(),
-        });
-        self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * CHECKOUT_OUTPUT_PAUSE * 0.5) as u64,
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: format!("🕰️  Arrived at {}", datetime_str),
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: format!(
-                "📍 Location: commit {} by {}",
-                &metadata.hash[..7],
-                metadata.author
-            ),
-        });
-        self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * CHECKOUT_OUTPUT_PAUSE) as u64,
-        });
+        if self.intro {
+            self.add_time_travel_steps(metadata);
+        } else {
+            // Single status line instead of the full intro
+            self.steps.push(AnimationStep::TerminalOutput {
+                text: self.narrator.summary(metadata),
+            });
+        }
 
-        // Apply new metadata after time-travel animation
+        // Apply new metadata after the intro
         self.steps.push(AnimationStep::ResetState);
 
-        // Sort file changes to match FileTree display order (directory -> filename)
-        let sorted_indices = metadata.sorted_file_indices();
+        let banner = self.comment_banner.then(|| self.narrator.banner(metadata));
 
-        // Process all file changes in sorted order
-        for &index in &sorted_indices {
+        // Sort file changes to match the FileTree display order
+        let sorted_indices = self.file_order.file_indices(metadata);
+        let animated = self
+            .max_files
+            .unwrap_or(usize::MAX)
+            .min(sorted_indices.len());
+
+        // Process file changes in sorted order, up to the cap
+        for &index in &sorted_indices[..animated] {
             let change = &metadata.changes[index];
             match (change.is_excluded, &change.status) {
+                // Type the start of a lock file or huge diff, then cut it short
+                (true, _)
+                    if self.excluded_preview_lines > 0
+                        && change
+                            .exclusion_reason
+                            .is_some_and(ExclusionReason::allows_preview)
+                        && !change.is_binary
+                        && !change.hunks.is_empty() =>
+                {
+                    let (hunks, remaining) =
+                        truncate_hunks(&change.hunks, self.excluded_preview_lines);
+                    self.add_open_file_steps(index, change, banner.as_deref());
+                    self.generate_steps_for_file(&FileChange {
+                        hunks,
+                        ..change.clone()
+                    });
+                    if remaining > 0 {
+                        self.steps.push(AnimationStep::Pause {
+                            duration_ms: self.pause_ms(self.pacing.git_add),
+                        });
+                        self.steps.push(AnimationStep::TerminalOutput {
+                            text: self.narrator.truncated(&change.path, remaining),
+                        });
+                    }
+                    self.steps.push(AnimationStep::Pause {
+                        duration_ms: self.pause_ms(self.pacing.open_cmd),
+                    });
+                }
                 // Skip excluded files (lock files and generated files)
                 (true, _) => {
                     // Switch to the excluded file to show in file tree
@@ -352,18 +928,53 @@ impl AnimationEngine {
                     });
 
                     self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * OPEN_FILE_PAUSE) as u64,
+                        duration_ms: self.pause_ms(self.pacing.open_file),
                     });
                     let reason = change
                         .exclusion_reason
-                        .as_deref()
-                        .unwrap_or("excluded file");
+                        .map_or("excluded file".to_string(), |reason| reason.to_string());
                     self.steps.push(AnimationStep::TerminalOutput {
-                        text: format!("📦 {} (skipped - {})", change.path, reason),
+                        text: self.narrator.skipped(&change.path, &reason),
+                    });
+                    self.steps.push(AnimationStep::Pause {
+                        duration_ms: self.pause_ms(self.pacing.open_cmd),
+                    });
+                }
+                // Erase the file's contents before removing it
+                (false, FileStatus::Deleted)
+                    if self.animate_deletes && !change.hunks.is_empty() =>
+                {
+                    self.add_open_file_steps(index, change, banner.as_deref());
+                    self.generate_steps_for_file(change);
+                    self.steps.push(AnimationStep::Pause {
+                        duration_ms: self.pause_ms(self.pacing.git_add),
+                    });
+                    self.add_terminal_command(&format!("rm {}", change.path));
+                    self.steps.push(AnimationStep::Pause {
+                        duration_ms: self.pause_ms(self.pacing.git_add_cmd),
+                    });
+                    self.add_stage_command(&change.path);
+                }
+                // Move the file, then edit it under its new path
+                (false, FileStatus::Renamed)
+                    if self.animate_deletes
+                        && !change.hunks.is_empty()
+                        && change.old_path.is_some() =>
+                {
+                    let old_path = change.old_path.as_deref().unwrap_or_default();
+                    self.steps.push(AnimationStep::Pause {
+                        duration_ms: self.pause_ms(self.pacing.git_add),
+                    });
+                    self.add_terminal_command(&format!("mv {} {}", old_path, change.path));
+                    self.steps.push(AnimationStep::Pause {
+                        duration_ms: self.pause_ms(self.pacing.git_add_cmd),
                     });
+                    self.add_open_file_steps(index, change, banner.as_deref());
+                    self.generate_steps_for_file(change);
                     self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * OPEN_CMD_PAUSE) as u64,
+                        duration_ms: self.pause_ms(self.pacing.git_add),
                     });
+                    self.add_stage_command(&change.path);
                 }
                 // For deleted files, skip editor animation and only run rm + git add
                 (false, FileStatus::Deleted) => {
@@ -377,16 +988,13 @@ impl AnimationEngine {
                     });
 
                     self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * GIT_ADD_PAUSE) as u64,
+                        duration_ms: self.pause_ms(self.pacing.git_add),
                     });
                     self.add_terminal_command(&format!("rm {}", change.path));
                     self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * GIT_ADD_CMD_PAUSE) as u64,
-                    });
-                    self.add_terminal_command(&format!("git add {}", change.path));
-                    self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * GIT_ADD_CMD_PAUSE) as u64,
+                        duration_ms: self.pause_ms(self.pacing.git_add_cmd),
                     });
+                    self.add_stage_command(&change.path);
                 }
                 // For renamed/moved files, skip editor animation and only run mv + git add
                 (false, FileStatus::Renamed) => {
@@ -401,142 +1009,232 @@ impl AnimationEngine {
                     });
 
                     self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * GIT_ADD_PAUSE) as u64,
+                        duration_ms: self.pause_ms(self.pacing.git_add),
                     });
                     if let Some(old_path) = &change.old_path {
                         self.add_terminal_command(&format!("mv {} {}", old_path, change.path));
                         self.steps.push(AnimationStep::Pause {
-                            duration_ms: (self.speed_ms as f64 * GIT_ADD_CMD_PAUSE) as u64,
+                            duration_ms: self.pause_ms(self.pacing.git_add_cmd),
                         });
                     }
-                    self.add_terminal_command(&format!("git add {}", change.path));
+                    self.add_stage_command(&change.path);
+                }
+                // Binary files have nothing to type, so open a placeholder
+                // in their place and stage them
+                (false, _) if change.is_binary => {
+                    let placeholder = Some(self.narrator.binary(change.size));
+                    self.add_open_file_steps(
+                        index,
+                        &FileChange {
+                            old_content: placeholder.clone(),
+                            new_content: placeholder,
+                            ..change.clone()
+                        },
+                        None,
+                    );
                     self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * GIT_ADD_CMD_PAUSE) as u64,
+                        duration_ms: self.pause_ms(self.pacing.git_add),
                     });
+                    self.add_stage_command(&change.path);
                 }
                 // Normal files (Added, Modified, etc.) - full editor animation
                 (false, _) => {
-                    // Open file in editor
-                    if index == 0 {
-                        self.steps.push(AnimationStep::Pause {
-                            duration_ms: (self.speed_ms as f64 * OPEN_FILE_FIRST_PAUSE) as u64,
-                        });
-                    } else {
-                        self.steps.push(AnimationStep::Pause {
-                            duration_ms: (self.speed_ms as f64 * OPEN_FILE_PAUSE) as u64,
-                        });
-                    }
-                    // Show "Open File..." dialog and type the file path
-                    self.steps.push(AnimationStep::OpenFileDialogStart);
-                    self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * 5.0) as u64,
-                    });
-
-                    // Type each character of the file path
-                    for ch in change.path.chars() {
-                        self.steps.push(AnimationStep::DialogTypeChar { ch });
-                    }
-
-                    self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * OPEN_CMD_PAUSE) as u64,
-                    });
-
-                    // Add file switch step with both old and new content
-                    let old_content = change.old_content.clone().unwrap_or_default();
-                    let new_content = change.new_content.clone().unwrap_or_default();
-                    self.steps.push(AnimationStep::SwitchFile {
-                        file_index: index,
-                        old_content,
-                        new_content,
-                        path: change.path.clone(),
-                    });
-
-                    // Add pause before starting file animation
-                    self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * FILE_SWITCH_PAUSE) as u64,
-                    });
+                    self.add_open_file_steps(index, change, banner.as_deref());
 
                     // Generate animation steps for this file
                     self.generate_steps_for_file(change);
 
                     // Git add this file after editing
                     self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * GIT_ADD_PAUSE) as u64,
-                    });
-                    self.add_terminal_command(&format!("git add {}", change.path));
-                    self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * GIT_ADD_CMD_PAUSE) as u64,
+                        duration_ms: self.pause_ms(self.pacing.git_add),
                     });
+                    self.add_stage_command(&change.path);
                 }
             }
         }
 
-        // Git commit
-        let commit_message = metadata.message.lines().next().unwrap_or("Update");
-        self.add_terminal_command(&format!("git commit -m \"{}\"", commit_message));
-        self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * GIT_COMMIT_PAUSE) as u64,
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: format!("💾 [main {}] {}", &metadata.hash[..7], commit_message),
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: format!(
-                "📝 {} file{} changed - immortalized forever!",
-                metadata.changes.len(),
-                if metadata.changes.len() == 1 { "" } else { "s" }
-            ),
-        });
-        self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * COMMIT_OUTPUT_PAUSE) as u64,
-        });
+        let remaining = sorted_indices.len() - animated;
+        if remaining > 0 {
+            self.steps.push(AnimationStep::Pause {
+                duration_ms: self.pause_ms(self.pacing.git_add),
+            });
+            self.steps.push(AnimationStep::TerminalOutput {
+                text: self.narrator.more_files(remaining),
+            });
+            self.steps.push(AnimationStep::Pause {
+                duration_ms: self.pause_ms(self.pacing.open_cmd),
+            });
+        }
 
-        // Git push
-        self.add_terminal_command("git push origin main");
-        self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * GIT_PUSH_PAUSE) as u64,
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: "🚀 Launching code into the cloud...".to_string(),
-        });
+        if self.reverse {
+            self.add_revert_steps(metadata);
+        } else {
+            self.add_commit_and_push_steps(metadata);
+        }
+
+        // Start with empty editor (no file opened yet)
+        self.buffer = EditorBuffer::new();
+    }
+
+    /// Open a file in the editor through the "Open File..." dialog
+    fn add_open_file_steps(&mut self, index: usize, change: &FileChange, banner: Option<&str>) {
+        if index == 0 {
+            self.steps.push(AnimationStep::Pause {
+                duration_ms: self.pause_ms(self.pacing.open_file_first),
+            });
+        } else {
+            self.steps.push(AnimationStep::Pause {
+                duration_ms: self.pause_ms(self.pacing.open_file),
+            });
+        }
+        // Show "Open File..." dialog and type the file path
+        self.steps.push(AnimationStep::OpenFileDialogStart);
         self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * PUSH_OUTPUT_PAUSE) as u64,
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: "📦 Compressing digital dreams: 100% (5/5)".to_string(),
+            duration_ms: self.pause_ms(self.pacing.open_dialog),
         });
+
+        // Type each character of the file path
+        for ch in change.path.chars() {
+            self.steps.push(AnimationStep::DialogTypeChar { ch });
+        }
+
         self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * PUSH_OUTPUT_PAUSE) as u64,
+            duration_ms: self.pause_ms(self.pacing.open_cmd),
         });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: "✍️  Signing with invisible ink: done.".to_string(),
+
+        // Add file switch step with both old and new content
+        let old_content = change.old_content.clone().unwrap_or_default();
+        let new_content = change.new_content.clone().unwrap_or_default();
+        self.steps.push(AnimationStep::SwitchFile {
+            file_index: index,
+            old_content,
+            new_content,
+            path: change.path.clone(),
         });
+
+        // Add pause before starting file animation
         self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * GIT_PUSH_PAUSE) as u64,
+            duration_ms: self.pause_ms(self.pacing.file_switch),
         });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: "📡 Beaming to origin/main via satellite...".to_string(),
+
+        if let Some(banner) = banner {
+            self.add_banner_steps(change, banner);
+        }
+    }
+
+    /// Type `banner` as a comment at the top of the file, then delete it
+    /// again so the file's edits play out against its real content
+    fn add_banner_steps(&mut self, change: &FileChange, banner: &str) {
+        let source = change.old_content.as_deref().unwrap_or_default();
+        let Some((open, close)) = comment_delimiters(Path::new(&change.path), source) else {
+            return;
+        };
+        let mut text = format!("{} {}", open, banner);
+        if !close.is_empty() {
+            text = format!("{} {}", text, close);
+        }
+
+        self.steps.push(AnimationStep::InsertLine {
+            line: 0,
+            content: String::new(),
         });
+        for (col, ch) in text.chars().enumerate() {
+            self.steps
+                .push(AnimationStep::InsertChar { line: 0, col, ch });
+        }
         self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * PUSH_OUTPUT_PAUSE) as u64,
-        });
-        self.steps.push(AnimationStep::TerminalOutput {
-            text: format!(
-                "   {}..{} ✨ SUCCESS",
-                &parent_hash[..7],
-                &metadata.hash[..7]
-            ),
+            duration_ms: self.pause_ms(self.pacing.file_switch),
         });
+        self.push_delete_line_steps(0);
+    }
+
+    /// Play the "time-travel" intro leading up to the commit
+    fn add_time_travel_steps(&mut self, metadata: &CommitMetadata) {
+        let (command, output) = self.narrator.intro(metadata);
+        self.add_terminal_command(&command);
         self.steps.push(AnimationStep::Pause {
-            duration_ms: (self.speed_ms as f64 * PUSH_FINAL_PAUSE) as u64,
+            duration_ms: self.pause_ms(self.pacing.checkout),
         });
-
-        // Start with empty editor (no file opened yet)
-        self.buffer = EditorBuffer::new();
+        self.add_terminal_output(
+            output,
+            self.pause_ms(self.pacing.checkout_output * 0.5),
+            self.pause_ms(self.pacing.checkout_output),
+        );
+    }
+
+    /// Print `lines` to the terminal, pausing `gap_ms` between lines and
+    /// `final_ms` after the last one
+    fn add_terminal_output(&mut self, lines: Vec<String>, gap_ms: u64, final_ms: u64) {
+        for (index, text) in lines.into_iter().enumerate() {
+            if index > 0 && gap_ms > 0 {
+                self.steps.push(AnimationStep::Pause {
+                    duration_ms: gap_ms,
+                });
+            }
+            self.steps.push(AnimationStep::TerminalOutput { text });
+        }
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: final_ms,
+        });
+    }
+
+    /// Stage a file after editing (reverse playback reverts the whole commit at the end instead)
+    fn add_stage_command(&mut self, path: &str) {
+        if self.reverse {
+            return;
+        }
+        self.add_terminal_command(&format!("git add {}", path));
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.git_add_cmd),
+        });
+    }
+
+    /// Commit and push the changes
+    fn add_commit_and_push_steps(&mut self, metadata: &CommitMetadata) {
+        // Git commit
+        self.add_terminal_command(&format!("git commit -m \"{}\"", subject(metadata)));
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.git_commit),
+        });
+        self.add_terminal_output(
+            self.narrator.commit(metadata),
+            0,
+            self.pause_ms(self.pacing.commit_output),
+        );
+
+        // Git push
+        self.add_terminal_command("git push origin main");
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.git_push),
+        });
+        self.add_terminal_output(
+            self.narrator.push(metadata),
+            self.pause_ms(self.pacing.push_output),
+            self.pause_ms(self.pacing.push_final),
+        );
+    }
+
+    /// Revert the commit whose changes were just un-written
+    fn add_revert_steps(&mut self, metadata: &CommitMetadata) {
+        self.add_terminal_command(&format!(
+            "git revert --no-edit {}",
+            short_hash(&metadata.hash)
+        ));
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.git_commit),
+        });
+        self.add_terminal_output(
+            self.narrator.revert(metadata),
+            0,
+            self.pause_ms(self.pacing.push_final),
+        );
     }
 
     /// Generate animation steps for a file change
     fn generate_steps_for_file(&mut self, change: &FileChange) {
+        self.pasting = self
+            .fast_above
+            .is_some_and(|lines| change.line_counts().0 > lines);
         let mut current_cursor_line = 0;
         let mut line_offset = 0i64; // Track how buffer lines differ from old file
 
@@ -584,9 +1282,10 @@ impl AnimationEngine {
 
             line_offset += additions - deletions;
 
-            // Add pause between hunks
+            // Pause between hunks, longer after bigger ones
+            let changed_lines = (additions + deletions) as usize;
             self.steps.push(AnimationStep::Pause {
-                duration_ms: (self.speed_ms as f64 * HUNK_PAUSE) as u64,
+                duration_ms: self.pause_ms(self.pacing.hunk * hunk_pause_factor(changed_lines)),
             });
         }
     }
@@ -644,8 +1343,9 @@ impl AnimationEngine {
         }
 
         // Generate movement steps
-        let base_pause =
-            (self.speed_ms as f64 * CURSOR_MOVE_PAUSE * base_speed_multiplier).max(1.0) as u64;
+        let base_pause = self
+            .pause_ms(self.pacing.cursor_move * base_speed_multiplier)
+            .max(1);
 
         for line in positions {
             if line != from_line {
@@ -664,6 +1364,37 @@ impl AnimationEngine {
         to_line
     }
 
+    /// Generate cursor movement steps along `line` from one column to another,
+    /// eased like movement between lines
+    fn generate_column_movement(&mut self, line: usize, from_col: usize, to_col: usize) {
+        let distance = from_col.abs_diff(to_col);
+        if distance == 0 {
+            return;
+        }
+
+        let num_steps = distance.min(MAX_COLUMN_STEPS);
+        let base_pause = self.pause_ms(self.pacing.cursor_move).max(1);
+        let mut last_col = from_col;
+        for i in 1..=num_steps {
+            let t = i as f64 / num_steps as f64;
+            let progress = (self.ease_in_out_cubic(t) * distance as f64).round() as usize;
+            let col = if from_col < to_col {
+                from_col + progress
+            } else {
+                from_col - progress
+            };
+
+            // Avoid duplicate positions
+            if col != last_col {
+                self.steps.push(AnimationStep::MoveCursor { line, col });
+                self.steps.push(AnimationStep::Pause {
+                    duration_ms: base_pause,
+                });
+                last_col = col;
+            }
+        }
+    }
+
     /// Ease-in-out cubic easing function
     /// Starts slow, accelerates in middle, ends slow
     fn ease_in_out_cubic(&self, t: f64) -> f64 {
@@ -686,52 +1417,77 @@ impl AnimationEngine {
         let mut buffer_line = start_buffer_line;
         let mut cursor_line = start_cursor_line;
 
-        for line_change in &hunk.lines {
-            match line_change.change_type {
+        let lines = &hunk.lines;
+        let mut i = 0;
+        while i < lines.len() {
+            match lines[i].change_type {
                 LineChangeType::Deletion => {
-                    // Delete the entire line at current buffer position
-                    self.steps
-                        .push(AnimationStep::DeleteLine { line: buffer_line });
-                    self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * DELETE_LINE_PAUSE) as u64,
-                    });
-                    cursor_line = buffer_line;
-                    // After deletion, buffer_line stays the same
-                    // (the next line moves up to this position)
-                }
-                LineChangeType::Addition => {
-                    let content = &line_change.content;
-                    let indentation_len = content.chars().take_while(|c| c.is_whitespace()).count();
-
-                    // Insert line with indentation already included
-                    let indentation: String = content.chars().take(indentation_len).collect();
-                    self.steps.push(AnimationStep::InsertLine {
-                        line: buffer_line,
-                        content: indentation,
-                    });
-
-                    // Type each character after the indentation
-                    for (i, ch) in content.chars().skip(indentation_len).enumerate() {
-                        self.steps.push(AnimationStep::InsertChar {
-                            line: buffer_line,
-                            col: indentation_len + i,
-                            ch,
-                        });
+                    // A block of deletions followed by additions is a modification:
+                    // lines are paired up in order so similar ones can be edited in place
+                    let deleted = lines[i..]
+                        .iter()
+                        .take_while(|l| matches!(l.change_type, LineChangeType::Deletion))
+                        .count();
+                    let added = lines[i + deleted..]
+                        .iter()
+                        .take_while(|l| matches!(l.change_type, LineChangeType::Addition))
+                        .count();
+                    let block = &lines[i..i + deleted + added];
+                    let (deletions, additions) = block.split_at(deleted);
+
+                    let paired = (0..deleted.min(added))
+                        .map(|j| line_edit(&deletions[j].content, &additions[j].content))
+                        .collect::<Vec<_>>();
+
+                    if paired.iter().all(Option::is_none) {
+                        for _ in deletions {
+                            self.push_delete_line_steps(buffer_line);
+                            cursor_line = buffer_line;
+                        }
+                        if !additions.is_empty() {
+                            self.push_added_lines_steps(buffer_line, additions);
+                            buffer_line += additions.len();
+                            cursor_line = buffer_line - 1;
+                        }
+                    } else {
+                        for j in 0..deleted.max(added) {
+                            if let Some(Some(edit)) = paired.get(j) {
+                                self.push_line_edit_steps(buffer_line, &additions[j].content, edit);
+                            } else {
+                                if j < deleted {
+                                    self.push_delete_line_steps(buffer_line);
+                                }
+                                if let Some(addition) = additions.get(j) {
+                                    self.push_insert_line_steps(buffer_line, &addition.content);
+                                } else {
+                                    cursor_line = buffer_line;
+                                    continue;
+                                }
+                            }
+                            cursor_line = buffer_line;
+                            buffer_line += 1;
+                        }
                     }
 
-                    cursor_line = buffer_line;
-                    buffer_line += 1; // Move to next line after insertion
-
-                    self.steps.push(AnimationStep::Pause {
-                        duration_ms: (self.speed_ms as f64 * INSERT_LINE_PAUSE) as u64,
-                    });
+                    i += block.len();
+                }
+                LineChangeType::Addition => {
+                    let added = lines[i..]
+                        .iter()
+                        .take_while(|l| matches!(l.change_type, LineChangeType::Addition))
+                        .count();
+                    self.push_added_lines_steps(buffer_line, &lines[i..i + added]);
+                    buffer_line += added; // Move to next line after insertion
+                    cursor_line = buffer_line - 1;
+                    i += added;
                 }
                 LineChangeType::Context => {
                     // Move cursor to next line if needed
                     if buffer_line != cursor_line {
                         // Calculate indentation (first non-whitespace character position)
-                        let col = line_change
+                        let col = lines[i]
                             .content
+                            .trim_end_matches(['\n', '\r'])
                             .chars()
                             .take_while(|c| c.is_whitespace())
                             .count();
@@ -740,11 +1496,12 @@ impl AnimationEngine {
                             col,
                         });
                         self.steps.push(AnimationStep::Pause {
-                            duration_ms: (self.speed_ms as f64 * CURSOR_MOVE_PAUSE) as u64,
+                            duration_ms: self.pause_ms(self.pacing.cursor_move),
                         });
                     }
                     cursor_line = buffer_line;
                     buffer_line += 1; // Move to next line
+                    i += 1;
                 }
             }
         }
@@ -752,21 +1509,272 @@ impl AnimationEngine {
         (cursor_line, buffer_line)
     }
 
+    /// Delete the entire line at `line`; the following line moves up into its place
+    fn push_delete_line_steps(&mut self, line: usize) {
+        self.steps.push(AnimationStep::DeleteLine { line });
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.delete_line),
+        });
+    }
+
+    /// Insert `additions` starting at `line`: typed one by one, or pasted
+    /// in bursts for a file above `--fast-above`
+    fn push_added_lines_steps(&mut self, line: usize, additions: &[LineChange]) {
+        if !self.pasting {
+            for (i, addition) in additions.iter().enumerate() {
+                self.push_insert_line_steps(line + i, &addition.content);
+            }
+            return;
+        }
+
+        for (burst, chunk) in additions.chunks(PASTE_BURST_LINES).enumerate() {
+            self.steps.push(AnimationStep::PasteLines {
+                line: line + burst * PASTE_BURST_LINES,
+                lines: chunk
+                    .iter()
+                    .map(|addition| addition.content.trim_end_matches(['\n', '\r']).to_string())
+                    .collect(),
+            });
+            self.steps.push(AnimationStep::Pause {
+                duration_ms: self.pause_ms(self.pacing.insert_line),
+            });
+        }
+    }
+
+    /// Insert a new line at `line` and type out `content`
+    fn push_insert_line_steps(&mut self, line: usize, content: &str) {
+        // Diff lines keep their line terminator; the buffer stores lines without it
+        let content = content.trim_end_matches(['\n', '\r']);
+        let indentation_len = if self.type_indentation {
+            0
+        } else {
+            content.chars().take_while(|c| c.is_whitespace()).count()
+        };
+
+        // Insert line with indentation already included
+        let indentation: String = content.chars().take(indentation_len).collect();
+        self.steps.push(AnimationStep::InsertLine {
+            line,
+            content: indentation,
+        });
+
+        // Type the text after the indentation
+        let text: String = content.chars().skip(indentation_len).collect();
+        self.push_typing_steps(line, indentation_len, &text);
+
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.insert_line),
+        });
+    }
+
+    /// Rewrite only the changed middle of the line at `line`: backspace over
+    /// the old text between the shared prefix and suffix, then type the new
+    fn push_line_edit_steps(&mut self, line: usize, new_content: &str, edit: &LineEdit) {
+        let new_content = new_content.trim_end_matches(['\n', '\r']);
+        let new_len = new_content.chars().count();
+
+        // Land on the line's indentation, then glide over to the change
+        let indentation = new_content
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .count()
+            .min(edit.prefix);
+        self.steps.push(AnimationStep::MoveCursor {
+            line,
+            col: indentation,
+        });
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.cursor_move),
+        });
+        self.generate_column_movement(line, indentation, edit.prefix + edit.removed);
+
+        for col in (edit.prefix..edit.prefix + edit.removed).rev() {
+            self.steps.push(AnimationStep::DeleteChar { line, col });
+        }
+
+        let inserted: String = new_content
+            .chars()
+            .skip(edit.prefix)
+            .take(new_len - edit.prefix - edit.suffix)
+            .collect();
+        self.push_typing_steps(line, edit.prefix, &inserted);
+
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.insert_line),
+        });
+    }
+
+    /// Type `text` into `line` starting at `col`, per the typing mode
+    fn push_typing_steps(&mut self, line: usize, col: usize, text: &str) {
+        match self.typing_mode {
+            TypingMode::Char => {
+                for (i, ch) in text.chars().enumerate() {
+                    let col = col + i;
+                    self.push_typo_steps(line, col, ch);
+                    self.steps.push(AnimationStep::InsertChar { line, col, ch });
+                }
+            }
+            TypingMode::Word => {
+                let mut col = col;
+                for word in split_words(text.chars()) {
+                    let word_len = word.chars().count();
+                    self.steps.push(AnimationStep::InsertWord {
+                        line,
+                        col,
+                        text: word,
+                    });
+                    col += word_len;
+                }
+            }
+        }
+    }
+
+    /// Randomly emit a wrong character at `col` followed by its correction
+    fn push_typo_steps(&mut self, line: usize, col: usize, ch: char) {
+        if !ch.is_ascii_alphanumeric() || !self.rng.random_bool(self.typo_probability) {
+            return;
+        }
+
+        let typo = loop {
+            let candidate = if ch.is_ascii_digit() {
+                self.rng.random_range('0'..='9')
+            } else {
+                self.rng.random_range('a'..='z')
+            };
+            if !candidate.eq_ignore_ascii_case(&ch) {
+                break candidate;
+            }
+        };
+
+        self.steps.push(AnimationStep::InsertChar {
+            line,
+            col,
+            ch: typo,
+        });
+        self.steps.push(AnimationStep::Pause {
+            duration_ms: self.pause_ms(self.pacing.typo_notice),
+        });
+        self.steps.push(AnimationStep::DeleteChar { line, col });
+    }
+
+    /// Suspend playback, freezing step timing until `resume` is called
+    pub fn suspend(&mut self) {
+        if self.suspended_at.is_none() {
+            self.suspended_at = Some(Instant::now());
+        }
+    }
+
+    /// Resume playback, shifting in-flight timers by the time spent suspended
+    pub fn resume(&mut self) {
+        if let Some(suspended_at) = self.suspended_at.take() {
+            let suspended_for = suspended_at.elapsed();
+            if let Some(pause_until) = self.pause_until {
+                self.pause_until = Some(pause_until + suspended_for);
+            }
+            self.last_update += suspended_for;
+            // Undo any manual scrolling done while suspended
+            self.update_scroll();
+        }
+    }
+
+    /// Scroll the editor by `lines` (negative scrolls up), stopping once the
+    /// last line is in view. Meant for browsing while suspended; the view
+    /// follows the cursor again on resume.
+    pub fn scroll_editor(&mut self, lines: isize) {
+        // Smallest offset that still fills the viewport down to the last line
+        let mut max_offset = self.buffer.lines.len();
+        let mut rows = 0;
+        while max_offset > 0 {
+            rows += self.calculate_line_display_height(&self.buffer.lines[max_offset - 1]);
+            if rows > self.viewport_height {
+                break;
+            }
+            max_offset -= 1;
+        }
+
+        let offset = self.buffer.scroll_offset;
+        self.buffer.scroll_offset = offset
+            .saturating_add_signed(lines)
+            .min(max_offset.max(offset));
+        self.buffer.settle_scroll();
+    }
+
+    /// Scroll so `line` sits in the middle of the editor, within the same
+    /// bounds as `scroll_editor`
+    pub fn scroll_editor_to_line(&mut self, line: usize) {
+        let target = line.saturating_sub(self.viewport_height / 2);
+        self.scroll_editor(target as isize - self.buffer.scroll_offset as isize);
+    }
+
+    /// Execute all remaining steps immediately, ignoring timing
+    pub fn fast_forward(&mut self) {
+        while self.current_step < self.steps.len() {
+            let step = self.steps[self.current_step].clone();
+            self.execute_step(step);
+            self.current_step += 1;
+        }
+        self.pause_until = None;
+        self.cursor_visible = true;
+        self.buffer.settle_scroll();
+        self.state = AnimationState::Finished;
+    }
+
+    /// Execute one frame's worth of steps on a simulated clock, for headless
+    /// rendering. Returns how long the resulting frame stays on screen in
+    /// milliseconds, or None once every step has run.
+    pub fn advance_frame(&mut self) -> Option<u64> {
+        if self.current_step >= self.steps.len() {
+            self.state = AnimationState::Finished;
+            return None;
+        }
+
+        let mut held_ms = 0;
+        while self.current_step < self.steps.len() {
+            let step = self.steps[self.current_step].clone();
+            let pause_ms = match step {
+                AnimationStep::Pause { duration_ms } => Some(duration_ms),
+                _ => None,
+            };
+
+            self.execute_step(step);
+            self.current_step += 1;
+            held_ms += self.next_step_delay;
+
+            if let Some(pause_ms) = pause_ms {
+                // Pauses end the frame so the paused state is what gets shown
+                self.pause_until = None;
+                held_ms += pause_ms;
+                break;
+            }
+            if held_ms >= self.frame_interval_ms {
+                break;
+            }
+        }
+
+        if self.current_step >= self.steps.len() {
+            self.state = AnimationState::Finished;
+        }
+        // Headless frames are step-driven, so show each scroll position as is
+        self.buffer.settle_scroll();
+        Some(held_ms)
+    }
+
     /// Update animation state and return true if display needs refresh
     pub fn tick(&mut self) -> bool {
         self.update_cursor_blink();
+        let scrolling = self.update_smooth_scroll();
 
         if self.is_paused() {
             return true;
         }
 
         if self.state != AnimationState::Playing {
-            return false;
+            return scrolling;
         }
 
         let now = Instant::now();
         if !self.should_render_frame(now) {
-            return false;
+            return scrolling;
         }
 
         let executed = self.execute_batch_steps(now);
@@ -775,11 +1783,40 @@ impl AnimationEngine {
             self.state = AnimationState::Finished;
         }
 
-        executed
+        executed || scrolling
+    }
+
+    /// Ease the displayed scroll position toward the target. Returns true
+    /// while the view is still moving.
+    fn update_smooth_scroll(&mut self) -> bool {
+        let elapsed_ms = self.scroll_clock.elapsed().as_millis() as u64;
+        self.scroll_clock = Instant::now();
+        self.advance_scroll(elapsed_ms)
+    }
+
+    fn advance_scroll(&mut self, elapsed_ms: u64) -> bool {
+        let Some(progress_ms) = self.buffer.scroll_elapsed_ms else {
+            return false;
+        };
+        let progress_ms = progress_ms + elapsed_ms;
+        if progress_ms >= SMOOTH_SCROLL_MS {
+            self.buffer.settle_scroll();
+            return true;
+        }
+
+        let t = progress_ms as f64 / SMOOTH_SCROLL_MS as f64;
+        let from = self.buffer.scroll_from;
+        let target = self.buffer.scroll_offset as f64;
+        self.buffer.display_scroll = from + (target - from) * self.ease_in_out_cubic(t);
+        self.buffer.scroll_elapsed_ms = Some(progress_ms);
+        true
     }
 
     fn update_cursor_blink(&mut self) {
-        if self.cursor_blink_timer.elapsed() >= Duration::from_millis(500) {
+        if self.cursor_blink_ms == 0 {
+            return;
+        }
+        if self.cursor_blink_timer.elapsed() >= Duration::from_millis(self.cursor_blink_ms) {
             self.cursor_visible = !self.cursor_visible;
             self.cursor_blink_timer = Instant::now();
         }
@@ -835,15 +1872,22 @@ impl AnimationEngine {
         accumulated_delay + self.next_step_delay <= self.frame_interval_ms
     }
 
-    fn execute_step(&mut self, step: AnimationStep) {
+    pub(crate) fn execute_step(&mut self, step: AnimationStep) {
         // Calculate delay for next step with randomization for typing steps
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
         self.next_step_delay = match &step {
-            AnimationStep::InsertChar { .. } | AnimationStep::TerminalTypeChar { .. } => {
+            AnimationStep::InsertChar { .. }
+            | AnimationStep::DeleteChar { .. }
+            | AnimationStep::TerminalTypeChar { .. } => {
                 // Add 70-130% variation to typing speed
                 let variation = rng.random_range(0.7..=1.3);
                 ((self.speed_ms as f64) * variation) as u64
             }
+            AnimationStep::InsertWord { .. } => {
+                // Pause between words, with the same variation as single characters
+                let variation = rng.random_range(0.7..=1.3);
+                ((self.speed_ms as f64) * WORD_PAUSE * variation) as u64
+            }
             AnimationStep::DialogTypeChar { .. } => {
                 // Dialog typing is slower (2x speed with variation)
                 let variation = rng.random_range(0.7..=1.3);
@@ -862,6 +1906,20 @@ impl AnimationEngine {
                 self.buffer.cursor_line = line;
                 self.buffer.cursor_col = col + 1;
             }
+            AnimationStep::DeleteChar { line, col } => {
+                self.active_pane = ActivePane::Editor;
+                self.buffer.delete_char(line, col);
+                self.buffer.cursor_line = line;
+                self.buffer.cursor_col = col;
+            }
+            AnimationStep::InsertWord { line, col, text } => {
+                self.active_pane = ActivePane::Editor;
+                for (i, ch) in text.chars().enumerate() {
+                    self.buffer.insert_char(line, col + i, ch);
+                }
+                self.buffer.cursor_line = line;
+                self.buffer.cursor_col = col + text.chars().count();
+            }
             AnimationStep::InsertLine { line, content } => {
                 self.active_pane = ActivePane::Editor;
                 let content_len = content.chars().count();
@@ -872,6 +1930,19 @@ impl AnimationEngine {
                 // Track line offset for old_highlights mapping
                 self.line_offset += 1;
             }
+            AnimationStep::PasteLines { line, lines } => {
+                self.active_pane = ActivePane::Editor;
+                let count = lines.len();
+                let last_len = lines.last().map_or(0, |content| content.chars().count());
+                for (i, content) in lines.into_iter().enumerate() {
+                    self.buffer.insert_line(line + i, content);
+                }
+                // The cursor ends on the last pasted line, so every pasted
+                // line is highlighted from the new content, as if typed
+                self.buffer.cursor_line = line + count.saturating_sub(1);
+                self.buffer.cursor_col = last_len;
+                self.line_offset += count as isize;
+            }
             AnimationStep::DeleteLine { line } => {
                 self.active_pane = ActivePane::Editor;
                 self.buffer.delete_line(line);
@@ -915,15 +1986,28 @@ impl AnimationEngine {
                 // Switch to new file
                 self.current_file_index = file_index;
                 self.current_file_path = Some(path.clone());
+
+                // The buffer drops the `\r` of CRLF line endings, so highlight
+                // the content the same way to keep byte offsets in step
+                let old_content = normalize_line_endings(old_content);
+                let new_content = normalize_line_endings(new_content);
                 self.buffer = EditorBuffer::from_content(&old_content);
 
                 // Update syntax highlighter for new file
                 // This will clear language settings if not supported
-                self.highlighter.borrow_mut().set_language_from_path(&path);
+                let mut highlighter = self.highlighter.borrow_mut();
+                if !highlighter.set_language_from_path(&path) {
+                    let source = if new_content.is_empty() {
+                        &old_content
+                    } else {
+                        &new_content
+                    };
+                    highlighter.set_language_from_content(source);
+                }
 
                 // Pre-calculate highlights for both old and new content
-                self.buffer.old_highlights = self.highlighter.borrow_mut().highlight(&old_content);
-                self.buffer.new_highlights = self.highlighter.borrow_mut().highlight(&new_content);
+                self.buffer.old_highlights = highlighter.highlight(&old_content);
+                self.buffer.new_highlights = highlighter.highlight(&new_content);
 
                 // Store content lines for byte offset calculation
                 self.buffer.old_content_lines = if old_content.is_empty() {
@@ -937,7 +2021,7 @@ impl AnimationEngine {
                     new_content.lines().map(|s| s.to_string()).collect()
                 };
 
-                // Pre-calculate line byte offsets (handles CRLF correctly)
+                // Pre-calculate line byte offsets
                 self.buffer.old_content_line_offsets = Self::calculate_line_offsets(&old_content);
                 self.buffer.new_content_line_offsets = Self::calculate_line_offsets(&new_content);
 
@@ -950,7 +2034,7 @@ impl AnimationEngine {
             AnimationStep::TerminalPrompt => {
                 self.active_pane = ActivePane::Terminal;
                 // Start a new command line with prompt
-                self.terminal_lines.push("~ ".to_string());
+                self.push_terminal_line(TERMINAL_PROMPT.to_string());
             }
             AnimationStep::TerminalTypeChar { ch } => {
                 self.active_pane = ActivePane::Terminal;
@@ -962,7 +2046,7 @@ impl AnimationEngine {
             AnimationStep::TerminalOutput { text } => {
                 self.active_pane = ActivePane::Terminal;
                 // Add output line
-                self.terminal_lines.push(text);
+                self.push_terminal_line(text);
             }
             AnimationStep::ResetState => {
                 // Apply pending metadata and reset UI state after time-travel animation
@@ -982,29 +2066,55 @@ impl AnimationEngine {
     }
 
     fn calculate_line_display_height(&self, line: &str) -> usize {
-        if self.content_width == 0 {
+        if !self.line_wrap {
             return 1;
         }
 
-        // Calculate text area width (excluding line numbers, padding, etc.)
-        let line_num_width = format!("{}", self.buffer.lines.len()).len().max(3);
-        let left_padding = 2;
-        let line_num_and_space = line_num_width + 1;
-        let separator = 2;
-        let right_padding = 2;
-        let fixed_width = left_padding + line_num_and_space + separator + right_padding;
-
-        let text_width = self.content_width.saturating_sub(fixed_width);
+        let text_width = self.content_width;
         if text_width == 0 {
             return 1;
         }
 
         // Calculate how many lines this text will take when wrapped (using display width)
-        let display_width = line.width();
+        let display_width = text_display_width(line, self.tab_width);
         display_width.div_ceil(text_width).max(1)
     }
 
+    /// Keep the cursor column inside the visible window when lines don't wrap
+    fn update_h_scroll(&mut self) {
+        let text_width = self.content_width;
+        if self.line_wrap || text_width == 0 {
+            self.buffer.h_scroll_offset = 0;
+            return;
+        }
+
+        // Display column of the cursor, accounting for wide characters
+        let cursor_column = self
+            .buffer
+            .lines
+            .get(self.buffer.cursor_line)
+            .map(|line| {
+                let prefix_end = line
+                    .char_indices()
+                    .nth(self.buffer.cursor_col)
+                    .map_or(line.len(), |(idx, _)| idx);
+                text_display_width(&line[..prefix_end], self.tab_width)
+            })
+            .unwrap_or(0);
+
+        // Keep a few columns of context to the right of the cursor
+        let margin = H_SCROLL_MARGIN.min(text_width / 4);
+        let offset = self.buffer.h_scroll_offset;
+        if cursor_column < offset {
+            self.buffer.h_scroll_offset = cursor_column.saturating_sub(margin);
+        } else if cursor_column + margin >= offset + text_width {
+            self.buffer.h_scroll_offset = cursor_column + margin + 1 - text_width;
+        }
+    }
+
     fn update_scroll(&mut self) {
+        self.update_h_scroll();
+
         if self.viewport_height == 0 {
             return;
         }
@@ -1045,10 +2155,912 @@ impl AnimationEngine {
             }
         }
 
-        self.buffer.scroll_offset = logical_offset;
+        if logical_offset != self.buffer.scroll_offset {
+            // Restart the easing from wherever the view currently is
+            self.buffer.scroll_offset = logical_offset;
+            self.buffer.scroll_from = self.buffer.display_scroll;
+            self.buffer.scroll_elapsed_ms = Some(0);
+        }
     }
 
     pub fn is_finished(&self) -> bool {
         self.state == AnimationState::Finished
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::LineChange;
+    use crate::syntax::TokenType;
+
+    #[test]
+    fn test_terminal_lines_stay_bounded() {
+        let mut engine = AnimationEngine::new(30);
+        engine.set_max_terminal_lines(100);
+
+        for i in 0..5000 {
+            if i % 10 == 0 {
+                engine.execute_step(AnimationStep::TerminalPrompt);
+                engine.execute_step(AnimationStep::TerminalTypeChar { ch: 'x' });
+            } else {
+                engine.execute_step(AnimationStep::TerminalOutput {
+                    text: format!("line {}", i),
+                });
+            }
+        }
+
+        assert_eq!(engine.terminal_lines.len(), 100);
+        assert_eq!(engine.terminal_lines.last().unwrap(), "line 4999");
+    }
+
+    #[test]
+    fn test_h_scroll_follows_cursor_with_wide_chars() {
+        let mut engine = AnimationEngine::new(30);
+        engine.set_line_wrap(false);
+        engine.set_content_width(47);
+        engine.buffer = EditorBuffer::from_content(&"漢".repeat(50));
+
+        engine.buffer.cursor_col = 40; // Display column 80
+        engine.update_scroll();
+        let offset = engine.buffer.h_scroll_offset;
+        assert!(offset <= 80 && 80 < offset + 47, "offset {offset}");
+
+        engine.buffer.cursor_col = 0;
+        engine.update_scroll();
+        assert_eq!(engine.buffer.h_scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_line_origins_follow_buffer_edits() {
+        use LineOrigin::*;
+
+        let mut buffer = EditorBuffer::from_content("a\nb\nc\n");
+        buffer.insert_line(1, "new".to_string());
+        buffer.insert_char(1, 3, '!');
+        buffer.insert_char(0, 1, 'x');
+        assert_eq!(
+            buffer.line_origins,
+            vec![Modified, Added, Unchanged, Unchanged]
+        );
+
+        buffer.delete_line(2);
+        assert_eq!(buffer.lines, vec!["ax", "new!", "c"]);
+        assert_eq!(buffer.line_origins, vec![Modified, Added, Unchanged]);
+    }
+
+    #[test]
+    fn test_scroll_editor_stays_in_bounds_and_resets_on_resume() {
+        let mut engine = AnimationEngine::new(30);
+        engine.set_viewport_height(10);
+        let content: String = (0..50).map(|i| format!("line {}\n", i)).collect();
+        engine.buffer = EditorBuffer::from_content(&content);
+        engine.update_scroll();
+        assert_eq!(engine.buffer.scroll_offset, 0);
+
+        engine.suspend();
+        engine.scroll_editor(100);
+        assert_eq!(engine.buffer.scroll_offset, 40);
+        engine.scroll_editor(-3);
+        assert_eq!(engine.buffer.scroll_offset, 37);
+        engine.scroll_editor(-100);
+        assert_eq!(engine.buffer.scroll_offset, 0);
+
+        engine.scroll_editor(20);
+        engine.resume();
+        assert_eq!(engine.buffer.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_scroll_eases_toward_target() {
+        let mut engine = AnimationEngine::new(30);
+        engine.set_viewport_height(10);
+        let content: String = (0..50).map(|i| format!("line {}\n", i)).collect();
+        engine.buffer = EditorBuffer::from_content(&content);
+        engine.buffer.cursor_line = 30;
+        engine.update_scroll();
+        assert_eq!(engine.buffer.scroll_offset, 25);
+        assert_eq!(engine.buffer.display_offset(), 0);
+
+        assert!(engine.advance_scroll(SMOOTH_SCROLL_MS / 2));
+        let midway = engine.buffer.display_offset();
+        assert!(midway > 0 && midway < 25, "{midway}");
+
+        // A new target mid-animation continues from the current position
+        engine.buffer.cursor_line = 40;
+        engine.update_scroll();
+        assert!(engine.advance_scroll(1));
+        assert!(engine.buffer.display_offset() >= midway);
+        assert!(engine.advance_scroll(SMOOTH_SCROLL_MS));
+        assert_eq!(engine.buffer.display_offset(), engine.buffer.scroll_offset);
+        assert!(!engine.advance_scroll(10));
+
+        // The shown offset never points past the buffer, even mid-animation
+        engine.buffer.lines.truncate(5);
+        assert_eq!(engine.buffer.display_offset(), 4);
+    }
+
+    #[test]
+    fn test_word_typing_matches_char_typing() {
+        let commit = crate::preview::sample_commit();
+        let final_buffer = |mode| {
+            let mut engine = AnimationEngine::new(30);
+            engine.set_typing_mode(mode);
+            engine.load_commit(&commit);
+            engine.fast_forward();
+            engine.buffer.lines
+        };
+
+        assert_eq!(
+            final_buffer(TypingMode::Word),
+            final_buffer(TypingMode::Char)
+        );
+    }
+
+    #[test]
+    fn test_split_words_keeps_trailing_whitespace() {
+        assert_eq!(
+            split_words("let x  = 1;".chars()),
+            ["let ", "x  ", "= ", "1;"]
+        );
+    }
+
+    #[test]
+    fn test_cursor_blink_can_be_disabled() {
+        let mut engine = AnimationEngine::new(10);
+        engine.cursor_blink_timer = Instant::now() - Duration::from_secs(1);
+        engine.tick();
+        assert!(!engine.cursor_visible);
+
+        engine.set_cursor_blink(0);
+        assert!(engine.cursor_visible);
+        engine.cursor_blink_timer = Instant::now() - Duration::from_secs(1);
+        engine.tick();
+        assert!(engine.cursor_visible);
+    }
+
+    #[test]
+    fn test_typos_converge_and_are_seeded() {
+        let commit = crate::preview::sample_commit();
+        let play = |typos| {
+            let mut engine = AnimationEngine::new(30);
+            engine.set_seed(7);
+            engine.set_typo_probability(typos);
+            engine.load_commit(&commit);
+            let steps = engine.steps().to_vec();
+            engine.fast_forward();
+            (engine.buffer.lines, steps)
+        };
+
+        let (clean, _) = play(0.0);
+        let (with_typos, steps) = play(0.5);
+        assert_eq!(with_typos, clean);
+        assert!(steps
+            .iter()
+            .any(|step| matches!(step, AnimationStep::DeleteChar { .. })));
+        assert_eq!(play(0.5).1.len(), steps.len());
+    }
+
+    #[test]
+    fn test_reverse_playback_restores_old_content() {
+        let commit = crate::preview::sample_commit();
+        let mut engine = AnimationEngine::new(30);
+        engine.set_reverse(true);
+        engine.load_commit(&commit);
+        engine.fast_forward();
+
+        // src/main.rs is the last file in tree order
+        let main_rs = commit
+            .changes
+            .iter()
+            .find(|change| change.path == "src/main.rs")
+            .unwrap();
+        let old_lines: Vec<&str> = main_rs.old_content.as_deref().unwrap().lines().collect();
+        assert_eq!(engine.buffer.lines, old_lines);
+        assert!(engine
+            .terminal_lines
+            .iter()
+            .any(|line| line.contains("git revert")));
+    }
+
+    #[test]
+    fn test_fast_above_pastes_large_additions() {
+        let new_content: String = (0..45).map(|i| format!("let x{} = {};\n", i, i)).collect();
+        let mut commit = crate::preview::sample_commit();
+        commit.changes.push(FileChange {
+            hunks: vec![DiffHunk {
+                old_start: 0,
+                old_lines: 0,
+                new_start: 1,
+                new_lines: 45,
+                lines: new_content
+                    .lines()
+                    .enumerate()
+                    .map(|(i, line)| LineChange {
+                        change_type: LineChangeType::Addition,
+                        content: format!("{}\n", line),
+                        old_line_no: None,
+                        new_line_no: Some(i + 1),
+                    })
+                    .collect(),
+            }],
+            ..file_change("src/big.rs", FileStatus::Added, None, Some(&new_content))
+        });
+
+        let mut engine = AnimationEngine::new(30);
+        engine.set_fast_above(Some(40));
+        engine.load_commit(&commit);
+        let big_steps: Vec<AnimationStep> = engine
+            .steps()
+            .iter()
+            .skip_while(|step| {
+                !matches!(step, AnimationStep::SwitchFile { path, .. } if path == "src/big.rs")
+            })
+            .take_while(|step| !matches!(step, AnimationStep::TerminalPrompt))
+            .cloned()
+            .collect();
+        let bursts: Vec<usize> = big_steps
+            .iter()
+            .filter_map(|step| match step {
+                AnimationStep::PasteLines { lines, .. } => Some(lines.len()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(bursts, [20, 20, 5]);
+        assert!(!big_steps
+            .iter()
+            .any(|step| matches!(step, AnimationStep::InsertChar { .. })));
+        // Small files below the threshold are still typed
+        assert!(engine
+            .steps()
+            .iter()
+            .any(|step| matches!(step, AnimationStep::InsertChar { .. })));
+
+        // Pasted lines count toward the offset used to map old highlights,
+        // with the cursor on the last of them
+        let mut pasted = AnimationEngine::new(30);
+        for step in big_steps {
+            pasted.execute_step(step);
+        }
+        let expected: Vec<&str> = new_content.lines().collect();
+        assert_eq!(pasted.buffer.lines, expected);
+        assert_eq!(pasted.line_offset, 45);
+        assert_eq!(pasted.buffer.cursor_line, 44);
+    }
+
+    #[test]
+    fn test_no_intro_goes_straight_to_reset() {
+        let mut engine = AnimationEngine::new(30);
+        engine.set_intro(false);
+        engine.load_commit(&crate::preview::sample_commit());
+
+        let steps = engine.steps();
+        assert!(matches!(steps[0], AnimationStep::TerminalOutput { .. }));
+        assert!(matches!(steps[1], AnimationStep::ResetState));
+    }
+
+    #[test]
+    fn test_ascii_glyphs_keep_terminal_output_ascii() {
+        let mut engine = AnimationEngine::new(30);
+        engine.set_narrator(Box::new(FunNarrator {
+            glyphs: crate::glyphs::Glyphs::ASCII,
+        }));
+        let mut metadata = crate::preview::sample_commit();
+        metadata.parent_hash = None;
+        for reverse in [false, true] {
+            engine.set_reverse(reverse);
+            engine.load_commit(&metadata);
+            let outputs: Vec<&String> = engine
+                .steps()
+                .iter()
+                .filter_map(|step| match step {
+                    AnimationStep::TerminalOutput { text } => Some(text),
+                    _ => None,
+                })
+                .collect();
+            assert!(outputs.len() > 2);
+            assert!(outputs.iter().all(|text| text.is_ascii()), "{:?}", outputs);
+        }
+    }
+
+    fn diff_line(change_type: LineChangeType, content: &str) -> LineChange {
+        LineChange {
+            change_type,
+            content: format!("{}\n", content),
+            old_line_no: None,
+            new_line_no: None,
+        }
+    }
+
+    fn diff_hunk(old_start: usize, lines: Vec<LineChange>) -> DiffHunk {
+        DiffHunk {
+            old_start,
+            old_lines: 0,
+            new_start: old_start,
+            new_lines: 0,
+            lines,
+        }
+    }
+
+    /// A text file change without hunks, for struct update syntax
+    fn file_change(
+        path: &str,
+        status: FileStatus,
+        old_content: Option<&str>,
+        new_content: Option<&str>,
+    ) -> FileChange {
+        FileChange {
+            path: path.to_string(),
+            old_path: None,
+            status,
+            is_binary: false,
+            size: new_content.or(old_content).map_or(0, str::len),
+            is_excluded: false,
+            exclusion_reason: None,
+            old_content: old_content.map(String::from),
+            new_content: new_content.map(String::from),
+            hunks: Vec::new(),
+            diff: String::new(),
+        }
+    }
+
+    fn test_commit(message: &str, changes: Vec<FileChange>) -> CommitMetadata {
+        CommitMetadata {
+            hash: "0123456789abcdef0123456789abcdef01234567".to_string(),
+            author: "Test".to_string(),
+            date: chrono::DateTime::from_timestamp(0, 0).unwrap(),
+            message: message.to_string(),
+            parent_hash: Some("fedcba9876543210fedcba9876543210fedcba98".to_string()),
+            changes,
+        }
+    }
+
+    #[test]
+    fn test_multi_hunk_diff_produces_new_content() {
+        use LineChangeType::{Addition, Context, Deletion};
+
+        let old_content: String = (1..=10).map(|i| format!("line {}\n", i)).collect();
+        let new_content =
+            "line 1\nLINE 2\nline 3\nline 4\nline 7\nline 8\nline 9\n    indented\nline 10\n";
+        let change = FileChange {
+            hunks: vec![
+                diff_hunk(
+                    1,
+                    vec![
+                        diff_line(Context, "line 1"),
+                        diff_line(Deletion, "line 2"),
+                        diff_line(Addition, "LINE 2"),
+                        diff_line(Context, "line 3"),
+                    ],
+                ),
+                diff_hunk(
+                    4,
+                    vec![
+                        diff_line(Context, "line 4"),
+                        diff_line(Deletion, "line 5"),
+                        diff_line(Deletion, "line 6"),
+                        diff_line(Context, "line 7"),
+                    ],
+                ),
+                diff_hunk(
+                    8,
+                    vec![
+                        diff_line(Context, "line 8"),
+                        diff_line(Context, "line 9"),
+                        diff_line(Addition, "    indented"),
+                        diff_line(Context, "line 10"),
+                    ],
+                ),
+            ],
+            ..file_change(
+                "notes.txt",
+                FileStatus::Modified,
+                Some(&old_content),
+                Some(new_content),
+            )
+        };
+        let commit = test_commit("Edit notes", vec![change]);
+
+        for mode in [TypingMode::Char, TypingMode::Word] {
+            let mut engine = AnimationEngine::new(30);
+            engine.set_typing_mode(mode);
+            engine.load_commit(&commit);
+            engine.fast_forward();
+
+            let expected: Vec<&str> = new_content.lines().collect();
+            assert_eq!(engine.buffer.lines, expected, "{:?} mode", mode);
+            // Two additions, three deletions
+            assert_eq!(engine.line_offset, -1);
+        }
+    }
+
+    #[test]
+    fn test_line_edit_finds_changed_middle() {
+        assert_eq!(
+            line_edit("    let x = 1;\n", "    let count = 1;\n"),
+            Some(LineEdit {
+                prefix: 8,
+                suffix: 5,
+                removed: 1,
+            })
+        );
+        // Only the indentation in common
+        assert_eq!(line_edit("    foo();", "    bar = 2;"), None);
+        assert_eq!(line_edit("abc", "xyz"), None);
+    }
+
+    #[test]
+    fn test_modified_line_is_edited_in_place() {
+        use LineChangeType::{Addition, Context, Deletion};
+
+        let old_content = "fn main() {\n    let total = a + b;\n    unused();\n}\n";
+        let new_content = "fn main() {\n    let total = a + b + c;\n}\n";
+        let change = FileChange {
+            hunks: vec![diff_hunk(
+                1,
+                vec![
+                    diff_line(Context, "fn main() {"),
+                    diff_line(Deletion, "    let total = a + b;"),
+                    diff_line(Deletion, "    unused();"),
+                    diff_line(Addition, "    let total = a + b + c;"),
+                    diff_line(Context, "}"),
+                ],
+            )],
+            ..file_change(
+                "main.rs",
+                FileStatus::Modified,
+                Some(old_content),
+                Some(new_content),
+            )
+        };
+        let commit = test_commit("Add c", vec![change]);
+
+        let mut engine = AnimationEngine::new(30);
+        engine.load_commit(&commit);
+
+        let deleted_lines = engine
+            .steps()
+            .iter()
+            .filter(|s| matches!(s, AnimationStep::DeleteLine { .. }))
+            .count();
+        let typed: String = engine
+            .steps()
+            .iter()
+            .filter_map(|s| match s {
+                AnimationStep::InsertChar { line: 1, ch, .. } => Some(*ch),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(deleted_lines, 1, "only the unrelated line is deleted");
+        assert_eq!(typed, " + c");
+
+        engine.fast_forward();
+        let expected: Vec<&str> = new_content.lines().collect();
+        assert_eq!(engine.buffer.lines, expected);
+    }
+
+    #[test]
+    fn test_animate_deletes_opens_deleted_and_renamed_files() {
+        // Reversed, the sample commit deletes the file it added
+        let mut metadata = crate::preview::sample_commit().reversed();
+        let renamed = metadata
+            .changes
+            .iter_mut()
+            .find(|change| matches!(change.status, FileStatus::Modified))
+            .unwrap();
+        renamed.status = FileStatus::Renamed;
+        renamed.old_path = Some("OLD_README.md".to_string());
+        let deleted = metadata
+            .changes
+            .iter()
+            .find(|change| matches!(change.status, FileStatus::Deleted))
+            .unwrap()
+            .path
+            .clone();
+
+        let opened_files = |animate_deletes| {
+            let mut engine = AnimationEngine::new(0);
+            engine.set_animate_deletes(animate_deletes);
+            engine.load_commit(&metadata);
+            let opened = engine
+                .steps()
+                .iter()
+                .filter(|step| matches!(step, AnimationStep::OpenFileDialogStart))
+                .count();
+            engine.fast_forward();
+            (opened, engine.terminal_lines.clone())
+        };
+
+        let (skipped, _) = opened_files(false);
+        let (opened, terminal) = opened_files(true);
+        assert_eq!(opened, skipped + 2);
+        for command in [
+            format!("rm {}", deleted),
+            "mv OLD_README.md README.md".to_string(),
+        ] {
+            assert!(terminal.iter().any(|line| line.ends_with(&command)));
+        }
+    }
+
+    #[test]
+    fn test_git_mv_with_edit_plays_as_rename() {
+        use crate::git::tests::{commit_rename, create_test_repo_with};
+        use crate::git::GitRepository;
+
+        let old: String = (1..=10).map(|i| format!("line {}\n", i)).collect();
+        let new = old.replace("line 5\n", "line five\n");
+        let path =
+            create_test_repo_with("rename-animation", &[("Add old.txt", &[("old.txt", &old)])]);
+        commit_rename(&path, "old.txt", "new.txt", &new);
+        let metadata = GitRepository::open(&path)
+            .unwrap()
+            .get_commit("HEAD")
+            .unwrap();
+        assert_eq!(metadata.changes[0].status, FileStatus::Renamed);
+
+        let mut engine = AnimationEngine::new(0);
+        engine.set_animate_deletes(true);
+        engine.load_commit(&metadata);
+        engine.fast_forward();
+        let expected: Vec<&str> = new.lines().collect();
+        assert_eq!(engine.buffer.lines, expected);
+        assert!(engine
+            .terminal_lines
+            .iter()
+            .any(|line| line.ends_with("mv old.txt new.txt")));
+        assert!(!engine
+            .terminal_lines
+            .iter()
+            .any(|line| line.ends_with("rm old.txt")));
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_indentation_is_pasted_unless_typed() {
+        let typed_chars = |type_indentation| {
+            let mut engine = AnimationEngine::new(30);
+            engine.set_type_indentation(type_indentation);
+            engine.push_insert_line_steps(0, "        return x;\n");
+            let inserted = engine.steps().iter().find_map(|step| match step {
+                AnimationStep::InsertLine { content, .. } => Some(content.clone()),
+                _ => None,
+            });
+            let typed = engine
+                .steps()
+                .iter()
+                .filter(|step| matches!(step, AnimationStep::InsertChar { .. }))
+                .count();
+            (inserted.unwrap(), typed)
+        };
+
+        assert_eq!(typed_chars(false), ("        ".to_string(), 9));
+        assert_eq!(typed_chars(true), (String::new(), 17));
+    }
+
+    #[test]
+    fn test_line_edit_glides_cursor_to_the_change() {
+        let old = "    let total = compute(a, b);";
+        let new = "    let total = compute(a, b, c);";
+        let edit = line_edit(old, new).unwrap();
+
+        let mut engine = AnimationEngine::new(30);
+        engine.push_line_edit_steps(3, new, &edit);
+        let cols: Vec<usize> = engine
+            .steps()
+            .iter()
+            .take_while(|step| !matches!(step, AnimationStep::DeleteChar { .. }))
+            .filter_map(|step| match step {
+                AnimationStep::MoveCursor { line: 3, col } => Some(*col),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(cols.first(), Some(&4));
+        assert_eq!(cols.last(), Some(&(edit.prefix + edit.removed)));
+        assert!(cols.len() > 2);
+        assert!(cols.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_hunk_pause_scales_with_hunk_size() {
+        assert_eq!(hunk_pause_factor(10), 1.0);
+        assert_eq!(hunk_pause_factor(1), HUNK_PAUSE_MIN_FACTOR);
+        assert_eq!(hunk_pause_factor(0), HUNK_PAUSE_MIN_FACTOR);
+        assert_eq!(hunk_pause_factor(10_000), HUNK_PAUSE_MAX_FACTOR);
+        assert!(hunk_pause_factor(5) < hunk_pause_factor(20));
+    }
+
+    #[test]
+    fn test_truncate_hunks_keeps_first_changes() {
+        use LineChangeType::{Addition, Context, Deletion};
+
+        let hunks = vec![
+            diff_hunk(
+                1,
+                vec![
+                    diff_line(Context, "a"),
+                    diff_line(Deletion, "b"),
+                    diff_line(Addition, "B"),
+                    diff_line(Context, "c"),
+                ],
+            ),
+            diff_hunk(
+                20,
+                vec![
+                    diff_line(Context, "x"),
+                    diff_line(Addition, "y"),
+                    diff_line(Addition, "z"),
+                ],
+            ),
+        ];
+        let (kept, remaining) = truncate_hunks(&hunks, 3);
+        assert_eq!(remaining, 1);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[1].lines.len(), 2);
+
+        // The cut can fall right at a hunk boundary
+        let (kept, remaining) = truncate_hunks(&hunks, 2);
+        assert_eq!((kept.len(), remaining), (1, 2));
+        assert_eq!(truncate_hunks(&hunks, 10).1, 0);
+    }
+
+    #[test]
+    fn test_excluded_file_is_previewed_then_cut_short() {
+        let mut metadata = crate::preview::sample_commit();
+        for change in &mut metadata.changes {
+            change.is_excluded = true;
+            change.exclusion_reason = Some(ExclusionReason::Generated);
+        }
+        // Files with more than the one previewed change get a marker
+        let cut_short = metadata
+            .changes
+            .iter()
+            .filter(|change| {
+                let (additions, deletions) = change.line_counts();
+                additions + deletions > 1
+            })
+            .count();
+
+        let mut engine = AnimationEngine::new(0);
+        engine.set_excluded_preview(1);
+        engine.load_commit(&metadata);
+        let typed = engine
+            .steps()
+            .iter()
+            .filter(|step| matches!(step, AnimationStep::InsertLine { .. }))
+            .count();
+        assert!(typed > 0);
+        engine.fast_forward();
+        let markers = engine
+            .terminal_lines
+            .iter()
+            .filter(|line| line.contains("(truncated, "))
+            .count();
+        assert!(cut_short > 0);
+        assert_eq!(markers, cut_short);
+
+        engine.set_excluded_preview(0);
+        engine.load_commit(&metadata);
+        assert!(!engine
+            .steps()
+            .iter()
+            .any(|step| matches!(step, AnimationStep::OpenFileDialogStart)));
+
+        // Files the user chose to ignore are never opened
+        for change in &mut metadata.changes {
+            change.exclusion_reason = Some(ExclusionReason::IgnorePattern);
+        }
+        engine.set_excluded_preview(1);
+        engine.load_commit(&metadata);
+        assert!(!engine
+            .steps()
+            .iter()
+            .any(|step| matches!(step, AnimationStep::OpenFileDialogStart)));
+    }
+
+    #[test]
+    fn test_comment_banner_is_typed_then_removed() {
+        let commit = crate::preview::sample_commit();
+        let mut engine = AnimationEngine::new(30);
+        engine.set_comment_banner(true);
+        engine.load_commit(&commit);
+
+        let banner: String = engine
+            .steps()
+            .iter()
+            .skip_while(|step| {
+                !matches!(step, AnimationStep::SwitchFile { path, .. } if path == "src/main.rs")
+            })
+            .take_while(|step| !matches!(step, AnimationStep::DeleteLine { line: 0 }))
+            .filter_map(|step| match step {
+                AnimationStep::InsertChar { line: 0, ch, .. } => Some(*ch),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(banner, "// commit c0ffee1 — Greet the user by name");
+
+        // The banner is gone again once the file has been edited
+        engine.fast_forward();
+        let main_rs = commit
+            .changes
+            .iter()
+            .find(|change| change.path == "src/main.rs")
+            .unwrap();
+        let new_lines: Vec<&str> = main_rs.new_content.as_deref().unwrap().lines().collect();
+        assert_eq!(engine.buffer.lines, new_lines);
+    }
+
+    #[test]
+    fn test_binary_file_opens_a_placeholder() {
+        let mut commit = crate::preview::sample_commit();
+        commit.changes.push(FileChange {
+            is_binary: true,
+            size: 12_595,
+            ..file_change("logo.png", FileStatus::Added, None, None)
+        });
+        let mut engine = AnimationEngine::new(30);
+        engine.load_commit(&commit);
+
+        let steps: Vec<&AnimationStep> = engine
+            .steps()
+            .iter()
+            .skip_while(|step| {
+                !matches!(step, AnimationStep::SwitchFile { path, .. } if path == "logo.png")
+            })
+            .take_while(|step| !matches!(step, AnimationStep::SwitchFile { path, .. } if path != "logo.png"))
+            .collect();
+        assert!(matches!(
+            steps[0],
+            AnimationStep::SwitchFile { new_content, .. }
+                if new_content == "Binary file (12.3 KB) — contents not shown"
+        ));
+        assert!(!steps
+            .iter()
+            .any(|step| matches!(step, AnimationStep::InsertChar { .. })));
+        let typed: String = steps
+            .iter()
+            .filter_map(|step| match step {
+                AnimationStep::TerminalTypeChar { ch } => Some(*ch),
+                _ => None,
+            })
+            .collect();
+        assert!(typed.contains("logo.png"), "{}", typed);
+    }
+
+    #[test]
+    fn test_line_offsets_with_and_without_trailing_newline() {
+        let switch_to = |content: &str| {
+            let mut engine = AnimationEngine::new(30);
+            engine.execute_step(AnimationStep::SwitchFile {
+                file_index: 0,
+                old_content: content.to_string(),
+                new_content: content.to_string(),
+                path: "src/lib.rs".to_string(),
+            });
+            engine.buffer
+        };
+        let with_newline = switch_to("fn a() {}\nfn b() {}\n");
+        let without_newline = switch_to("fn a() {}\nfn b() {}");
+
+        assert_eq!(with_newline.lines, without_newline.lines);
+        assert_eq!(with_newline.old_content_line_offsets, [0, 10, 20]);
+        assert_eq!(without_newline.old_content_line_offsets, [0, 10, 19]);
+
+        // The last line is highlighted the same either way, and a line past
+        // it starts at the end of the file rather than on the last line
+        let last_line = |buffer: &EditorBuffer| {
+            let start = buffer.old_content_line_offsets[1];
+            buffer
+                .old_highlights
+                .iter()
+                .filter(|span| span.start >= start)
+                .map(|span| (span.start - start, span.end - start, span.token_type))
+                .collect::<Vec<_>>()
+        };
+        assert!(!last_line(&without_newline).is_empty());
+        assert_eq!(last_line(&with_newline), last_line(&without_newline));
+        assert!(without_newline
+            .old_highlights
+            .iter()
+            .all(|span| span.end <= without_newline.old_content_line_offsets[2]));
+    }
+
+    #[test]
+    fn test_crlf_highlights_land_on_their_tokens() {
+        let mut engine = AnimationEngine::new(30);
+        engine.execute_step(AnimationStep::SwitchFile {
+            file_index: 0,
+            old_content: "fn a() {}\r\n\r\n// done\r\nfn b() {}\r\n".to_string(),
+            new_content: String::new(),
+            path: "src/lib.rs".to_string(),
+        });
+        let buffer = &engine.buffer;
+        assert_eq!(buffer.lines, ["fn a() {}", "", "// done", "fn b() {}"]);
+
+        // Read each highlighted span back out of the buffer line it falls on
+        let text_of = |token_type| {
+            buffer
+                .old_highlights
+                .iter()
+                .filter(|span| span.token_type == token_type)
+                .map(|span| {
+                    let line = buffer
+                        .old_content_line_offsets
+                        .iter()
+                        .rposition(|&offset| offset <= span.start)
+                        .unwrap();
+                    let start = span.start - buffer.old_content_line_offsets[line];
+                    buffer.lines[line][start..start + span.end - span.start].to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(text_of(TokenType::Comment), ["// done"]);
+        assert_eq!(text_of(TokenType::Function), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_max_files_summarizes_the_rest() {
+        let commit = crate::preview::sample_commit();
+        let mut engine = AnimationEngine::new(30);
+        engine.set_max_files(Some(1));
+        engine.load_commit(&commit);
+
+        let opened: Vec<&str> = engine
+            .steps()
+            .iter()
+            .filter_map(|step| match step {
+                AnimationStep::SwitchFile { path, .. } => Some(path.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            opened,
+            [commit.changes[commit.sorted_file_indices()[0]]
+                .path
+                .as_str()]
+        );
+
+        engine.fast_forward();
+        assert!(engine
+            .terminal_lines
+            .iter()
+            .any(|line| line.ends_with("… and 2 more files")));
+        assert!(engine
+            .terminal_lines
+            .iter()
+            .any(|line| line.contains("git commit")));
+    }
+
+    #[test]
+    fn test_root_commit_types_new_file_from_empty() {
+        use LineChangeType::Addition;
+
+        let new_content = "first\n  second\n";
+        let change = FileChange {
+            hunks: vec![diff_hunk(
+                0,
+                vec![
+                    diff_line(Addition, "first"),
+                    diff_line(Addition, "  second"),
+                ],
+            )],
+            ..file_change("README", FileStatus::Added, None, Some(new_content))
+        };
+        let commit = CommitMetadata {
+            parent_hash: None,
+            ..test_commit("Initial commit", vec![change])
+        };
+
+        let mut engine = AnimationEngine::new(30);
+        engine.load_commit(&commit);
+        engine.fast_forward();
+
+        assert_eq!(engine.buffer.lines, vec!["first", "  second"]);
+        let output: Vec<&str> = engine.terminal_lines.iter().map(String::as_str).collect();
+        assert!(output
+            .iter()
+            .any(|l| l.contains("[main (root-commit) 0123456]")));
+        assert!(output.iter().any(|l| l.contains("[new branch]")));
+    }
+}
@@ -0,0 +1,91 @@
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use serde_json::json;
+
+/// Writes an asciinema v2 cast: a JSON header line followed by one JSON
+/// array per output event.
+///
+/// See <https://docs.asciinema.org/manual/asciicast/v2/>
+pub struct CastWriter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> CastWriter<W> {
+    pub fn new(mut out: W, width: u16, height: u16) -> Result<Self> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let header = json!({
+            "version": 2,
+            "width": width,
+            "height": height,
+            "timestamp": timestamp,
+            "env": { "TERM": "xterm-256color" },
+        });
+        writeln!(out, "{}", header)?;
+        Ok(Self { out })
+    }
+
+    /// Record terminal output `data` at `time_ms` milliseconds into the cast
+    pub fn write_output(&mut self, time_ms: u64, data: &str) -> Result<()> {
+        let event = json!([time_ms as f64 / 1000.0, "o", data]);
+        writeln!(self.out, "{}", event)?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W> {
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// In-memory writer whose contents can be drained while a terminal backend
+/// still owns a clone of it
+#[derive(Clone, Default)]
+pub struct OutputCapture(Rc<RefCell<Vec<u8>>>);
+
+impl OutputCapture {
+    /// Return everything written since the last call
+    pub fn take(&self) -> String {
+        let bytes = std::mem::take(&mut *self.0.borrow_mut());
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
+
+impl Write for OutputCapture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_writes_header_and_events() {
+        let mut cast = CastWriter::new(Vec::new(), 80, 24).unwrap();
+        cast.write_output(0, "hello").unwrap();
+        cast.write_output(1500, "\x1b[1;1H").unwrap();
+        let out = String::from_utf8(cast.finish().unwrap()).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+
+        let header: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(header["version"], 2);
+        assert_eq!(header["width"], 80);
+        assert_eq!(header["height"], 24);
+
+        assert_eq!(lines[1], r#"[0.0,"o","hello"]"#);
+        assert_eq!(lines[2], r#"[1.5,"o","\u001b[1;1H"]"#);
+    }
+}
@@ -13,10 +13,60 @@ pub struct Config {
     pub background: bool,
     #[serde(default = "default_order")]
     pub order: String,
-    #[serde(default = "default_loop")]
+    #[serde(default = "default_loop", rename = "loop", alias = "loop_playback")]
     pub loop_playback: bool,
+    #[serde(default)]
+    pub random_no_repeat: bool,
     #[serde(default = "default_ignore_patterns")]
     pub ignore_patterns: Vec<String>,
+    #[serde(default = "default_terminal_history")]
+    pub terminal_history: usize,
+    #[serde(default = "default_terminal_success_markers")]
+    pub terminal_success_markers: Vec<String>,
+    #[serde(default = "default_terminal_error_markers")]
+    pub terminal_error_markers: Vec<String>,
+    #[serde(default = "default_wrap")]
+    pub wrap: bool,
+    #[serde(default = "default_tab_width")]
+    pub tab_width: usize,
+    #[serde(default = "default_pause_scale")]
+    pub pause_scale: f64,
+    #[serde(default = "default_hunk_pause")]
+    pub hunk_pause: f64,
+    #[serde(default = "default_intro")]
+    pub intro: bool,
+    #[serde(default = "default_cursor_blink_ms")]
+    pub cursor_blink_ms: u64,
+    #[serde(default)]
+    pub type_indentation: bool,
+    #[serde(default)]
+    pub comment_banner: bool,
+    #[serde(default)]
+    pub animate_deletes: bool,
+    #[serde(default = "default_excluded_preview_lines")]
+    pub excluded_preview_lines: usize,
+    #[serde(default)]
+    pub ascii: bool,
+    #[serde(default = "default_flavor")]
+    pub flavor: String,
+    #[serde(default)]
+    pub clock: bool,
+    #[serde(default = "default_clock_format")]
+    pub clock_format: String,
+    #[serde(default)]
+    pub respect_gitignore: bool,
+    #[serde(default)]
+    pub extra_excluded_files: Vec<String>,
+    #[serde(default)]
+    pub extra_excluded_patterns: Vec<String>,
+    #[serde(default)]
+    pub unexcluded_files: Vec<String>,
+    #[serde(default = "default_file_tree_width_pct")]
+    pub file_tree_width_pct: u16,
+    #[serde(default = "default_editor_height_pct")]
+    pub editor_height_pct: u16,
+    #[serde(default)]
+    pub rotate_themes: bool,
 }
 
 fn default_theme() -> String {
@@ -43,6 +93,62 @@ fn default_ignore_patterns() -> Vec<String> {
     Vec::new()
 }
 
+fn default_terminal_history() -> usize {
+    crate::animation::DEFAULT_MAX_TERMINAL_LINES
+}
+
+fn default_terminal_success_markers() -> Vec<String> {
+    crate::panes::OutputMarkers::default().success
+}
+
+fn default_terminal_error_markers() -> Vec<String> {
+    crate::panes::OutputMarkers::default().error
+}
+
+fn default_wrap() -> bool {
+    true
+}
+
+fn default_tab_width() -> usize {
+    crate::animation::DEFAULT_TAB_WIDTH
+}
+
+fn default_pause_scale() -> f64 {
+    crate::animation::PacingConfig::default().scale
+}
+
+fn default_hunk_pause() -> f64 {
+    crate::animation::PacingConfig::default().hunk
+}
+
+fn default_intro() -> bool {
+    true
+}
+
+fn default_excluded_preview_lines() -> usize {
+    crate::animation::DEFAULT_EXCLUDED_PREVIEW_LINES
+}
+
+fn default_flavor() -> String {
+    "fun".to_string()
+}
+
+fn default_clock_format() -> String {
+    "24h".to_string()
+}
+
+fn default_cursor_blink_ms() -> u64 {
+    crate::animation::DEFAULT_CURSOR_BLINK_MS
+}
+
+fn default_file_tree_width_pct() -> u16 {
+    30
+}
+
+fn default_editor_height_pct() -> u16 {
+    80
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -51,7 +157,32 @@ impl Default for Config {
             background: default_background(),
             order: default_order(),
             loop_playback: default_loop(),
+            random_no_repeat: false,
             ignore_patterns: default_ignore_patterns(),
+            terminal_history: default_terminal_history(),
+            terminal_success_markers: default_terminal_success_markers(),
+            terminal_error_markers: default_terminal_error_markers(),
+            wrap: default_wrap(),
+            tab_width: default_tab_width(),
+            pause_scale: default_pause_scale(),
+            hunk_pause: default_hunk_pause(),
+            intro: default_intro(),
+            cursor_blink_ms: default_cursor_blink_ms(),
+            type_indentation: false,
+            comment_banner: false,
+            animate_deletes: false,
+            excluded_preview_lines: default_excluded_preview_lines(),
+            ascii: false,
+            flavor: default_flavor(),
+            clock: false,
+            clock_format: default_clock_format(),
+            respect_gitignore: false,
+            extra_excluded_files: Vec::new(),
+            extra_excluded_patterns: Vec::new(),
+            unexcluded_files: Vec::new(),
+            file_tree_width_pct: default_file_tree_width_pct(),
+            editor_height_pct: default_editor_height_pct(),
+            rotate_themes: false,
         }
     }
 }
@@ -71,6 +202,55 @@ impl Config {
             .with_context(|| format!("Failed to parse config file: {}", config_path.display()))
     }
 
+    /// Keys explicitly set in the config file (empty if the file does not exist)
+    pub fn file_keys() -> Result<Vec<String>> {
+        let config_path = Self::config_path()?;
+
+        if !config_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let contents = fs::read_to_string(&config_path)
+            .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
+        let table: toml::Table = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config file: {}", config_path.display()))?;
+
+        // Report legacy aliases under their canonical key
+        Ok(table
+            .keys()
+            .map(|key| match key.as_str() {
+                "loop_playback" => "loop".to_string(),
+                _ => key.clone(),
+            })
+            .collect())
+    }
+
+    /// Render the effective config as TOML, marking values that fall back to defaults
+    pub fn to_annotated_toml(&self, file_keys: &[String]) -> Result<String> {
+        let rendered = toml::to_string(self).context("Failed to serialize config")?;
+        let mut known_keys = Vec::new();
+
+        let mut output = String::new();
+        for line in rendered.lines() {
+            let key = line.split(" = ").next().unwrap_or("").trim();
+            known_keys.push(key.to_string());
+            if file_keys.iter().any(|k| k == key) {
+                output.push_str(&format!("{}\n", line));
+            } else {
+                output.push_str(&format!("{}  # default\n", line));
+            }
+        }
+
+        for key in file_keys.iter().filter(|k| !known_keys.contains(k)) {
+            output.push_str(&format!(
+                "# unknown key '{}' in config file is ignored\n",
+                key
+            ));
+        }
+
+        Ok(output)
+    }
+
     pub fn save(&self) -> Result<()> {
         let config_path = Self::config_path()?;
 
@@ -164,22 +344,4 @@ impl Config {
 
         Ok(config_dir.join("config.toml"))
     }
-
-    #[allow(dead_code)]
-    pub fn themes_dir() -> Result<PathBuf> {
-        let config_dir = dirs::home_dir()
-            .context("Failed to determine home directory")?
-            .join(".config")
-            .join("gitlogue")
-            .join("themes");
-
-        fs::create_dir_all(&config_dir).with_context(|| {
-            format!(
-                "Failed to create themes directory: {}",
-                config_dir.display()
-            )
-        })?;
-
-        Ok(config_dir)
-    }
 }
@@ -1,10 +1,17 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Local, Utc};
 use chrono_english::{parse_date_string, Dialect};
-use git2::{Commit as Git2Commit, Delta, DiffOptions, Oid, Repository};
+use git2::{
+    BranchType, Commit as Git2Commit, Delta, DiffFindOptions, DiffOptions, Oid, Repository,
+};
 use globset::{Glob, GlobSet, GlobSetBuilder};
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use regex::{Regex, RegexBuilder};
 use std::cell::RefCell;
+use std::collections::{BTreeMap, BinaryHeap, HashSet};
+use std::fmt;
 use std::path::Path;
 use std::sync::OnceLock;
 
@@ -95,30 +102,102 @@ pub fn init_ignore_patterns(patterns: &[String]) -> Result<()> {
     Ok(())
 }
 
-/// Check if a file should be excluded from diff animation
-pub fn should_exclude_file(path: &str) -> bool {
+/// Lock and generated files skipped by the animation: the built-in lists plus
+/// user additions, minus any entries the user chose to un-exclude
+#[derive(Debug, Clone)]
+pub struct ExcludedFiles {
+    files: Vec<String>,
+    patterns: Vec<String>,
+}
+
+impl Default for ExcludedFiles {
+    fn default() -> Self {
+        Self::new(&[], &[], &[])
+    }
+}
+
+impl ExcludedFiles {
+    /// Merge user-configured exclusions with the built-in ones. Patterns are
+    /// file name suffixes or path fragments (a leading `*` is ignored, so
+    /// `*.pb.go` works too); `unexcluded` entries are removed from both lists.
+    pub fn new(extra_files: &[String], extra_patterns: &[String], unexcluded: &[String]) -> Self {
+        let normalize = |pattern: &str| pattern.trim_start_matches('*').to_string();
+        let unexcluded: Vec<String> = unexcluded.iter().map(|p| normalize(p)).collect();
+        let keep = |entry: &String| !unexcluded.contains(entry);
+
+        let files = EXCLUDED_FILES
+            .iter()
+            .map(|f| f.to_string())
+            .chain(extra_files.iter().cloned())
+            .filter(keep)
+            .collect();
+        let patterns = EXCLUDED_PATTERNS
+            .iter()
+            .map(|p| p.to_string())
+            .chain(extra_patterns.iter().map(|p| normalize(p)))
+            .filter(|p| !p.is_empty())
+            .filter(keep)
+            .collect();
+
+        Self { files, patterns }
+    }
+}
+
+/// Why a changed file is not animated in full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExclusionReason {
+    /// A lock file or generated file
+    Generated,
+    /// Matches one of the user's `ignore_patterns`
+    IgnorePattern,
+    /// Matches the repository's ignore rules
+    Gitignored,
+    /// More changed lines than are worth typing
+    TooManyChanges(usize),
+}
+
+impl ExclusionReason {
+    /// Whether the start of the file may still be typed. Files the user
+    /// chose to ignore stay hidden.
+    pub fn allows_preview(self) -> bool {
+        matches!(self, Self::Generated | Self::TooManyChanges(_))
+    }
+}
+
+impl fmt::Display for ExclusionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Generated => write!(f, "lock/generated file"),
+            Self::IgnorePattern => write!(f, "ignored pattern"),
+            Self::Gitignored => write!(f, "gitignored"),
+            Self::TooManyChanges(lines) => write!(f, "too many changes ({} lines)", lines),
+        }
+    }
+}
+
+/// Why `path` is excluded from diff animation by name, if it is: a
+/// user-defined ignore pattern or a lock/generated file
+pub fn exclusion_reason(path: &str, excluded: &ExcludedFiles) -> Option<ExclusionReason> {
     // Check user-defined patterns first
     if let Some(patterns) = USER_PATTERNS.get() {
         if patterns.is_match(path) {
-            return true;
+            return Some(ExclusionReason::IgnorePattern);
         }
     }
 
     let filename = path.rsplit('/').next().unwrap_or(path);
 
     // Check if it's a lock file
-    if EXCLUDED_FILES.contains(&filename) {
-        return true;
+    if excluded.files.iter().any(|f| f == filename) {
+        return Some(ExclusionReason::Generated);
     }
 
     // Check if it matches excluded patterns
-    for pattern in EXCLUDED_PATTERNS {
-        if filename.ends_with(pattern) || path.contains(pattern) {
-            return true;
-        }
-    }
-
-    false
+    excluded
+        .patterns
+        .iter()
+        .any(|pattern| filename.ends_with(pattern.as_str()) || path.contains(pattern.as_str()))
+        .then_some(ExclusionReason::Generated)
 }
 
 // Check if a commit matches the author filter pattern (case-insensitive partial match)
@@ -131,6 +210,20 @@ fn matches_author(commit: &Git2Commit, pattern: &str) -> bool {
     name.to_lowercase().contains(&pattern_lower) || email.to_lowercase().contains(&pattern_lower)
 }
 
+// Build a case-insensitive commit message matcher (substring, or regex when requested)
+pub fn build_message_matcher(pattern: &str, use_regex: bool) -> Result<Regex> {
+    let source = if use_regex {
+        pattern.to_string()
+    } else {
+        regex::escape(pattern)
+    };
+
+    RegexBuilder::new(&source)
+        .case_insensitive(true)
+        .build()
+        .with_context(|| format!("Invalid message pattern: {}", pattern))
+}
+
 // Parse a date string using chrono-english (supports Git-like formats)
 pub fn parse_date(input: &str) -> Result<DateTime<Utc>> {
     let now = Local::now();
@@ -140,6 +233,38 @@ pub fn parse_date(input: &str) -> Result<DateTime<Utc>> {
         .with_context(|| format!("Invalid date format: '{}'. Use formats like '2024-01-01', '1 week ago', 'yesterday'", input))
 }
 
+/// Abbreviate a commit hash to 7 characters, as git does. Shorter (or
+/// otherwise unexpected) input is returned unchanged rather than panicking.
+pub fn short_hash(hash: &str) -> &str {
+    hash.get(..7).unwrap_or(hash)
+}
+
+/// Describe `date` relative to `now`, e.g. "3 months ago" or "in 2 days"
+/// for future-dated commits. Both are UTC, so time zones do not matter.
+pub fn format_relative_date(date: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    const UNITS: &[(i64, &str)] = &[
+        (365 * 24 * 60 * 60, "year"),
+        (30 * 24 * 60 * 60, "month"),
+        (7 * 24 * 60 * 60, "week"),
+        (24 * 60 * 60, "day"),
+        (60 * 60, "hour"),
+        (60, "minute"),
+    ];
+
+    let seconds = (now - date).num_seconds();
+    let Some(&(size, unit)) = UNITS.iter().find(|(size, _)| seconds.abs() >= *size) else {
+        return "just now".to_string();
+    };
+
+    let count = seconds.abs() / size;
+    let plural = if count == 1 { "" } else { "s" };
+    if seconds < 0 {
+        format!("in {} {}{}", count, unit, plural)
+    } else {
+        format!("{} {}{} ago", count, unit, plural)
+    }
+}
+
 // Check if a commit date is within the specified date range
 fn matches_date_filter(
     commit: &Git2Commit,
@@ -164,19 +289,87 @@ fn matches_date_filter(
     Ok(true)
 }
 
+/// Walks history from one or more starting commits on demand, newest commit first
+/// (the same commit-date order as a default revwalk). Only the commits
+/// visited so far are kept in memory.
+struct CommitWalker {
+    queue: BinaryHeap<(i64, Oid)>,
+    seen: HashSet<Oid>,
+}
+
+impl CommitWalker {
+    fn new(starts: &[Git2Commit]) -> Self {
+        let mut walker = Self {
+            queue: BinaryHeap::new(),
+            seen: HashSet::new(),
+        };
+        for start in starts {
+            walker.push(start);
+        }
+        walker
+    }
+
+    fn push(&mut self, commit: &Git2Commit) {
+        if self.seen.insert(commit.id()) {
+            self.queue.push((commit.time().seconds(), commit.id()));
+        }
+    }
+
+    fn next<'repo>(&mut self, repo: &'repo Repository) -> Result<Option<Git2Commit<'repo>>> {
+        let Some((_, oid)) = self.queue.pop() else {
+            return Ok(None);
+        };
+        let commit = repo.find_commit(oid)?;
+        for parent in commit.parents() {
+            self.push(&parent);
+        }
+        Ok(Some(commit))
+    }
+}
+
+#[derive(Default)]
+struct Shuffle {
+    // Drawn from the back
+    remaining: Vec<usize>,
+    last: Option<usize>,
+}
+
 pub struct GitRepository {
     repo: Repository,
+    // Full candidate list, only built for modes that need all of history up front
     commit_cache: RefCell<Option<Vec<Oid>>>,
+    // Lazy history walk used by desc playback when the cache has not been built
+    commit_walker: RefCell<Option<CommitWalker>>,
     // Shared index for both cache-based playback (asc/desc) and range playback.
     // These modes are mutually exclusive based on CLI arguments.
     commit_index: RefCell<usize>,
     commit_range: RefCell<Option<Vec<Oid>>>,
+    // Random source for commit selection (seedable for reproducible playback)
+    rng: RefCell<StdRng>,
+    // Shuffled candidates still to play, when random order plays every
+    // commit once before repeating any
+    shuffle: RefCell<Option<Shuffle>>,
     author_filter: Option<String>,
+    author_exclude_filter: Option<String>,
+    message_filter: Option<Regex>,
     before_filter: Option<DateTime<Utc>>,
     after_filter: Option<DateTime<Utc>>,
+    // Only commits that change this path (a file or directory, relative to
+    // the repository root)
+    path_filter: Option<String>,
+    // Only files with these statuses are played; commits without any are skipped
+    status_filter: Option<HashSet<FileStatus>>,
+    // Keep merge commits as candidates, diffed against `merge_parent` (0-based)
+    include_merges: bool,
+    merge_parent: usize,
+    // Also exclude files matched by the repository's ignore rules
+    respect_gitignore: bool,
+    excluded_files: ExcludedFiles,
+    // Commit history is walked from these instead of HEAD
+    start_refs: Vec<Oid>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FileStatus {
     Added,
     Deleted,
@@ -199,6 +392,26 @@ impl FileStatus {
     }
 }
 
+impl std::str::FromStr for FileStatus {
+    type Err = String;
+
+    /// The letter `git status` uses, e.g. `M` or `a`. `U` (unmerged) is
+    /// rejected: committed changes never have conflicts
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "A" => Ok(FileStatus::Added),
+            "D" => Ok(FileStatus::Deleted),
+            "M" => Ok(FileStatus::Modified),
+            "R" => Ok(FileStatus::Renamed),
+            "C" => Ok(FileStatus::Copied),
+            _ => Err(format!(
+                "invalid file status '{}' (expected one of A, D, M, R, C)",
+                s
+            )),
+        }
+    }
+}
+
 impl From<Delta> for FileStatus {
     fn from(delta: Delta) -> Self {
         match delta {
@@ -248,10 +461,11 @@ pub struct FileChange {
     #[allow(dead_code)]
     pub old_path: Option<String>,
     pub status: FileStatus,
-    #[allow(dead_code)]
     pub is_binary: bool,
+    /// Size of the file in bytes after the commit, or before it for a deletion
+    pub size: usize,
     pub is_excluded: bool,
-    pub exclusion_reason: Option<String>,
+    pub exclusion_reason: Option<ExclusionReason>,
     pub old_content: Option<String>,
     #[allow(dead_code)]
     pub new_content: Option<String>,
@@ -266,28 +480,146 @@ pub struct CommitMetadata {
     pub author: String,
     pub date: DateTime<Utc>,
     pub message: String,
+    /// Parent the changes were diffed against; None for a root commit
+    pub parent_hash: Option<String>,
     pub changes: Vec<FileChange>,
 }
 
+/// Lightweight commit information (no diff extraction), used for listing
+#[derive(Debug, Clone)]
+pub struct CommitSummary {
+    pub hash: String,
+    pub author: String,
+    pub date: DateTime<Utc>,
+    pub summary: String,
+}
+
 impl CommitMetadata {
     /// Returns indices sorted in FileTree display order (directory -> filename)
     pub fn sorted_file_indices(&self) -> Vec<usize> {
-        let mut indices: Vec<usize> = (0..self.changes.len()).collect();
-        indices.sort_by_key(|&index| {
-            let path = &self.changes[index].path;
-            let parts: Vec<&str> = path.split('/').collect();
-
-            if parts.len() == 1 {
-                // Root level file: ("", filename)
-                (String::new(), path.clone())
-            } else {
-                // File in directory: (directory, filename)
-                let dir = parts[..parts.len() - 1].join("/");
-                let filename = parts[parts.len() - 1].to_string();
-                (dir, filename)
+        self.sorted_file_indices_by(|_| ())
+    }
+
+    /// Returns indices grouped by directory as in the FileTree, with the
+    /// directories ordered by their first file and the files in each by
+    /// `key`, ties falling back to directory -> filename order
+    pub fn sorted_file_indices_by<K: Ord>(&self, key: impl Fn(&FileChange) -> K) -> Vec<usize> {
+        // Root level files go under "", which sorts first
+        let mut dirs: BTreeMap<&str, Vec<(&str, usize)>> = BTreeMap::new();
+        for (index, change) in self.changes.iter().enumerate() {
+            let (dir, filename) = change.path.rsplit_once('/').unwrap_or(("", &change.path));
+            dirs.entry(dir).or_default().push((filename, index));
+        }
+
+        let by_key = |a: usize, b: usize| key(&self.changes[a]).cmp(&key(&self.changes[b]));
+        let mut groups: Vec<Vec<usize>> = dirs
+            .into_values()
+            .map(|mut files| {
+                files.sort_by(|a, b| by_key(a.1, b.1).then_with(|| a.0.cmp(b.0)));
+                files.into_iter().map(|(_, index)| index).collect()
+            })
+            .collect();
+        // Stable, so directories with equal keys stay in name order
+        groups.sort_by(|a, b| by_key(a[0], b[0]));
+        groups.concat()
+    }
+
+    /// Added and deleted lines across every changed file
+    pub fn line_counts(&self) -> (usize, usize) {
+        self.changes
+            .iter()
+            .map(FileChange::line_counts)
+            .fold((0, 0), |(a, d), (fa, fd)| (a + fa, d + fd))
+    }
+
+    /// The same commit as if it were being undone: additions become
+    /// deletions and each file goes from its new content back to the old one
+    pub fn reversed(&self) -> CommitMetadata {
+        CommitMetadata {
+            changes: self.changes.iter().map(FileChange::reversed).collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl FileChange {
+    /// Added and deleted lines across all hunks
+    pub fn line_counts(&self) -> (usize, usize) {
+        let mut additions = 0;
+        let mut deletions = 0;
+        for line in self.hunks.iter().flat_map(|hunk| &hunk.lines) {
+            match line.change_type {
+                LineChangeType::Addition => additions += 1,
+                LineChangeType::Deletion => deletions += 1,
+                LineChangeType::Context => {}
+            }
+        }
+        (additions, deletions)
+    }
+
+    fn reversed(&self) -> FileChange {
+        let status = match self.status {
+            FileStatus::Added => FileStatus::Deleted,
+            FileStatus::Deleted => FileStatus::Added,
+            status => status,
+        };
+        let (path, old_path) = match &self.old_path {
+            Some(old_path) if matches!(self.status, FileStatus::Renamed) => {
+                (old_path.clone(), Some(self.path.clone()))
+            }
+            _ => (self.path.clone(), self.old_path.clone()),
+        };
+
+        FileChange {
+            path,
+            old_path,
+            status,
+            old_content: self.new_content.clone(),
+            new_content: self.old_content.clone(),
+            hunks: self.hunks.iter().map(DiffHunk::reversed).collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl DiffHunk {
+    fn reversed(&self) -> DiffHunk {
+        let mut lines = Vec::with_capacity(self.lines.len());
+        let mut deletions = Vec::new();
+        let mut additions = Vec::new();
+
+        // Swap change types, keeping deletions before additions within each block
+        for line in &self.lines {
+            let reversed = LineChange {
+                change_type: match line.change_type {
+                    LineChangeType::Addition => LineChangeType::Deletion,
+                    LineChangeType::Deletion => LineChangeType::Addition,
+                    LineChangeType::Context => LineChangeType::Context,
+                },
+                content: line.content.clone(),
+                old_line_no: line.new_line_no,
+                new_line_no: line.old_line_no,
+            };
+            match reversed.change_type {
+                LineChangeType::Deletion => deletions.push(reversed),
+                LineChangeType::Addition => additions.push(reversed),
+                LineChangeType::Context => {
+                    lines.append(&mut deletions);
+                    lines.append(&mut additions);
+                    lines.push(reversed);
+                }
             }
-        });
-        indices
+        }
+        lines.append(&mut deletions);
+        lines.append(&mut additions);
+
+        DiffHunk {
+            old_start: self.new_start,
+            old_lines: self.new_lines,
+            new_start: self.old_start,
+            new_lines: self.old_lines,
+            lines,
+        }
     }
 }
 
@@ -297,11 +629,23 @@ impl GitRepository {
         Ok(Self {
             repo,
             commit_cache: RefCell::new(None),
+            commit_walker: RefCell::new(None),
             commit_index: RefCell::new(0),
             commit_range: RefCell::new(None),
+            rng: RefCell::new(StdRng::from_os_rng()),
+            shuffle: RefCell::new(None),
             author_filter: None,
+            author_exclude_filter: None,
+            message_filter: None,
             before_filter: None,
             after_filter: None,
+            path_filter: None,
+            status_filter: None,
+            include_merges: false,
+            merge_parent: 0,
+            respect_gitignore: false,
+            excluded_files: ExcludedFiles::default(),
+            start_refs: Vec::new(),
         })
     }
 
@@ -313,21 +657,97 @@ impl GitRepository {
 
         let commit = obj.peel_to_commit().context("Object is not a commit")?;
 
-        Self::extract_metadata_with_changes(&self.repo, &commit)
+        let metadata = self.extract_metadata_with_changes(&commit)?;
+        if self.status_filter.is_some() && metadata.changes.is_empty() {
+            anyhow::bail!(
+                "No files in commit {} match the status filter",
+                short_hash(&metadata.hash)
+            );
+        }
+        Ok(metadata)
+    }
+
+    pub fn get_commit_summary(&self, hash: &str) -> Result<CommitSummary> {
+        let obj = self
+            .repo
+            .revparse_single(hash)
+            .context("Invalid commit hash or commit not found")?;
+
+        let commit = obj.peel_to_commit().context("Object is not a commit")?;
+
+        Ok(Self::extract_summary(&commit))
+    }
+
+    /// Commits eligible for playback, oldest first, without extracting diffs
+    pub fn list_commits(&self) -> Result<Vec<CommitSummary>> {
+        let oids: Vec<Oid> = if let Some(range) = self.commit_range.borrow().as_ref() {
+            range.clone()
+        } else {
+            self.populate_cache()?;
+            let cache = self.commit_cache.borrow();
+            cache.as_ref().unwrap().iter().rev().copied().collect()
+        };
+
+        oids.into_iter()
+            .map(|oid| {
+                let commit = self.repo.find_commit(oid)?;
+                Ok(Self::extract_summary(&commit))
+            })
+            .collect()
     }
 
     pub fn random_commit(&self) -> Result<CommitMetadata> {
+        let commit = self.repo.find_commit(self.random_oid()?)?;
+        self.extract_metadata_with_changes(&commit)
+    }
+
+    /// The next `count` random picks, drawn the same way as `random_commit`
+    /// or `random_range_commit`, so with a seed they are what playback shows
+    pub fn random_summaries(&self, count: usize) -> Result<Vec<CommitSummary>> {
+        (0..count)
+            .map(|_| {
+                let oid = if self.commit_range.borrow().is_some() {
+                    self.random_range_oid()?
+                } else {
+                    self.random_oid()?
+                };
+                Ok(Self::extract_summary(&self.repo.find_commit(oid)?))
+            })
+            .collect()
+    }
+
+    fn random_oid(&self) -> Result<Oid> {
         self.populate_cache()?;
 
         let cache = self.commit_cache.borrow();
         let candidates = cache.as_ref().unwrap();
 
-        let selected_oid = candidates
-            .get(rand::rng().random_range(0..candidates.len()))
-            .context("Failed to select random commit")?;
+        candidates
+            .get(self.random_index(candidates.len()))
+            .copied()
+            .context("Failed to select random commit")
+    }
 
-        let commit = self.repo.find_commit(*selected_oid)?;
-        Self::extract_metadata_with_changes(&self.repo, &commit)
+    // Index of the next random candidate out of `len`: an independent draw,
+    // or the next one in the shuffle with `--random-no-repeat`
+    fn random_index(&self, len: usize) -> usize {
+        let mut rng = self.rng.borrow_mut();
+        let mut shuffle = self.shuffle.borrow_mut();
+        let Some(shuffle) = shuffle.as_mut() else {
+            return rng.random_range(0..len);
+        };
+
+        if shuffle.remaining.is_empty() {
+            shuffle.remaining = (0..len).collect();
+            shuffle.remaining.shuffle(&mut *rng);
+            // Do not repeat the last commit of the previous pass right away
+            if len > 1 && shuffle.remaining.last() == shuffle.last.as_ref() {
+                shuffle.remaining.swap(0, len - 1);
+            }
+        }
+        let index = shuffle.remaining.pop().unwrap_or_default();
+        shuffle.last = Some(index);
+        index
     }
 
     pub fn next_asc_commit(&self) -> Result<CommitMetadata> {
@@ -354,11 +774,13 @@ impl GitRepository {
         *index += 1;
 
         let commit = self.repo.find_commit(*selected_oid)?;
-        Self::extract_metadata_with_changes(&self.repo, &commit)
+        self.extract_metadata_with_changes(&commit)
     }
 
     pub fn next_desc_commit(&self) -> Result<CommitMetadata> {
-        self.populate_cache()?;
+        if self.commit_cache.borrow().is_none() {
+            return self.next_walked_commit();
+        }
 
         let cache = self.commit_cache.borrow();
         let candidates = cache.as_ref().unwrap();
@@ -378,17 +800,107 @@ impl GitRepository {
         *index += 1;
 
         let commit = self.repo.find_commit(*selected_oid)?;
-        Self::extract_metadata_with_changes(&self.repo, &commit)
+        self.extract_metadata_with_changes(&commit)
+    }
+
+    // Pull the next matching commit from the lazy history walk, so desc
+    // playback can start without enumerating the whole repository
+    fn next_walked_commit(&self) -> Result<CommitMetadata> {
+        let mut walker = self.commit_walker.borrow_mut();
+        if walker.is_none() {
+            let starts = self.start_commits()?;
+            *walker = Some(CommitWalker::new(&starts));
+        }
+        let walker = walker.as_mut().unwrap();
+        let mut index = self.commit_index.borrow_mut();
+
+        while let Some(commit) = walker.next(&self.repo)? {
+            if self.matches_filters(&commit)? {
+                *index += 1;
+                return self.extract_metadata_with_changes(&commit);
+            }
+        }
+
+        if *index > 0 {
+            anyhow::bail!("All commits have been played");
+        }
+        if self.has_filters() {
+            anyhow::bail!("No commits found matching the filters in repository");
+        }
+        anyhow::bail!("No non-merge commits found in repository");
+    }
+
+    /// Number of commits played from the current list (1-based position of
+    /// the commit returned last)
+    pub fn current_index(&self) -> usize {
+        *self.commit_index.borrow()
+    }
+
+    /// Length of the playback list, or None while desc playback is walking
+    /// history lazily and the length is not known yet
+    pub fn total_count(&self) -> Option<usize> {
+        if let Some(range) = self.commit_range.borrow().as_ref() {
+            return Some(range.len());
+        }
+        self.commit_cache.borrow().as_ref().map(Vec::len)
+    }
+
+    /// Whether every commit in the playback list has been played, as opposed
+    /// to the last commit failing to load. Random order never runs out.
+    pub fn is_exhausted(&self) -> bool {
+        match self.total_count() {
+            Some(total) => self.current_index() >= total,
+            None => self
+                .commit_walker
+                .borrow()
+                .as_ref()
+                .is_some_and(|walker| walker.queue.is_empty()),
+        }
+    }
+
+    /// Skip the first `index` commits of the playback list, to resume an
+    /// earlier session. Starts from the beginning instead when the list has
+    /// become shorter since. Builds the whole list, so positions are known.
+    pub fn set_commit_index(&self, index: usize) -> Result<()> {
+        if self.commit_range.borrow().is_none() {
+            self.populate_cache()?;
+        }
+        let total = self.total_count().unwrap_or_default();
+        *self.commit_index.borrow_mut() = if index < total { index } else { 0 };
+        Ok(())
     }
 
     pub fn reset_index(&self) {
         *self.commit_index.borrow_mut() = 0;
+        *self.commit_walker.borrow_mut() = None;
+    }
+
+    /// Seed random commit selection so playback order is reproducible
+    pub fn set_seed(&self, seed: u64) {
+        *self.rng.borrow_mut() = StdRng::seed_from_u64(seed);
+    }
+
+    /// In random order, play every candidate once in a shuffled order before
+    /// reshuffling, instead of drawing each commit independently
+    pub fn set_random_no_repeat(&mut self, no_repeat: bool) {
+        *self.shuffle.get_mut() = no_repeat.then(Shuffle::default);
     }
 
     pub fn set_author_filter(&mut self, author: Option<String>) {
         self.author_filter = author;
     }
 
+    pub fn set_author_exclude_filter(&mut self, author: Option<String>) {
+        self.author_exclude_filter = author;
+    }
+
+    pub fn set_message_filter(&mut self, pattern: Option<String>, use_regex: bool) -> Result<()> {
+        self.message_filter = pattern
+            .map(|p| build_message_matcher(&p, use_regex))
+            .transpose()?;
+        Ok(())
+    }
+
     pub fn set_before_filter(&mut self, before: Option<DateTime<Utc>>) {
         self.before_filter = before;
     }
@@ -397,8 +909,102 @@ impl GitRepository {
         self.after_filter = after;
     }
 
-    pub fn set_commit_range(&self, range: &str) -> Result<()> {
-        let commits = self.parse_commit_range(range)?;
+    /// Only play commits that change `path`, like `git log -- <path>`
+    pub fn set_path_filter(&mut self, path: Option<String>) {
+        self.path_filter = path;
+    }
+
+    /// Only play files whose status is in `statuses`, skipping commits that
+    /// have none
+    pub fn set_status_filter(&mut self, statuses: Option<HashSet<FileStatus>>) {
+        self.status_filter = statuses;
+    }
+
+    pub fn set_include_merges(&mut self, include_merges: bool) {
+        self.include_merges = include_merges;
+    }
+
+    /// Diff merge commits against this parent (0-based; first parent by default)
+    pub fn set_merge_parent(&mut self, parent: usize) {
+        self.merge_parent = parent;
+    }
+
+    /// Exclude changed files matched by the repository's `.gitignore` files,
+    /// `.git/info/exclude` and `core.excludesFile`
+    pub fn set_respect_gitignore(&mut self, respect: bool) {
+        self.respect_gitignore = respect;
+    }
+
+    pub fn set_excluded_files(&mut self, excluded: ExcludedFiles) {
+        self.excluded_files = excluded;
+    }
+
+    /// Walk history from `refname` (a branch, tag, remote-tracking branch or
+    /// any revision) instead of HEAD
+    pub fn set_start_ref(&mut self, refname: &str) -> Result<()> {
+        let commit = self
+            .repo
+            .revparse_single(refname)
+            .with_context(|| format!("Ref not found: {}", refname))?
+            .peel_to_commit()
+            .with_context(|| format!("Ref does not point to a commit: {}", refname))?;
+        self.start_refs = vec![commit.id()];
+        Ok(())
+    }
+
+    /// Walk history from every local branch instead of HEAD. Commits shared
+    /// by several branches are only played once.
+    pub fn set_all_refs(&mut self) -> Result<()> {
+        let mut tips = Vec::new();
+        for branch in self.repo.branches(Some(BranchType::Local))? {
+            let (branch, _) = branch?;
+            if let Ok(commit) = branch.get().peel_to_commit() {
+                if !tips.contains(&commit.id()) {
+                    tips.push(commit.id());
+                }
+            }
+        }
+        if tips.is_empty() {
+            anyhow::bail!("No branches found in repository");
+        }
+        self.start_refs = tips;
+        Ok(())
+    }
+
+    fn start_commits(&self) -> Result<Vec<Git2Commit<'_>>> {
+        if self.start_refs.is_empty() {
+            return Ok(vec![self.repo.head()?.peel_to_commit()?]);
+        }
+        self.start_refs
+            .iter()
+            .map(|oid| Ok(self.repo.find_commit(*oid)?))
+            .collect()
+    }
+
+    /// Play `specs` one after another in the order given, each a single
+    /// commit or a range such as `HEAD~5..HEAD`
+    pub fn set_commit_playlist(&self, specs: &[impl AsRef<str>]) -> Result<()> {
+        let mut commits = Vec::new();
+        for spec in specs {
+            let spec = spec.as_ref();
+            if spec.contains("..") {
+                commits.extend(self.parse_commit_range(spec)?);
+            } else {
+                let commit = self
+                    .repo
+                    .revparse_single(spec)
+                    .and_then(|obj| obj.peel_to_commit())
+                    .with_context(|| format!("Invalid commit '{}'", spec))?;
+                // Named commits skip the commit filters, but not a file
+                // filter that would leave nothing to play
+                if self.matches_file_filters(&commit)? {
+                    commits.push(commit.id());
+                }
+            }
+        }
+        if commits.is_empty() {
+            anyhow::bail!("No commits found matching the filters in playlist");
+        }
         *self.commit_range.borrow_mut() = Some(commits);
         *self.commit_index.borrow_mut() = 0;
         Ok(())
@@ -421,7 +1027,7 @@ impl GitRepository {
         *index += 1;
 
         let commit = self.repo.find_commit(*selected_oid)?;
-        Self::extract_metadata_with_changes(&self.repo, &commit)
+        self.extract_metadata_with_changes(&commit)
     }
 
     pub fn next_range_commit_desc(&self) -> Result<CommitMetadata> {
@@ -443,10 +1049,15 @@ impl GitRepository {
         *index += 1;
 
         let commit = self.repo.find_commit(*selected_oid)?;
-        Self::extract_metadata_with_changes(&self.repo, &commit)
+        self.extract_metadata_with_changes(&commit)
     }
 
     pub fn random_range_commit(&self) -> Result<CommitMetadata> {
+        let commit = self.repo.find_commit(self.random_range_oid()?)?;
+        self.extract_metadata_with_changes(&commit)
+    }
+
+    fn random_range_oid(&self) -> Result<Oid> {
         let range = self.commit_range.borrow();
         let commits = range.as_ref().context("Commit range not set")?;
 
@@ -454,15 +1065,85 @@ impl GitRepository {
             anyhow::bail!("No commits in range");
         }
 
-        let selected_oid = commits
-            .get(rand::rng().random_range(0..commits.len()))
-            .context("Failed to select random commit")?;
+        commits
+            .get(self.random_index(commits.len()))
+            .copied()
+            .context("Failed to select random commit")
+    }
 
-        let commit = self.repo.find_commit(*selected_oid)?;
-        Self::extract_metadata_with_changes(&self.repo, &commit)
+    // Whether any commit filter (author, message, date) is active
+    fn has_filters(&self) -> bool {
+        self.author_filter.is_some()
+            || self.author_exclude_filter.is_some()
+            || self.message_filter.is_some()
+            || self.before_filter.is_some()
+            || self.after_filter.is_some()
+            || self.path_filter.is_some()
+            || self.status_filter.is_some()
+    }
+
+    // Whether a commit is playable (non-merge unless merges are included) and
+    // passes the author, message, date, path and status filters
+    fn matches_filters(&self, commit: &Git2Commit) -> Result<bool> {
+        if commit.parent_count() > 1 && !self.include_merges {
+            return Ok(false);
+        }
+        if let Some(ref pattern) = self.author_filter {
+            if !matches_author(commit, pattern) {
+                return Ok(false);
+            }
+        }
+        if let Some(ref pattern) = self.author_exclude_filter {
+            if matches_author(commit, pattern) {
+                return Ok(false);
+            }
+        }
+        if let Some(ref matcher) = self.message_filter {
+            if !matcher.is_match(commit.message().unwrap_or("")) {
+                return Ok(false);
+            }
+        }
+        if !matches_date_filter(
+            commit,
+            self.before_filter.as_ref(),
+            self.after_filter.as_ref(),
+        )? {
+            return Ok(false);
+        }
+        self.matches_file_filters(commit)
     }
 
-    // Collect non-merge commits from a revwalk, applying author and date filters if set
+    // Whether the commit changes a file under the path filter with a status
+    // in the status filter, relative to the parent it is diffed against
+    fn matches_file_filters(&self, commit: &Git2Commit) -> Result<bool> {
+        if self.path_filter.is_none() && self.status_filter.is_none() {
+            return Ok(true);
+        }
+        let parent_tree = Self::diff_parent(commit, self.merge_parent)?
+            .map(|parent| parent.tree())
+            .transpose()?;
+        let mut diff_opts = DiffOptions::new();
+        if let Some(path) = &self.path_filter {
+            diff_opts.pathspec(path);
+        }
+        let mut diff = self.repo.diff_tree_to_tree(
+            parent_tree.as_ref(),
+            Some(&commit.tree()?),
+            Some(&mut diff_opts),
+        )?;
+        find_renames_and_copies(&mut diff)?;
+        Ok(diff
+            .deltas()
+            .any(|delta| self.matches_status(FileStatus::from(delta.status()))))
+    }
+
+    fn matches_status(&self, status: FileStatus) -> bool {
+        self.status_filter
+            .as_ref()
+            .is_none_or(|statuses| statuses.contains(&status))
+    }
+
+    // Collect non-merge commits from a revwalk, applying author, message and date filters if set
     fn collect_commits_from_revwalk(
         &self,
         revwalk: git2::Revwalk,
@@ -471,31 +1152,19 @@ impl GitRepository {
         let mut commits = Vec::new();
         for oid in revwalk.filter_map(|oid| oid.ok()) {
             if let Ok(commit) = self.repo.find_commit(oid) {
-                if commit.parent_count() <= 1 {
-                    if let Some(ref pattern) = self.author_filter {
-                        if !matches_author(&commit, pattern) {
-                            continue;
-                        }
-                    }
-                    if !matches_date_filter(
-                        &commit,
-                        self.before_filter.as_ref(),
-                        self.after_filter.as_ref(),
-                    )? {
-                        continue;
-                    }
+                if self.matches_filters(&commit)? {
                     commits.push(oid);
                 }
             }
         }
 
         if commits.is_empty() {
-            if self.author_filter.is_some()
-                || self.before_filter.is_some()
-                || self.after_filter.is_some()
-            {
+            if self.has_filters() {
                 anyhow::bail!("No commits found matching the filters {}", context);
             }
+            if self.include_merges {
+                anyhow::bail!("No commits found {}", context);
+            }
             anyhow::bail!("No non-merge commits found {}", context);
         }
 
@@ -528,20 +1197,25 @@ impl GitRepository {
             Some(self.repo.revparse_single(parts[0])?.id())
         };
 
-        let end = if parts[1].is_empty() {
-            self.repo.head()?.peel_to_commit()?.id()
+        let ends = if parts[1].is_empty() {
+            self.start_commits()?.iter().map(|c| c.id()).collect()
         } else {
-            self.repo.revparse_single(parts[1])?.id()
+            vec![self.repo.revparse_single(parts[1])?.id()]
         };
 
         let mut revwalk = self.repo.revwalk()?;
-        revwalk.push(end)?;
+        for end in ends {
+            revwalk.push(end)?;
+        }
 
         if let Some(start_oid) = start {
             revwalk.hide(start_oid)?;
         }
 
-        let mut commits = self.collect_commits_from_revwalk(revwalk, "in range")?;
+        // A valid range can still be empty, e.g. when the start is not an
+        // ancestor of the end or both sides name the same commit
+        let mut commits =
+            self.collect_commits_from_revwalk(revwalk, &format!("in range '{}'", range))?;
         commits.reverse();
         Ok(commits)
     }
@@ -550,7 +1224,9 @@ impl GitRepository {
         let mut cache = self.commit_cache.borrow_mut();
         if cache.is_none() {
             let mut revwalk = self.repo.revwalk()?;
-            revwalk.push_head()?;
+            for start in self.start_commits()? {
+                revwalk.push(start.id())?;
+            }
 
             let candidates = self.collect_commits_from_revwalk(revwalk, "in repository")?;
             *cache = Some(candidates);
@@ -558,10 +1234,18 @@ impl GitRepository {
         Ok(())
     }
 
-    fn extract_metadata_with_changes(
-        repo: &Repository,
-        commit: &Git2Commit,
-    ) -> Result<CommitMetadata> {
+    fn extract_summary(commit: &Git2Commit) -> CommitSummary {
+        let author = commit.author();
+        let timestamp = author.when().seconds();
+        CommitSummary {
+            hash: commit.id().to_string(),
+            author: author.name().unwrap_or("Unknown").to_string(),
+            date: DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now),
+            summary: commit.summary().unwrap_or("").trim().to_string(),
+        }
+    }
+
+    fn extract_metadata_with_changes(&self, commit: &Git2Commit) -> Result<CommitMetadata> {
         let hash = commit.id().to_string();
         let author = commit.author();
         let author_name = author.name().unwrap_or("Unknown").to_string();
@@ -569,32 +1253,66 @@ impl GitRepository {
         let date = DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now);
         let message = commit.message().unwrap_or("").trim().to_string();
 
-        let changes = Self::extract_changes(repo, commit)?;
+        let parent = Self::diff_parent(commit, self.merge_parent)?;
+        let changes = self.extract_changes(commit, parent.as_ref())?;
+        let parent_hash = parent
+            .map(|p| p.id())
+            .or_else(|| commit.parent_id(0).ok())
+            .map(|oid| oid.to_string());
 
         Ok(CommitMetadata {
             hash,
             author: author_name,
             date,
             message,
+            parent_hash,
             changes,
         })
     }
 
-    fn extract_changes(repo: &Repository, commit: &Git2Commit) -> Result<Vec<FileChange>> {
+    // The parent to diff against: for merges the selected one (0-based),
+    // otherwise the only one, or another whose tree is available (e.g. in a
+    // shallow clone). Merges with fewer parents than selected are an error.
+    fn diff_parent<'repo>(
+        commit: &Git2Commit<'repo>,
+        merge_parent: usize,
+    ) -> Result<Option<Git2Commit<'repo>>> {
+        let parent_count = commit.parent_count();
+        if parent_count == 0 {
+            return Ok(None);
+        }
+        let preferred = if parent_count > 1 { merge_parent } else { 0 };
+        if preferred >= parent_count {
+            anyhow::bail!(
+                "Merge commit {} has {} parents, so it has no parent {}",
+                short_hash(&commit.id().to_string()),
+                parent_count,
+                merge_parent + 1
+            );
+        }
+        Ok(std::iter::once(preferred)
+            .chain((0..parent_count).filter(|&i| i != preferred))
+            .filter_map(|i| commit.parent(i).ok())
+            .find(|p| p.tree().is_ok()))
+    }
+
+    fn extract_changes(
+        &self,
+        commit: &Git2Commit,
+        parent: Option<&Git2Commit>,
+    ) -> Result<Vec<FileChange>> {
+        let repo = &self.repo;
         let commit_tree = commit.tree().context("Failed to get commit tree")?;
-        let parent_tree = if commit.parent_count() > 0 {
-            match commit.parent(0).and_then(|p| p.tree()) {
-                Ok(tree) => Some(tree),
-                Err(_) => return Ok(Vec::new()), // Skip if parent tree unavailable
-            }
-        } else {
-            None
+        let parent_tree = match parent {
+            Some(parent) => Some(parent.tree()?),
+            None if commit.parent_count() > 0 => return Ok(Vec::new()), // Skip if no parent tree is available
+            None => None,
         };
 
         let mut diff_opts = DiffOptions::new();
         diff_opts.context_lines(3);
 
-        let diff = match repo.diff_tree_to_tree(
+        let mut diff = match repo.diff_tree_to_tree(
             parent_tree.as_ref(),
             Some(&commit_tree),
             Some(&mut diff_opts),
@@ -602,12 +1320,16 @@ impl GitRepository {
             Ok(d) => d,
             Err(_) => return Ok(Vec::new()), // Skip if diff fails
         };
+        find_renames_and_copies(&mut diff)?;
 
         let mut changes = Vec::new();
 
         for i in 0..diff.deltas().len() {
             let delta = diff.get_delta(i).unwrap();
             let status = FileStatus::from(delta.status());
+            if !self.matches_status(status) {
+                continue;
+            }
 
             let path = delta
                 .new_file()
@@ -627,43 +1349,34 @@ impl GitRepository {
                 None
             };
 
-            let is_binary = delta.new_file().is_binary() || delta.old_file().is_binary();
-
-            let old_content = if let Some(parent_tree) = parent_tree.as_ref() {
-                if let Some(old_file_path) = delta.old_file().path() {
-                    parent_tree
-                        .get_path(old_file_path)
-                        .ok()
-                        .and_then(|entry| repo.find_blob(entry.id()).ok())
-                        .and_then(|blob| {
-                            if !blob.is_binary() && blob.size() <= MAX_BLOB_SIZE {
-                                Some(String::from_utf8_lossy(blob.content()).to_string())
-                            } else {
-                                None
-                            }
-                        })
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
-
-            let new_content = if let Some(new_file_path) = delta.new_file().path() {
-                commit_tree
-                    .get_path(new_file_path)
-                    .ok()
-                    .and_then(|entry| repo.find_blob(entry.id()).ok())
-                    .and_then(|blob| {
-                        if !blob.is_binary() && blob.size() <= MAX_BLOB_SIZE {
-                            Some(String::from_utf8_lossy(blob.content()).to_string())
-                        } else {
-                            None
-                        }
-                    })
-            } else {
-                None
+            let old_blob = parent_tree
+                .as_ref()
+                .zip(delta.old_file().path())
+                .and_then(|(tree, path)| tree.get_path(path).ok())
+                .and_then(|entry| repo.find_blob(entry.id()).ok());
+            let new_blob = delta
+                .new_file()
+                .path()
+                .and_then(|path| commit_tree.get_path(path).ok())
+                .and_then(|entry| repo.find_blob(entry.id()).ok());
+
+            let is_binary = delta.new_file().is_binary()
+                || delta.old_file().is_binary()
+                || old_blob
+                    .iter()
+                    .chain(&new_blob)
+                    .any(|blob| blob.is_binary());
+            let size = new_blob
+                .as_ref()
+                .or(old_blob.as_ref())
+                .map_or(0, |blob| blob.size());
+
+            let text = |blob: &git2::Blob| {
+                (!blob.is_binary() && blob.size() <= MAX_BLOB_SIZE)
+                    .then(|| String::from_utf8_lossy(blob.content()).to_string())
             };
+            let old_content = old_blob.as_ref().and_then(text);
+            let new_content = new_blob.as_ref().and_then(text);
 
             let mut hunks = Vec::new();
             let mut diff_text = String::new();
@@ -737,22 +1450,24 @@ impl GitRepository {
                 .count();
 
             // Determine exclusion reason
-            let (is_excluded, exclusion_reason) = if should_exclude_file(&path) {
-                (true, Some("lock/generated file".to_string()))
-            } else if total_changed_lines > MAX_CHANGE_LINES {
-                (
-                    true,
-                    Some(format!("too many changes ({} lines)", total_changed_lines)),
-                )
-            } else {
-                (false, None)
-            };
+            let exclusion_reason = exclusion_reason(&path, &self.excluded_files)
+                .or_else(|| {
+                    (self.respect_gitignore
+                        && repo.is_path_ignored(Path::new(&path)).unwrap_or(false))
+                    .then_some(ExclusionReason::Gitignored)
+                })
+                .or_else(|| {
+                    (total_changed_lines > MAX_CHANGE_LINES)
+                        .then_some(ExclusionReason::TooManyChanges(total_changed_lines))
+                });
+            let is_excluded = exclusion_reason.is_some();
 
             changes.push(FileChange {
                 path,
                 old_path,
                 status,
                 is_binary,
+                size,
                 is_excluded,
                 exclusion_reason,
                 old_content,
@@ -766,87 +1481,571 @@ impl GitRepository {
     }
 }
 
+/// Pair deleted and added files into renames, and added files into copies
+/// of files changed in the same commit, as `git show -C` does
+fn find_renames_and_copies(diff: &mut git2::Diff) -> Result<()> {
+    diff.find_similar(Some(DiffFindOptions::new().renames(true).copies(true)))
+        .context("Failed to detect renamed and copied files")
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
+    use git2::Signature;
+    use std::path::PathBuf;
+
+    fn excluded(path: &str) -> bool {
+        exclusion_reason(path, &ExcludedFiles::default()).is_some()
+    }
+
+    // Create a throwaway repository with `count` linear commits, each touching one file
+    pub(crate) fn create_test_repo(name: &str, count: usize) -> PathBuf {
+        let commits: Vec<(String, String, String)> = (0..count)
+            .map(|i| {
+                (
+                    format!("Commit {}", i),
+                    format!("file{}.txt", i),
+                    format!("content {}\n", i),
+                )
+            })
+            .collect();
+        let files: Vec<[(&str, &str); 1]> = commits
+            .iter()
+            .map(|(_, file, contents)| [(file.as_str(), contents.as_str())])
+            .collect();
+        let commits: Vec<(&str, &[(&str, &str)])> = commits
+            .iter()
+            .zip(&files)
+            .map(|((message, _, _), files)| (message.as_str(), &files[..]))
+            .collect();
+        create_test_repo_with(name, &commits)
+    }
+
+    // Create a throwaway repository with one linear commit on master per
+    // `(message, files)` entry, each writing its `(path, contents)` files
+    pub(crate) fn create_test_repo_with(
+        name: &str,
+        commits: &[(&str, &[(&str, &str)])],
+    ) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("gitlogue-test-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        let repo = Repository::init(&path).unwrap();
+
+        let mut parent: Option<Oid> = None;
+        for (message, files) in commits {
+            parent = Some(commit_files(&repo, files, parent.as_slice(), message));
+        }
+        if let Some(head) = parent {
+            repo.reference("refs/heads/master", head, true, "test")
+                .unwrap();
+            repo.set_head("refs/heads/master").unwrap();
+        }
+
+        path
+    }
+
+    // Commit `files` over the first parent's tree without moving any branch
+    fn commit_files(
+        repo: &Repository,
+        files: &[(&str, &str)],
+        parents: &[Oid],
+        message: &str,
+    ) -> Oid {
+        let parents: Vec<Git2Commit> = parents
+            .iter()
+            .map(|p| repo.find_commit(*p).unwrap())
+            .collect();
+        let base_tree = parents.first().map(|p| p.tree().unwrap());
+        let mut builder = repo.treebuilder(base_tree.as_ref()).unwrap();
+        for (file, contents) in files {
+            let blob = repo.blob(contents.as_bytes()).unwrap();
+            builder.insert(file, blob, 0o100644).unwrap();
+        }
+        let tree = repo.find_tree(builder.write().unwrap()).unwrap();
+        let parent_refs: Vec<&Git2Commit> = parents.iter().collect();
+        let signature = Signature::now("Test Author", "test@example.com").unwrap();
+        repo.commit(None, &signature, &signature, message, &tree, &parent_refs)
+            .unwrap()
+    }
+
+    // Commit a `git mv from to` on master, writing `contents` to the new path
+    pub(crate) fn commit_rename(path: &Path, from: &str, to: &str, contents: &str) -> Oid {
+        let repo = Repository::open(path).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        let mut builder = repo.treebuilder(Some(&head.tree().unwrap())).unwrap();
+        builder.remove(from).unwrap();
+        let blob = repo.blob(contents.as_bytes()).unwrap();
+        builder.insert(to, blob, 0o100644).unwrap();
+        let tree = repo.find_tree(builder.write().unwrap()).unwrap();
+        let signature = Signature::now("Test Author", "test@example.com").unwrap();
+        let message = format!("Rename {} to {}", from, to);
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            &message,
+            &tree,
+            &[&head],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_get_commit_detects_renamed_files() {
+        let old: String = (1..=10).map(|i| format!("line {}\n", i)).collect();
+        let new = old.replace("line 5\n", "line five\n");
+        let path = create_test_repo_with("rename", &[("Add old.txt", &[("old.txt", &old)])]);
+        commit_rename(&path, "old.txt", "new.txt", &new);
+
+        let repo = GitRepository::open(&path).unwrap();
+        let changes = repo.get_commit("HEAD").unwrap().changes;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].status, FileStatus::Renamed);
+        assert_eq!(changes[0].path, "new.txt");
+        assert_eq!(changes[0].old_path.as_deref(), Some("old.txt"));
+        assert_eq!(changes[0].line_counts(), (1, 1));
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_seed_reproduces_random_order() {
+        let path = create_test_repo("seed", 10);
+
+        let play = |seed: u64| -> Vec<String> {
+            let repo = GitRepository::open(&path).unwrap();
+            repo.set_seed(seed);
+            (0..8).map(|_| repo.random_commit().unwrap().hash).collect()
+        };
+
+        assert_eq!(play(42), play(42));
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_random_no_repeat_plays_each_commit_once_per_pass() {
+        let path = create_test_repo("no-repeat", 5);
+        let mut repo = GitRepository::open(&path).unwrap();
+        repo.set_random_no_repeat(true);
+        repo.set_seed(7);
+
+        let played: Vec<String> = (0..15)
+            .map(|_| repo.random_commit().unwrap().message)
+            .collect();
+        for pass in played.chunks(5) {
+            let unique: HashSet<&String> = pass.iter().collect();
+            assert_eq!(unique.len(), 5, "{:?}", played);
+        }
+        assert!(played.windows(2).all(|pair| pair[0] != pair[1]));
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_list_commits_oldest_first() {
+        let path = create_test_repo("list", 5);
+        let repo = GitRepository::open(&path).unwrap();
+
+        let summaries: Vec<String> = repo
+            .list_commits()
+            .unwrap()
+            .into_iter()
+            .map(|c| c.summary)
+            .collect();
+        assert_eq!(
+            summaries,
+            vec!["Commit 0", "Commit 1", "Commit 2", "Commit 3", "Commit 4"]
+        );
+
+        repo.set_commit_playlist(&["HEAD~2..HEAD"]).unwrap();
+        let in_range = repo.list_commits().unwrap();
+        assert_eq!(in_range.len(), 2);
+        assert_eq!(in_range[0].summary, "Commit 3");
+
+        // Single commits and ranges play in the order they were given
+        repo.set_commit_playlist(&["HEAD~1", "HEAD~4..HEAD~2", "HEAD~4"])
+            .unwrap();
+        let playlist: Vec<String> = std::iter::from_fn(|| repo.next_range_commit_asc().ok())
+            .map(|c| c.message)
+            .collect();
+        assert_eq!(
+            playlist,
+            vec!["Commit 3", "Commit 1", "Commit 2", "Commit 0"]
+        );
+        assert!(repo.set_commit_playlist(&["HEAD", "missing"]).is_err());
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_path_filter_keeps_commits_changing_path() {
+        let path = create_test_repo("follow", 4);
+        let mut repo = GitRepository::open(&path).unwrap();
+        repo.set_path_filter(Some("file2.txt".to_string()));
+
+        let summaries: Vec<String> = repo
+            .list_commits()
+            .unwrap()
+            .into_iter()
+            .map(|c| c.summary)
+            .collect();
+        assert_eq!(summaries, vec!["Commit 2"]);
+        // The lazy desc walk applies the same filter
+        assert_eq!(repo.next_walked_commit().unwrap().message, "Commit 2");
+
+        let mut repo = GitRepository::open(&path).unwrap();
+        repo.set_path_filter(Some("missing.txt".to_string()));
+        assert_eq!(
+            repo.next_asc_commit().unwrap_err().to_string(),
+            "No commits found matching the filters in repository"
+        );
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_status_filter_keeps_matching_files() {
+        // The last commit edits a file and adds another
+        let path = create_test_repo_with(
+            "status",
+            &[
+                ("Commit 0", &[("file0.txt", "content 0\n")]),
+                ("Commit 1", &[("file1.txt", "content 1\n")]),
+                (
+                    "Edit",
+                    &[("file0.txt", "changed\n"), ("extra.txt", "extra\n")],
+                ),
+            ],
+        );
+
+        let mut git_repo = GitRepository::open(&path).unwrap();
+        git_repo.set_status_filter(Some(HashSet::from([FileStatus::Modified])));
+        let summaries: Vec<String> = git_repo
+            .list_commits()
+            .unwrap()
+            .into_iter()
+            .map(|c| c.summary)
+            .collect();
+        assert_eq!(summaries, vec!["Edit"]);
+
+        let metadata = git_repo.get_commit("HEAD").unwrap();
+        let paths: Vec<&str> = metadata.changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["file0.txt"]);
+        assert!(git_repo.get_commit("HEAD~1").is_err());
+        assert!(git_repo.set_commit_playlist(&["HEAD~1"]).is_err());
+
+        assert_eq!("m".parse::<FileStatus>(), Ok(FileStatus::Modified));
+        assert!("X".parse::<FileStatus>().is_err());
+        assert!("U".parse::<FileStatus>().is_err());
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_status_filter_matches_renames_and_copies() {
+        let old: String = (1..=10).map(|i| format!("line {}\n", i)).collect();
+        let edited = old.replace("line 5\n", "line five\n");
+        let path = create_test_repo_with(
+            "status-similar",
+            &[
+                ("Add a.txt", &[("a.txt", &old)]),
+                ("Copy a.txt", &[("a.txt", &edited), ("b.txt", &old)]),
+            ],
+        );
+        commit_rename(&path, "b.txt", "c.txt", &old);
+
+        let summaries = |status: FileStatus| -> Vec<String> {
+            let mut repo = GitRepository::open(&path).unwrap();
+            repo.set_status_filter(Some(HashSet::from([status])));
+            repo.list_commits()
+                .unwrap()
+                .into_iter()
+                .map(|c| c.summary)
+                .collect()
+        };
+        assert_eq!(
+            summaries(FileStatus::Renamed),
+            vec!["Rename b.txt to c.txt"]
+        );
+        assert_eq!(summaries(FileStatus::Copied), vec!["Copy a.txt"]);
+
+        let repo = GitRepository::open(&path).unwrap();
+        let copy = repo.get_commit("HEAD~1").unwrap();
+        let statuses: Vec<FileStatus> = copy.changes.iter().map(|c| c.status).collect();
+        assert!(statuses.contains(&FileStatus::Copied));
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_desc_walks_lazily_newest_first() {
+        let path = create_test_repo("desc", 4);
+        let repo = GitRepository::open(&path).unwrap();
+
+        let play = || -> Vec<String> {
+            std::iter::from_fn(|| repo.next_desc_commit().ok())
+                .map(|c| c.message)
+                .collect()
+        };
+        assert_eq!(play(), vec!["Commit 3", "Commit 2", "Commit 1", "Commit 0"]);
+        assert!(repo.commit_cache.borrow().is_none());
+        assert!(repo.is_exhausted());
+
+        // Looping restarts the walk from HEAD
+        repo.reset_index();
+        assert_eq!(play().len(), 4);
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_set_commit_index_resumes_or_starts_over() {
+        let path = create_test_repo("resume", 4);
+        let repo = GitRepository::open(&path).unwrap();
+
+        repo.set_commit_index(2).unwrap();
+        assert_eq!(repo.next_desc_commit().unwrap().message, "Commit 1");
+        assert_eq!(repo.total_count(), Some(4));
+
+        // An index past the end of a list that has since shrunk
+        repo.set_commit_index(9).unwrap();
+        assert_eq!(repo.next_asc_commit().unwrap().message, "Commit 0");
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_playback_position() {
+        let path = create_test_repo("position", 3);
+        let repo = GitRepository::open(&path).unwrap();
+
+        // The lazy desc walk does not know how many commits there are
+        repo.next_desc_commit().unwrap();
+        assert_eq!(repo.current_index(), 1);
+        assert_eq!(repo.total_count(), None);
+
+        repo.reset_index();
+        repo.next_asc_commit().unwrap();
+        repo.next_asc_commit().unwrap();
+        assert_eq!(repo.current_index(), 2);
+        assert_eq!(repo.total_count(), Some(3));
+        assert!(!repo.is_exhausted());
+        repo.next_asc_commit().unwrap();
+        assert!(repo.is_exhausted());
+        assert!(repo.next_asc_commit().is_err());
+
+        repo.set_commit_playlist(&["HEAD~1..HEAD"]).unwrap();
+        repo.next_range_commit_asc().unwrap();
+        assert_eq!((repo.current_index(), repo.total_count()), (1, Some(1)));
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_include_merges_with_parent_selection() {
+        let path = create_test_repo_with("merge", &[]);
+        let repo = Repository::open(&path).unwrap();
+
+        let base = commit_files(&repo, &[("a.txt", "a\n")], &[], "Base");
+        let main = commit_files(&repo, &[("b.txt", "b\n")], &[base], "Main");
+        let side = commit_files(&repo, &[("c.txt", "c\n")], &[base], "Side");
+        let merge = commit_files(&repo, &[("c.txt", "c\n")], &[main, side], "Merge");
+        repo.reference("refs/heads/master", merge, true, "test")
+            .unwrap();
+        repo.set_head("refs/heads/master").unwrap();
+
+        let changed_paths = |repo: &GitRepository| -> Vec<String> {
+            let metadata = repo.get_commit(&merge.to_string()).unwrap();
+            metadata.changes.into_iter().map(|c| c.path).collect()
+        };
+        let mut git_repo = GitRepository::open(&path).unwrap();
+        assert_eq!(changed_paths(&git_repo), vec!["c.txt"]);
+        git_repo.set_merge_parent(1);
+        assert_eq!(changed_paths(&git_repo), vec!["b.txt"]);
+        git_repo.set_merge_parent(4);
+        let err = git_repo.get_commit(&merge.to_string()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Merge commit {} has 2 parents, so it has no parent 5",
+                short_hash(&merge.to_string())
+            )
+        );
+        // Commits with a single parent are unaffected
+        assert_eq!(
+            git_repo
+                .get_commit(&main.to_string())
+                .unwrap()
+                .changes
+                .len(),
+            1
+        );
+        git_repo.set_merge_parent(1);
+
+        let count_played =
+            |repo: &GitRepository| std::iter::from_fn(|| repo.next_desc_commit().ok()).count();
+        assert_eq!(count_played(&git_repo), 3);
+        git_repo.set_include_merges(true);
+        git_repo.reset_index();
+        assert_eq!(count_played(&git_repo), 4);
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_start_ref_walks_other_branch() {
+        let path = create_test_repo_with("ref", &[]);
+        let repo = Repository::open(&path).unwrap();
+
+        let base = commit_files(&repo, &[("a.txt", "a\n")], &[], "Base");
+        let main = commit_files(&repo, &[("b.txt", "b\n")], &[base], "Main");
+        let feature = commit_files(&repo, &[("c.txt", "c\n")], &[base], "Feature");
+        repo.reference("refs/heads/master", main, true, "test")
+            .unwrap();
+        repo.reference("refs/heads/feature", feature, true, "test")
+            .unwrap();
+        repo.set_head("refs/heads/master").unwrap();
+
+        let mut git_repo = GitRepository::open(&path).unwrap();
+        assert!(git_repo.set_start_ref("missing").is_err());
+        git_repo.set_start_ref("feature").unwrap();
+
+        let played: Vec<String> = std::iter::from_fn(|| git_repo.next_asc_commit().ok())
+            .map(|c| c.message)
+            .collect();
+        assert_eq!(played, vec!["Base", "Feature"]);
+        git_repo.reset_index();
+        assert_eq!(git_repo.next_desc_commit().unwrap().message, "Feature");
+
+        git_repo
+            .set_commit_playlist(&[format!("{}..", base)])
+            .unwrap();
+        assert_eq!(git_repo.next_range_commit_asc().unwrap().message, "Feature");
+
+        // Every branch, with the shared base commit played once
+        let mut git_repo = GitRepository::open(&path).unwrap();
+        git_repo.set_all_refs().unwrap();
+        let mut played: Vec<String> = std::iter::from_fn(|| git_repo.next_desc_commit().ok())
+            .map(|c| c.message)
+            .collect();
+        played.sort();
+        assert_eq!(played, vec!["Base", "Feature", "Main"]);
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_short_hash_and_empty_range() {
+        assert_eq!(short_hash("0123456789abcdef"), "0123456");
+        assert_eq!(short_hash("abc"), "abc");
+        // Never slices through a multi-byte character
+        assert_eq!(short_hash("abcdefé"), "abcdefé");
+
+        let path = create_test_repo("empty-range", 3);
+        let repo = GitRepository::open(&path).unwrap();
+        let err = repo.set_commit_playlist(&["HEAD..HEAD~1"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No non-merge commits found in range 'HEAD..HEAD~1'"
+        );
+        assert!(repo.next_range_commit_asc().is_err());
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_respect_gitignore_is_opt_in() {
+        let path = create_test_repo("gitignore", 1);
+        std::fs::write(path.join(".gitignore"), "file0.txt\n").unwrap();
+
+        let mut repo = GitRepository::open(&path).unwrap();
+        let reason =
+            |repo: &GitRepository| repo.get_commit("HEAD").unwrap().changes[0].exclusion_reason;
+        assert_eq!(reason(&repo), None);
+        repo.set_respect_gitignore(true);
+        assert_eq!(reason(&repo), Some(ExclusionReason::Gitignored));
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
 
     #[test]
     fn test_should_exclude_lock_files() {
         // JavaScript/Node.js
-        assert!(should_exclude_file("package-lock.json"));
-        assert!(should_exclude_file("yarn.lock"));
-        assert!(should_exclude_file("pnpm-lock.yaml"));
+        assert!(excluded("package-lock.json"));
+        assert!(excluded("yarn.lock"));
+        assert!(excluded("pnpm-lock.yaml"));
         // Rust
-        assert!(should_exclude_file("Cargo.lock"));
+        assert!(excluded("Cargo.lock"));
         // Ruby
-        assert!(should_exclude_file("Gemfile.lock"));
+        assert!(excluded("Gemfile.lock"));
         // Python
-        assert!(should_exclude_file("poetry.lock"));
-        assert!(should_exclude_file("Pipfile.lock"));
+        assert!(excluded("poetry.lock"));
+        assert!(excluded("Pipfile.lock"));
         // PHP
-        assert!(should_exclude_file("composer.lock"));
+        assert!(excluded("composer.lock"));
         // Go
-        assert!(should_exclude_file("go.sum"));
+        assert!(excluded("go.sum"));
         // Swift
-        assert!(should_exclude_file("Package.resolved"));
+        assert!(excluded("Package.resolved"));
         // Dart/Flutter
-        assert!(should_exclude_file("pubspec.lock"));
+        assert!(excluded("pubspec.lock"));
         // .NET/C#
-        assert!(should_exclude_file("packages.lock.json"));
-        assert!(should_exclude_file("project.assets.json"));
+        assert!(excluded("packages.lock.json"));
+        assert!(excluded("project.assets.json"));
         // Elixir
-        assert!(should_exclude_file("mix.lock"));
+        assert!(excluded("mix.lock"));
         // Java/Gradle
-        assert!(should_exclude_file("gradle.lockfile"));
-        assert!(should_exclude_file("buildscript-gradle.lockfile"));
+        assert!(excluded("gradle.lockfile"));
+        assert!(excluded("buildscript-gradle.lockfile"));
         // Scala
-        assert!(should_exclude_file("build.sbt.lock"));
+        assert!(excluded("build.sbt.lock"));
         // Bazel
-        assert!(should_exclude_file("MODULE.bazel.lock"));
+        assert!(excluded("MODULE.bazel.lock"));
     }
 
     #[test]
     fn test_should_exclude_lock_files_with_path() {
-        assert!(should_exclude_file("path/to/package-lock.json"));
-        assert!(should_exclude_file("src/Cargo.lock"));
-        assert!(should_exclude_file("frontend/yarn.lock"));
+        assert!(excluded("path/to/package-lock.json"));
+        assert!(excluded("src/Cargo.lock"));
+        assert!(excluded("frontend/yarn.lock"));
     }
 
     #[test]
     fn test_should_exclude_minified_files() {
-        assert!(should_exclude_file("bundle.min.js"));
-        assert!(should_exclude_file("app.min.css"));
-        assert!(should_exclude_file("vendor.bundle.js"));
-        assert!(should_exclude_file("styles.bundle.css"));
+        assert!(excluded("bundle.min.js"));
+        assert!(excluded("app.min.css"));
+        assert!(excluded("vendor.bundle.js"));
+        assert!(excluded("styles.bundle.css"));
         // Source maps
-        assert!(should_exclude_file("app.js.map"));
-        assert!(should_exclude_file("styles.css.map"));
-        assert!(should_exclude_file("types.d.ts.map"));
+        assert!(excluded("app.js.map"));
+        assert!(excluded("styles.css.map"));
+        assert!(excluded("types.d.ts.map"));
     }
 
     #[test]
     fn test_should_exclude_minified_files_with_path() {
-        assert!(should_exclude_file("dist/bundle.min.js"));
-        assert!(should_exclude_file("public/assets/app.min.css"));
+        assert!(excluded("dist/bundle.min.js"));
+        assert!(excluded("public/assets/app.min.css"));
     }
 
     #[test]
     fn test_should_not_exclude_normal_files() {
-        assert!(!should_exclude_file("src/main.rs"));
-        assert!(!should_exclude_file("package.json"));
-        assert!(!should_exclude_file("Cargo.toml"));
-        assert!(!should_exclude_file("app.js"));
-        assert!(!should_exclude_file("styles.css"));
-        assert!(!should_exclude_file("lock.txt"));
-        assert!(!should_exclude_file("minify.rs"));
+        assert!(!excluded("src/main.rs"));
+        assert!(!excluded("package.json"));
+        assert!(!excluded("Cargo.toml"));
+        assert!(!excluded("app.js"));
+        assert!(!excluded("styles.css"));
+        assert!(!excluded("lock.txt"));
+        assert!(!excluded("minify.rs"));
     }
 
     #[test]
     fn test_should_exclude_snapshot_files() {
-        assert!(should_exclude_file("component.test.ts.snap"));
-        assert!(should_exclude_file("tests/__snapshots__/test.snap"));
-        assert!(should_exclude_file("__snapshots__/component.snap"));
-        assert!(should_exclude_file("src/__snapshots__/app.test.js.snap"));
+        assert!(excluded("component.test.ts.snap"));
+        assert!(excluded("tests/__snapshots__/test.snap"));
+        assert!(excluded("__snapshots__/component.snap"));
+        assert!(excluded("src/__snapshots__/app.test.js.snap"));
     }
 
     #[test]
@@ -863,17 +2062,68 @@ mod tests {
         let _ = init_ignore_patterns(&patterns);
 
         // Test file extension patterns
-        assert!(should_exclude_file("diagram.svg"));
-        assert!(should_exclude_file("path/to/notebook.ipynb"));
-        assert!(should_exclude_file("assets/icon.svg"));
-        assert!(!should_exclude_file("image.png"));
-        assert!(!should_exclude_file("script.py"));
+        assert!(excluded("diagram.svg"));
+        assert!(excluded("path/to/notebook.ipynb"));
+        assert!(excluded("assets/icon.svg"));
+        assert!(!excluded("image.png"));
+        assert!(!excluded("script.py"));
 
         // Test directory patterns
-        assert!(should_exclude_file("dist/bundle.js"));
-        assert!(should_exclude_file("dist/css/main.css"));
-        assert!(should_exclude_file("node_modules/pkg/index.js"));
-        assert!(!should_exclude_file("src/index.js"));
+        assert!(excluded("dist/bundle.js"));
+        assert!(excluded("dist/css/main.css"));
+        assert!(excluded("node_modules/pkg/index.js"));
+        assert!(!excluded("src/index.js"));
+    }
+
+    #[test]
+    fn test_excluded_files_extend_and_unexclude() {
+        let excluded = ExcludedFiles::new(
+            &["schema.sql.gen".to_string()],
+            &["*.pb.go".to_string()],
+            &["Cargo.lock".to_string(), ".snap".to_string()],
+        );
+
+        assert!(exclusion_reason("db/schema.sql.gen", &excluded).is_some());
+        assert!(exclusion_reason("api/service.pb.go", &excluded).is_some());
+        assert!(exclusion_reason("yarn.lock", &excluded).is_some());
+        assert!(exclusion_reason("Cargo.lock", &excluded).is_none());
+        assert!(exclusion_reason("tests/output.snap", &excluded).is_none());
+        assert!(exclusion_reason("api/service.go", &excluded).is_none());
+    }
+
+    #[test]
+    fn test_format_relative_date() {
+        let now = DateTime::parse_from_rfc3339("2024-06-15T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let relative = |offset: chrono::Duration| format_relative_date(now - offset, now);
+
+        assert_eq!(relative(chrono::Duration::seconds(20)), "just now");
+        assert_eq!(relative(chrono::Duration::minutes(1)), "1 minute ago");
+        assert_eq!(relative(chrono::Duration::hours(5)), "5 hours ago");
+        assert_eq!(relative(chrono::Duration::days(3)), "3 days ago");
+        assert_eq!(relative(chrono::Duration::days(14)), "2 weeks ago");
+        assert_eq!(relative(chrono::Duration::days(95)), "3 months ago");
+        assert_eq!(relative(chrono::Duration::days(800)), "2 years ago");
+        // Future-dated commits (e.g. a skewed clock)
+        assert_eq!(relative(chrono::Duration::days(-2)), "in 2 days");
+    }
+
+    #[test]
+    fn test_message_matcher_substring() {
+        let matcher = build_message_matcher("Fix(", false).unwrap();
+        assert!(matcher.is_match("fix(parser): handle empty input"));
+        assert!(matcher.is_match("Hotfix(ui)"));
+        assert!(!matcher.is_match("feat: add parser"));
+    }
+
+    #[test]
+    fn test_message_matcher_regex() {
+        let matcher = build_message_matcher("^(feat|fix):", true).unwrap();
+        assert!(matcher.is_match("FEAT: add parser"));
+        assert!(matcher.is_match("fix: typo"));
+        assert!(!matcher.is_match("docs: fix typo"));
+        assert!(build_message_matcher("(unclosed", true).is_err());
     }
 
     #[test]
@@ -0,0 +1,116 @@
+/// Symbols that mark the commit and push messages printed to the terminal
+/// pane, so the emoji and ASCII variants cover the same places
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    pub displace: &'static str,
+    pub warp: &'static str,
+    pub arrived: &'static str,
+    pub location: &'static str,
+    pub root: &'static str,
+    pub package: &'static str,
+    pub commit: &'static str,
+    pub files_changed: &'static str,
+    pub launch: &'static str,
+    pub sign: &'static str,
+    pub beam: &'static str,
+    pub success: &'static str,
+    pub revert: &'static str,
+    pub sweep: &'static str,
+    pub ellipsis: &'static str,
+    pub dash: &'static str,
+}
+
+impl Glyphs {
+    // Emoji drawn with a variation selector carry an extra space, as most
+    // terminals give them one cell but draw them across two
+    pub const EMOJI: Glyphs = Glyphs {
+        displace: "⚡",
+        warp: "✨",
+        arrived: "🕰️ ",
+        location: "📍",
+        root: "🌱",
+        package: "📦",
+        commit: "💾",
+        files_changed: "📝",
+        launch: "🚀",
+        sign: "✍️ ",
+        beam: "📡",
+        success: "✨",
+        revert: "⏪",
+        sweep: "🧹",
+        ellipsis: "…",
+        dash: "—",
+    };
+
+    pub const ASCII: Glyphs = Glyphs {
+        displace: "[*]",
+        warp: "[~]",
+        arrived: "[@]",
+        location: "[>]",
+        root: "[+]",
+        package: "[=]",
+        commit: "[#]",
+        files_changed: "[.]",
+        launch: "[^]",
+        sign: "[/]",
+        beam: "->",
+        success: "*",
+        revert: "<<",
+        sweep: "[-]",
+        ellipsis: "...",
+        dash: "-",
+    };
+
+    /// ASCII when the terminal is unlikely to draw emoji: the Linux console,
+    /// or a locale that is not UTF-8 (including no locale at all)
+    pub fn detect() -> Glyphs {
+        if supports_emoji(|name| std::env::var(name).ok()) {
+            Glyphs::EMOJI
+        } else {
+            Glyphs::ASCII
+        }
+    }
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Glyphs::EMOJI
+    }
+}
+
+fn supports_emoji(env: impl Fn(&str) -> Option<String>) -> bool {
+    if env("TERM").as_deref() == Some("linux") {
+        return false;
+    }
+    // The first locale variable that is set decides, as in setlocale(3)
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(|name| env(name).filter(|value| !value.is_empty()))
+        .is_some_and(|locale| {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supports(vars: &[(&str, &str)]) -> bool {
+        supports_emoji(|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn test_detects_emoji_support_from_locale() {
+        assert!(supports(&[("LANG", "en_US.UTF-8")]));
+        assert!(supports(&[("LC_ALL", "C.utf8"), ("LANG", "C")]));
+        assert!(supports(&[("LC_ALL", ""), ("LANG", "de_DE.UTF-8")]));
+        assert!(!supports(&[("LC_ALL", "C"), ("LANG", "en_US.UTF-8")]));
+        assert!(!supports(&[("TERM", "linux"), ("LANG", "en_US.UTF-8")]));
+        assert!(!supports(&[]));
+    }
+}
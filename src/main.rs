@@ -1,20 +1,36 @@
 mod animation;
+mod cast;
 mod config;
 mod git;
+mod glyphs;
+mod narrator;
 mod panes;
+mod prefetch;
+mod preview;
+mod resume;
+mod snapshot;
 mod syntax;
 mod theme;
 mod ui;
 mod widgets;
 
+use animation::{AnimationEngine, PacingConfig};
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use config::Config;
-use git::GitRepository;
+use git::{FileStatus, GitRepository};
+use glyphs::Glyphs;
+use panes::{ClockFormat, OutputMarkers};
+use prefetch::{CommitPrefetcher, CommitSelection};
+use resume::PlaybackState;
 use std::path::{Path, PathBuf};
 use theme::Theme;
 use ui::UI;
 
+// Cast dimensions used when recording without a terminal attached
+const DEFAULT_CAST_WIDTH: u16 = 120;
+const DEFAULT_CAST_HEIGHT: u16 = 40;
+
 #[derive(Debug, Clone, Copy, Default, ValueEnum)]
 pub enum PlaybackOrder {
     #[default]
@@ -23,6 +39,78 @@ pub enum PlaybackOrder {
     Desc,
 }
 
+/// How added lines are typed in the editor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum TypingMode {
+    /// One character at a time
+    #[default]
+    Char,
+    /// One word at a time
+    Word,
+}
+
+/// How commit dates are shown in the status bar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum DateFormat {
+    /// Timestamp, e.g. 2024-06-15 12:00:00
+    #[default]
+    Absolute,
+    /// Age, e.g. 3 months ago
+    Relative,
+    /// Timestamp followed by the age
+    Both,
+}
+
+/// Style of the terminal output around each commit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Flavor {
+    /// Time travel and code beamed to the cloud
+    #[default]
+    Fun,
+    /// Output that looks like real git commands
+    Plain,
+}
+
+/// Where the terminal pane is placed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum TerminalPosition {
+    /// Under the editor
+    #[default]
+    Bottom,
+    /// Under the file tree, with the commit info moving under the editor
+    Left,
+    /// Not shown; the editor takes the full height
+    Hidden,
+}
+
+/// Order in which the files of a commit are listed and animated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum FileOrder {
+    /// By directory, then file name
+    #[default]
+    Path,
+    /// Most changed lines first within each directory
+    Changes,
+    /// Largest files (in bytes) first within each directory
+    Size,
+}
+
+impl FileOrder {
+    /// Indices into `metadata.changes`, still grouped by directory
+    pub fn file_indices(self, metadata: &git::CommitMetadata) -> Vec<usize> {
+        match self {
+            FileOrder::Path => metadata.sorted_file_indices(),
+            FileOrder::Changes => metadata.sorted_file_indices_by(|change| {
+                let (additions, deletions) = change.line_counts();
+                std::cmp::Reverse(additions + deletions)
+            }),
+            FileOrder::Size => {
+                metadata.sorted_file_indices_by(|change| std::cmp::Reverse(change.size))
+            }
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[command(
     name = "gitlogue",
@@ -43,23 +131,186 @@ pub struct Args {
         short,
         long,
         value_name = "HASH_OR_RANGE",
-        help = "Replay a specific commit or commit range (e.g., HEAD~5..HEAD or abc123..)"
+        action = clap::ArgAction::Append,
+        value_delimiter = ',',
+        help = "Replay a specific commit or commit range (e.g., HEAD~5..HEAD or abc123..); repeat or separate with commas to play several in order"
+    )]
+    pub commit: Vec<String>,
+
+    #[arg(
+        long = "ref",
+        value_name = "REFNAME",
+        help = "Play history from a branch, tag or remote-tracking branch instead of HEAD"
+    )]
+    pub start_ref: Option<String>,
+
+    #[arg(
+        long,
+        conflicts_with = "start_ref",
+        help = "Play history from every local branch instead of only HEAD"
     )]
-    pub commit: Option<String>,
+    pub all: bool,
 
     #[arg(
         short,
         long,
-        value_name = "MS",
-        help = "Typing speed in milliseconds per character (overrides config file)"
+        value_name = "DURATION",
+        value_parser = parse_speed,
+        help = "Typing speed per character, e.g. 30, 50ms or 0.1s (plain numbers are milliseconds, overrides config file)"
     )]
     pub speed: Option<u64>,
 
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Target frames per second, 5-240 (default: 120; lower values save CPU)"
+    )]
+    pub fps: Option<u64>,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "MODE",
+        default_value_t = TypingMode::Char,
+        help = "Type added lines character by character or word by word"
+    )]
+    pub typing_mode: TypingMode,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        default_value_t = DateFormat::Absolute,
+        help = "Show commit dates in the status bar as a timestamp, an age (\"3 months ago\"), or both"
+    )]
+    pub date_format: DateFormat,
+
+    #[arg(
+        long,
+        value_name = "FACTOR",
+        value_parser = parse_multiplier,
+        help = "Scale every pause between animation phases, e.g. 0.5 for half as long (overrides config file)"
+    )]
+    pub pause_scale: Option<f64>,
+
+    #[arg(
+        long,
+        value_name = "KEYSTROKES",
+        value_parser = parse_multiplier,
+        help = "Pause between hunks, in multiples of the typing speed (default: 50, overrides config file)"
+    )]
+    pub hunk_pause: Option<f64>,
+
+    #[arg(
+        long,
+        value_name = "PROBABILITY",
+        value_parser = parse_probability,
+        help = "Chance (0.0-1.0) of mistyping a character and correcting it with backspace"
+    )]
+    pub typos: Option<f64>,
+
+    #[arg(
+        long,
+        help = "Type the indentation of added lines space by space instead of inserting it at once (overrides config file)"
+    )]
+    pub type_indentation: bool,
+
+    #[arg(
+        long,
+        help = "Type a comment naming the commit at the top of each file before editing it (overrides config file)"
+    )]
+    pub comment_banner: bool,
+
+    #[arg(
+        long,
+        help = "Play commits backwards, un-writing their changes as if they were reverted"
+    )]
+    pub reverse: bool,
+
+    #[arg(
+        long,
+        help = "Skip the time-travel intro before each commit (overrides config file)"
+    )]
+    pub no_intro: bool,
+
+    #[arg(
+        long,
+        help = "Erase deleted files and edit renamed files in the editor instead of only running rm/mv (overrides config file)"
+    )]
+    pub animate_deletes: bool,
+
+    #[arg(
+        long,
+        value_name = "LINES",
+        help = "Type the first LINES changed lines of lock files and huge diffs instead of skipping them (overrides config file)"
+    )]
+    pub excluded_preview: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Keep the cursor visible instead of blinking (overrides config file)"
+    )]
+    pub no_blink: bool,
+
+    #[arg(
+        long,
+        help = "Use ASCII instead of emoji in terminal messages (default: detected from the locale)"
+    )]
+    pub ascii: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FLAVOR",
+        help = "Whimsical or realistic git output in the terminal (default: fun, overrides config file)"
+    )]
+    pub flavor: Option<Flavor>,
+
+    #[arg(
+        long,
+        help = "Show only the editor, hiding the file tree, commit info and terminal (toggle with f)"
+    )]
+    pub editor_only: bool,
+
+    #[arg(
+        long,
+        value_name = "PCT",
+        value_parser = clap::value_parser!(u16).range(ui::MIN_LAYOUT_PCT as i64..=ui::MAX_LAYOUT_PCT as i64),
+        help = "Percentage of the width given to the file tree column (overrides config file)"
+    )]
+    pub file_tree_width: Option<u16>,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "POSITION",
+        default_value_t = TerminalPosition::Bottom,
+        help = "Place the terminal pane under the editor, under the file tree, or hide it"
+    )]
+    pub terminal_position: TerminalPosition,
+
+    #[arg(
+        long,
+        value_name = "PCT",
+        value_parser = clap::value_parser!(u16).range(ui::MIN_LAYOUT_PCT as i64..=ui::MAX_LAYOUT_PCT as i64),
+        help = "Percentage of the height given to the editor and file tree (overrides config file)"
+    )]
+    pub editor_height: Option<u16>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_name = "BOOL",
+        help = "Wrap long editor lines (use --wrap=false to scroll horizontally instead, overrides config file)"
+    )]
+    pub wrap: Option<bool>,
+
     #[arg(
         short,
         long,
         value_name = "NAME",
-        help = "Theme to use (overrides config file)"
+        help = "Theme name or path to a theme file, or \"random\" for a different built-in theme per commit (overrides config file)"
     )]
     pub theme: Option<String>,
 
@@ -85,10 +336,89 @@ pub struct Args {
         num_args = 0..=1,
         default_missing_value = "true",
         value_name = "BOOL",
-        help = "Loop the animation continuously (useful with --commit for commit ranges)"
+        help = "Loop the animation continuously, starting over once every commit has been played"
     )]
     pub loop_playback: Option<bool>,
 
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Stop after playing N commits (takes precedence over --loop)"
+    )]
+    pub max_commits: Option<u64>,
+
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Animate at most N files of each commit and summarize the rest in one line"
+    )]
+    pub max_files_per_commit: Option<u64>,
+
+    #[arg(
+        long,
+        value_name = "LINES",
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Paste the added lines of files with more than LINES additions in bursts instead of typing them"
+    )]
+    pub fast_above: Option<u64>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = FileOrder::Path,
+        help = "Order of the files within each commit: by path, most changed lines first, or largest first"
+    )]
+    pub file_order: FileOrder,
+
+    #[arg(
+        long,
+        help = "In random order, play every commit once before repeating any (overrides config file)"
+    )]
+    pub random_no_repeat: bool,
+
+    #[arg(
+        long,
+        help = "In asc or desc order, continue from the commit the last --resume session stopped at"
+    )]
+    pub resume: bool,
+
+    #[arg(
+        long,
+        value_name = "SEED",
+        help = "Seed for random commit selection and typing variation (reproducible playback)"
+    )]
+    pub seed: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Print the commits that would be played, in order, and exit"
+    )]
+    pub list_commits: bool,
+
+    #[arg(
+        long,
+        help = "Print the animation steps planned for the first commit as JSON, and exit"
+    )]
+    pub dump_steps: bool,
+
+    #[arg(
+        short = 'o',
+        long,
+        value_name = "PATH",
+        help = "Record the first commit to an asciinema v2 cast file (or text frames with --frames) instead of playing it; use - for stdout"
+    )]
+    pub output: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "N",
+        requires = "output",
+        help = "With --output, write N plain-text snapshots of the layout taken one frame interval apart"
+    )]
+    pub frames: Option<usize>,
+
     #[arg(long, help = "Display third-party license information")]
     pub license: bool,
 
@@ -107,6 +437,38 @@ pub struct Args {
 
     #[arg(
         long,
+        value_name = "PATTERN",
+        value_parser = |s: &str| if s.trim().is_empty() {
+            Err("Exclude author pattern cannot be empty".to_string())
+        } else {
+            Ok(s.to_string())
+        },
+        help = "Skip commits by author name or email (partial match, case-insensitive)"
+    )]
+    pub exclude_author: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        value_parser = |s: &str| if s.is_empty() {
+            Err("Message pattern cannot be empty".to_string())
+        } else {
+            Ok(s.to_string())
+        },
+        help = "Only play commits whose message contains this text (case-insensitive)"
+    )]
+    pub grep: Option<String>,
+
+    #[arg(
+        long,
+        requires = "grep",
+        help = "Interpret the --grep pattern as a regular expression"
+    )]
+    pub grep_regex: bool,
+
+    #[arg(
+        long,
+        visible_alias = "until",
         value_name = "DATE",
         help = "Show commits before this date (e.g., '2024-01-01', '1 week ago', 'yesterday')"
     )]
@@ -114,11 +476,42 @@ pub struct Args {
 
     #[arg(
         long,
+        visible_alias = "since",
         value_name = "DATE",
         help = "Show commits after this date (e.g., '2024-01-01', '1 week ago', 'yesterday')"
     )]
     pub after: Option<String>,
 
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Only play commits that change this file or directory, relative to the repository root"
+    )]
+    pub follow: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "STATUSES",
+        value_delimiter = ',',
+        help = "Only animate files with these statuses, e.g. M,A (A added, D deleted, M modified, R renamed, C copied; U unmerged is rejected since commits have no conflicts); commits without any are skipped"
+    )]
+    pub status_filter: Vec<FileStatus>,
+
+    #[arg(
+        long,
+        help = "Include merge commits, diffed against their first parent (or --merge-parent)"
+    )]
+    pub include_merges: bool,
+
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u64).range(1..),
+        requires = "include_merges",
+        help = "Diff merge commits against their Nth parent instead of the first"
+    )]
+    pub merge_parent: Option<u64>,
+
     #[arg(
         short = 'i',
         long = "ignore",
@@ -135,6 +528,18 @@ pub struct Args {
     )]
     pub ignore_file: Option<PathBuf>,
 
+    #[arg(
+        long,
+        help = "Also ignore files matched by the repository's .gitignore rules"
+    )]
+    pub respect_gitignore: bool,
+
+    #[arg(
+        long,
+        help = "Show the current time and session length in the status bar"
+    )]
+    pub clock: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -146,6 +551,17 @@ pub enum Commands {
         #[command(subcommand)]
         command: ThemeCommands,
     },
+    /// Configuration commands
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Print the effective configuration and the config file path
+    Show,
 }
 
 #[derive(Subcommand, Debug)]
@@ -157,6 +573,74 @@ pub enum ThemeCommands {
         #[arg(value_name = "NAME", help = "Theme name to set as default")]
         name: String,
     },
+    /// Preview a theme on a sample commit (press any key to exit)
+    Preview {
+        #[arg(value_name = "NAME", help = "Theme name to preview")]
+        name: String,
+    },
+    /// Print a theme as an editable TOML theme file
+    Dump {
+        #[arg(value_name = "NAME", help = "Theme name to export")]
+        name: String,
+    },
+}
+
+/// Parse a per-character typing delay with an optional `ms`/`s` suffix into milliseconds
+fn parse_speed(input: &str) -> Result<u64, String> {
+    let trimmed = input.trim();
+    let (number, multiplier) = if let Some(value) = trimmed.strip_suffix("ms") {
+        (value, 1.0)
+    } else if let Some(value) = trimmed.strip_suffix('s') {
+        (value, 1000.0)
+    } else {
+        (trimmed, 1.0)
+    };
+
+    let value: f64 = number.trim().parse().map_err(|_| {
+        format!(
+            "Invalid speed '{}'. Use milliseconds like '30' or '50ms', or seconds like '0.1s'",
+            input
+        )
+    })?;
+
+    let millis = (value * multiplier).round();
+    if !millis.is_finite() || millis < 1.0 {
+        return Err(format!(
+            "Speed must be at least 1ms per character (got '{}')",
+            input
+        ));
+    }
+
+    Ok(millis as u64)
+}
+
+fn parse_multiplier(input: &str) -> Result<f64, String> {
+    let value: f64 = input
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid number '{}'. Use a value like 0.5 or 2", input))?;
+
+    if !value.is_finite() || value < 0.0 {
+        return Err(format!("Value must be zero or positive (got '{}')", input));
+    }
+
+    Ok(value)
+}
+
+fn parse_probability(input: &str) -> Result<f64, String> {
+    let value: f64 = input
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid probability '{}'. Use a number like 0.05", input))?;
+
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!(
+            "Probability must be between 0.0 and 1.0 (got '{}')",
+            input
+        ));
+    }
+
+    Ok(value)
 }
 
 impl Args {
@@ -199,6 +683,66 @@ impl Args {
     }
 }
 
+/// Print the resolved playback plan (`--list-commits`) without starting the UI
+fn list_commits(
+    repo: &GitRepository,
+    single_commit: Option<&str>,
+    order: PlaybackOrder,
+    max_commits: Option<usize>,
+) -> Result<()> {
+    if matches!(order, PlaybackOrder::Random) && single_commit.is_none() {
+        let candidates = repo.list_commits()?.len();
+        match max_commits {
+            Some(max) => println!(
+                "Random order: {} commits drawn from {} candidates",
+                max, candidates
+            ),
+            None => println!(
+                "Random order: commits are drawn from these {} candidates",
+                candidates
+            ),
+        }
+    }
+
+    for commit in &planned_commits(repo, single_commit, order, max_commits)? {
+        println!(
+            "{}  {}  {}  {}",
+            git::short_hash(&commit.hash),
+            commit.date.format("%Y-%m-%d %H:%M"),
+            commit.author,
+            commit.summary
+        );
+    }
+
+    Ok(())
+}
+
+/// The commits `--list-commits` shows, capped by `--max-commits` like
+/// playback. A capped random order draws the picks as playback does, so they
+/// match what is played when `--seed` is given.
+fn planned_commits(
+    repo: &GitRepository,
+    single_commit: Option<&str>,
+    order: PlaybackOrder,
+    max_commits: Option<usize>,
+) -> Result<Vec<git::CommitSummary>> {
+    if let Some(hash) = single_commit {
+        return Ok(vec![repo.get_commit_summary(hash)?]);
+    }
+
+    let mut commits = match (order, max_commits) {
+        (PlaybackOrder::Random, Some(max)) => return repo.random_summaries(max),
+        _ => repo.list_commits()?,
+    };
+    if matches!(order, PlaybackOrder::Desc) {
+        commits.reverse();
+    }
+    if let Some(max) = max_commits {
+        commits.truncate(max);
+    }
+    Ok(commits)
+}
+
 fn main() -> Result<()> {
     let args = Args::parse();
 
@@ -221,7 +765,9 @@ fn main() -> Result<()> {
                 }
                 ThemeCommands::Set { name } => {
                     // Validate theme exists
-                    Theme::load(&name)?;
+                    if name != Theme::RANDOM {
+                        Theme::load(&name)?;
+                    }
 
                     // Load existing config or create new one
                     let mut config = Config::load().unwrap_or_default();
@@ -232,6 +778,33 @@ fn main() -> Result<()> {
                     println!("Theme set to '{}' in {}", name, config_path.display());
                     return Ok(());
                 }
+                ThemeCommands::Dump { name } => {
+                    print!("{}", Theme::load(&name)?.to_toml()?);
+                    return Ok(());
+                }
+                ThemeCommands::Preview { name } => {
+                    let theme = Theme::load(&name)?;
+                    let mut ui = UI::new(AnimationEngine::new(0), None, theme);
+                    return ui.run_preview(preview::sample_commit());
+                }
+            },
+            Commands::Config { command } => match command {
+                ConfigCommands::Show => {
+                    let config_path = Config::config_path()?;
+                    let config = Config::load()?;
+                    let file_keys = Config::file_keys()?;
+
+                    if config_path.exists() {
+                        println!("# Config file: {}", config_path.display());
+                    } else {
+                        println!(
+                            "# Config file: {} (not found, using defaults)",
+                            config_path.display()
+                        );
+                    }
+                    print!("{}", config.to_annotated_toml(&file_keys)?);
+                    return Ok(());
+                }
             },
         }
     }
@@ -239,10 +812,20 @@ fn main() -> Result<()> {
     let repo_path = args.validate()?;
     let mut repo = GitRepository::open(&repo_path)?;
 
+    if let Some(seed) = args.seed {
+        repo.set_seed(seed);
+    }
+
     // Set author filter if specified
     if args.author.is_some() {
         repo.set_author_filter(args.author.clone());
     }
+    if args.exclude_author.is_some() {
+        repo.set_author_exclude_filter(args.exclude_author.clone());
+    }
+    if args.grep.is_some() {
+        repo.set_message_filter(args.grep.clone(), args.grep_regex)?;
+    }
 
     // Set date filters if specified
     if let Some(ref before_str) = args.before {
@@ -253,14 +836,34 @@ fn main() -> Result<()> {
         let after_date = git::parse_date(after_str)?;
         repo.set_after_filter(Some(after_date));
     }
+    if args.follow.is_some() {
+        repo.set_path_filter(args.follow.clone());
+    }
+    if !args.status_filter.is_empty() {
+        repo.set_status_filter(Some(args.status_filter.iter().copied().collect()));
+    }
 
-    let is_commit_specified = args.commit.is_some();
-    let is_range_mode = args
-        .commit
-        .as_ref()
-        .map(|c| c.contains(".."))
-        .unwrap_or(false);
-    let is_filtered = args.author.is_some() || args.before.is_some() || args.after.is_some();
+    if let Some(ref refname) = args.start_ref {
+        repo.set_start_ref(refname)?;
+    } else if args.all {
+        repo.set_all_refs()?;
+    }
+    repo.set_include_merges(args.include_merges);
+    if let Some(parent) = args.merge_parent {
+        repo.set_merge_parent(parent as usize - 1);
+    }
+
+    let is_commit_specified = !args.commit.is_empty();
+    // Several commits play as a playlist, the same way as a range
+    let is_range_mode = args.commit.len() > 1 || args.commit.iter().any(|c| c.contains(".."));
+    let single_commit = args.commit.first().filter(|_| !is_range_mode);
+    let is_filtered = args.author.is_some()
+        || args.exclude_author.is_some()
+        || args.grep.is_some()
+        || args.before.is_some()
+        || args.after.is_some()
+        || args.follow.is_some()
+        || !args.status_filter.is_empty();
 
     // Load config: CLI arguments > config file > defaults
     let config = Config::load()?;
@@ -279,6 +882,12 @@ fn main() -> Result<()> {
     }
     patterns.extend(args.ignore.clone());
     git::init_ignore_patterns(&patterns).ok();
+    repo.set_respect_gitignore(args.respect_gitignore || config.respect_gitignore);
+    repo.set_excluded_files(git::ExcludedFiles::new(
+        &config.extra_excluded_files,
+        &config.extra_excluded_patterns,
+        &config.unexcluded_files,
+    ));
     let theme_name = args.theme.as_deref().unwrap_or(&config.theme);
     let speed = args.speed.unwrap_or(config.speed);
     let background = args.background.unwrap_or(config.background);
@@ -294,55 +903,260 @@ fn main() -> Result<()> {
     }
 
     let loop_playback = args.loop_playback.unwrap_or(config.loop_playback);
-    let mut theme = Theme::load(theme_name)?;
+    repo.set_random_no_repeat(args.random_no_repeat || config.random_no_repeat);
+    // An explicit --theme overrides rotate_themes from the config file
+    let rotate_themes =
+        theme_name == Theme::RANDOM || (args.theme.is_none() && config.rotate_themes);
+    let mut themes = if rotate_themes {
+        Theme::builtin_themes()
+            .iter()
+            .map(|name| Theme::load(name))
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        vec![Theme::load(theme_name)?]
+    };
 
     // Apply transparent background if requested
     if !background {
-        theme = theme.with_transparent_background();
+        themes = themes
+            .into_iter()
+            .map(Theme::with_transparent_background)
+            .collect();
     }
 
-    // Setup commit range if specified
+    // Setup commit range or playlist if specified
     if is_range_mode {
-        repo.set_commit_range(args.commit.as_ref().unwrap())?;
+        repo.set_commit_playlist(&args.commit)?;
+    }
+
+    if args.list_commits {
+        return list_commits(
+            &repo,
+            single_commit.map(String::as_str),
+            order,
+            args.max_commits.map(|n| n as usize),
+        );
+    }
+
+    // Sequential playback picks up where the last --resume session stopped
+    let resume_state = (args.resume
+        && single_commit.is_none()
+        && !matches!(order, PlaybackOrder::Random))
+    .then(|| PlaybackState {
+        repo: repo_path.clone(),
+        order: order
+            .to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default(),
+        commits: args.commit.clone(),
+        start: match &args.start_ref {
+            Some(refname) => refname.clone(),
+            None if args.all => "--all".to_string(),
+            None => "HEAD".to_string(),
+        },
+        commit_index: 0,
+    });
+    if let Some(state) = &resume_state {
+        let index = PlaybackState::load()
+            .filter(|saved| saved.same_playlist(state))
+            .map_or(0, |saved| saved.commit_index);
+        repo.set_commit_index(index)?;
     }
 
     // Load initial commit
-    let metadata = if is_range_mode {
-        match order {
-            PlaybackOrder::Random => repo.random_range_commit()?,
-            PlaybackOrder::Asc => repo.next_range_commit_asc()?,
-            PlaybackOrder::Desc => repo.next_range_commit_desc()?,
-        }
-    } else if let Some(commit_hash) = &args.commit {
-        repo.get_commit(commit_hash)?
-    } else {
-        match order {
-            PlaybackOrder::Random => repo.random_commit()?,
-            PlaybackOrder::Asc => repo.next_asc_commit()?,
-            PlaybackOrder::Desc => repo.next_desc_commit()?,
-        }
+    let selection = CommitSelection {
+        order,
+        loop_playback,
+        commit_spec: single_commit.cloned(),
+        is_range_mode,
     };
+    let metadata = selection.next_commit(&repo)?;
+    let position = selection.position(&repo);
+
+    // Filtered modes (range/author/date) always keep iterating the repository;
+    // a single specified commit only repeats when looping
+    let plays_more_commits = is_range_mode || is_filtered || !is_commit_specified || loop_playback;
 
-    // Create UI with repository reference
-    // Filtered modes (range/author/date) always need repo ref for iteration
-    let repo_ref = if is_range_mode || is_filtered {
-        Some(&repo)
-    } else if is_commit_specified && !loop_playback {
-        None
+    let mut engine = match args.fps {
+        Some(fps) => AnimationEngine::with_fps(speed, fps),
+        None => AnimationEngine::new(speed),
+    };
+    if let Some(seed) = args.seed {
+        engine.set_seed(seed);
+    }
+    engine.set_line_wrap(args.wrap.unwrap_or(config.wrap));
+    engine.set_tab_width(config.tab_width);
+    engine.set_typing_mode(args.typing_mode);
+    if let Some(typos) = args.typos {
+        engine.set_typo_probability(typos);
+    }
+    engine.set_type_indentation(args.type_indentation || config.type_indentation);
+    engine.set_comment_banner(args.comment_banner || config.comment_banner);
+    engine.set_reverse(args.reverse);
+    engine.set_intro(config.intro && !args.no_intro);
+    engine.set_animate_deletes(args.animate_deletes || config.animate_deletes);
+    engine.set_file_order(args.file_order);
+    engine.set_max_files(args.max_files_per_commit.map(|n| n as usize));
+    engine.set_fast_above(args.fast_above.map(|n| n as usize));
+    engine.set_excluded_preview(
+        args.excluded_preview
+            .unwrap_or(config.excluded_preview_lines),
+    );
+    let flavor = match args.flavor {
+        Some(flavor) => flavor,
+        None => Flavor::from_str(&config.flavor, true)
+            .map_err(|e| anyhow::anyhow!("Invalid flavor in config file: {}", e))?,
+    };
+    let glyphs = if args.ascii || config.ascii {
+        Glyphs::ASCII
     } else {
-        Some(&repo)
+        Glyphs::detect()
     };
-    let mut ui = UI::new(
-        speed,
-        repo_ref,
-        theme,
-        order,
-        loop_playback,
-        args.commit.clone(),
-        is_range_mode,
+    engine.set_narrator(narrator::narrator(flavor, glyphs));
+    engine.set_cursor_blink(if args.no_blink {
+        0
+    } else {
+        config.cursor_blink_ms
+    });
+    engine.set_pacing(PacingConfig {
+        scale: args.pause_scale.unwrap_or(config.pause_scale),
+        hunk: args.hunk_pause.unwrap_or(config.hunk_pause),
+        ..PacingConfig::default()
+    });
+
+    if args.dump_steps {
+        engine.load_commit(&metadata);
+        let json = serde_json::to_string_pretty(engine.steps())
+            .context("Failed to serialize animation steps")?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    // Recording only plays the first commit, so there is nothing to prefetch
+    let prefetcher = (plays_more_commits && args.output.is_none())
+        .then(|| CommitPrefetcher::spawn(repo, selection));
+    let mut ui = UI::new(engine, prefetcher, themes[0].clone());
+    if rotate_themes {
+        ui.set_theme_rotation(themes, args.seed);
+    }
+    ui.set_terminal_history(config.terminal_history);
+    ui.set_output_markers(OutputMarkers {
+        success: config.terminal_success_markers.clone(),
+        error: config.terminal_error_markers.clone(),
+    });
+    ui.set_max_commits(args.max_commits.map(|n| n as usize));
+    ui.set_resume_state(resume_state);
+    ui.set_date_format(args.date_format);
+    if args.clock || config.clock {
+        ui.set_clock(match config.clock_format.as_str() {
+            "12h" => ClockFormat::H12,
+            _ => ClockFormat::H24,
+        });
+    }
+    ui.set_editor_only(args.editor_only);
+    ui.set_terminal_position(args.terminal_position);
+    ui.set_layout(
+        args.file_tree_width.unwrap_or(config.file_tree_width_pct),
+        args.editor_height.unwrap_or(config.editor_height_pct),
     );
-    ui.load_commit(metadata);
+    ui.load_commit(metadata, position);
+
+    if let Some(path) = &args.output {
+        let (width, height) =
+            crossterm::terminal::size().unwrap_or((DEFAULT_CAST_WIDTH, DEFAULT_CAST_HEIGHT));
+        let out: Box<dyn std::io::Write> = if path.as_os_str() == "-" {
+            Box::new(std::io::stdout().lock())
+        } else {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("Failed to create {}", path.display()))?;
+            Box::new(std::io::BufWriter::new(file))
+        };
+        match args.frames {
+            Some(frames) => ui.record_frames(out, width, height, frames)?,
+            None => ui.record_cast(out, width, height)?,
+        }
+        return Ok(());
+    }
+
     ui.run()?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_speed_units() {
+        assert_eq!(parse_speed("50"), Ok(50));
+        assert_eq!(parse_speed("50ms"), Ok(50));
+        assert_eq!(parse_speed("0.1s"), Ok(100));
+        assert_eq!(parse_speed("2s"), Ok(2000));
+        assert_eq!(parse_speed(" 20 ms "), Ok(20));
+    }
+
+    #[test]
+    fn test_parse_speed_rejects_invalid() {
+        assert!(parse_speed("0").is_err());
+        assert!(parse_speed("-5ms").is_err());
+        assert!(parse_speed("0.0001s").is_err());
+        assert!(parse_speed("fast").is_err());
+        assert!(parse_speed("").is_err());
+    }
+
+    #[test]
+    fn test_parse_probability() {
+        assert_eq!(parse_probability("0.05"), Ok(0.05));
+        assert_eq!(parse_probability("1"), Ok(1.0));
+        assert!(parse_probability("1.5").is_err());
+        assert!(parse_probability("-0.1").is_err());
+        assert!(parse_probability("often").is_err());
+    }
+
+    #[test]
+    fn test_parse_multiplier() {
+        assert_eq!(parse_multiplier("0.5"), Ok(0.5));
+        assert_eq!(parse_multiplier("0"), Ok(0.0));
+        assert!(parse_multiplier("-1").is_err());
+        assert!(parse_multiplier("inf").is_err());
+        assert!(parse_multiplier("slow").is_err());
+    }
+
+    #[test]
+    fn test_planned_commits_respect_max_commits() {
+        let path = git::tests::create_test_repo("planned", 5);
+        let open = || {
+            let repo = GitRepository::open(&path).unwrap();
+            repo.set_seed(7);
+            repo
+        };
+        let summaries = |order, max| -> Vec<String> {
+            planned_commits(&open(), None, order, max)
+                .unwrap()
+                .into_iter()
+                .map(|c| c.summary)
+                .collect()
+        };
+
+        assert_eq!(
+            summaries(PlaybackOrder::Asc, Some(2)),
+            ["Commit 0", "Commit 1"]
+        );
+        assert_eq!(
+            summaries(PlaybackOrder::Desc, Some(2)),
+            ["Commit 4", "Commit 3"]
+        );
+        assert_eq!(summaries(PlaybackOrder::Random, None).len(), 5);
+
+        // Capped random order lists the commits playback would draw
+        let listed = summaries(PlaybackOrder::Random, Some(3));
+        let repo = open();
+        let played: Vec<String> = (0..3)
+            .map(|_| repo.random_commit().unwrap().message)
+            .collect();
+        assert_eq!(listed, played);
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+}
@@ -0,0 +1,418 @@
+use crate::git::{short_hash, CommitMetadata};
+use crate::glyphs::Glyphs;
+use crate::Flavor;
+
+/// Writes what the terminal pane prints around a commit. The engine types
+/// the `git` commands and paces the output; a narrator only supplies the
+/// text, so a new `--flavor` is one more implementation of this trait.
+pub trait Narrator {
+    /// Command typed to travel to the commit, and what it prints
+    fn intro(&self, metadata: &CommitMetadata) -> (String, Vec<String>);
+
+    /// One line shown instead of the intro with `--no-intro`
+    fn summary(&self, metadata: &CommitMetadata) -> String;
+
+    /// Shown for a file whose changes are not typed out
+    fn skipped(&self, path: &str, reason: &str) -> String;
+
+    /// Shown after typing only the start of a file's changes
+    fn truncated(&self, path: &str, remaining: usize) -> String;
+
+    /// Shown after the files animated with `--max-files-per-commit`
+    fn more_files(&self, remaining: usize) -> String;
+
+    /// Shown in the editor in place of a binary file's contents
+    fn binary(&self, size: usize) -> String;
+
+    /// Comment typed at the top of each file with `--comment-banner`,
+    /// without the comment tokens
+    fn banner(&self, metadata: &CommitMetadata) -> String;
+
+    /// Output of `git commit`
+    fn commit(&self, metadata: &CommitMetadata) -> Vec<String>;
+
+    /// Output of `git push`
+    fn push(&self, metadata: &CommitMetadata) -> Vec<String>;
+
+    /// Output of `git revert`, given the already reversed commit
+    fn revert(&self, metadata: &CommitMetadata) -> Vec<String>;
+}
+
+/// The narrator for a `--flavor`
+pub fn narrator(flavor: Flavor, glyphs: Glyphs) -> Box<dyn Narrator> {
+    match flavor {
+        Flavor::Fun => Box::new(FunNarrator { glyphs }),
+        Flavor::Plain => Box::new(PlainNarrator),
+    }
+}
+
+/// First line of the commit message
+pub fn subject(metadata: &CommitMetadata) -> &str {
+    metadata.message.lines().next().unwrap_or("Update")
+}
+
+fn plural(count: usize, word: &str) -> String {
+    format!("{} {}{}", count, word, if count == 1 { "" } else { "s" })
+}
+
+/// `1842` as `1,842`
+fn thousands(count: usize) -> String {
+    let digits = count.to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// `512 B`, `12.3 KB` or `4.0 MB`
+fn human_size(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// `(truncated, 1,842 more changes)`
+fn truncation_note(remaining: usize) -> String {
+    format!(
+        "(truncated, {} more change{})",
+        thousands(remaining),
+        if remaining == 1 { "" } else { "s" }
+    )
+}
+
+/// Time travel, immortalized commits and code beamed to the cloud
+pub struct FunNarrator {
+    pub glyphs: Glyphs,
+}
+
+impl Narrator for FunNarrator {
+    fn intro(&self, metadata: &CommitMetadata) -> (String, Vec<String>) {
+        let glyphs = &self.glyphs;
+        let datetime_str = metadata.date.format("%Y-%m-%d %H:%M:%S").to_string();
+        let location = if metadata.parent_hash.is_some() {
+            format!(
+                "{} Location: commit {} by {}",
+                glyphs.location,
+                short_hash(&metadata.hash),
+                metadata.author
+            )
+        } else {
+            format!(
+                "{} Location: root commit {} by {} - where it all began",
+                glyphs.root,
+                short_hash(&metadata.hash),
+                metadata.author
+            )
+        };
+        (
+            format!("time-travel {}", datetime_str),
+            vec![
+                format!(
+                    "{} Initializing temporal displacement field...",
+                    glyphs.displace
+                ),
+                format!("{} Warping through spacetime...", glyphs.warp),
+                format!("{} Arrived at {}", glyphs.arrived, datetime_str),
+                location,
+            ],
+        )
+    }
+
+    fn summary(&self, metadata: &CommitMetadata) -> String {
+        format!(
+            "{} commit {} by {} ({})",
+            self.glyphs.location,
+            short_hash(&metadata.hash),
+            metadata.author,
+            metadata.date.format("%Y-%m-%d %H:%M")
+        )
+    }
+
+    fn skipped(&self, path: &str, reason: &str) -> String {
+        format!("{} {} (skipped - {})", self.glyphs.package, path, reason)
+    }
+
+    fn truncated(&self, path: &str, remaining: usize) -> String {
+        format!(
+            "{} {} {} {}",
+            self.glyphs.package,
+            path,
+            self.glyphs.ellipsis,
+            truncation_note(remaining)
+        )
+    }
+
+    fn more_files(&self, remaining: usize) -> String {
+        format!(
+            "{} and {} more file{}",
+            self.glyphs.ellipsis,
+            remaining,
+            if remaining == 1 { "" } else { "s" }
+        )
+    }
+
+    fn binary(&self, size: usize) -> String {
+        format!(
+            "Binary file ({}) {} contents not shown",
+            human_size(size),
+            self.glyphs.dash
+        )
+    }
+
+    fn banner(&self, metadata: &CommitMetadata) -> String {
+        format!(
+            "commit {} {} {}",
+            short_hash(&metadata.hash),
+            self.glyphs.dash,
+            subject(metadata)
+        )
+    }
+
+    fn commit(&self, metadata: &CommitMetadata) -> Vec<String> {
+        let root_marker = if metadata.parent_hash.is_none() {
+            " (root-commit)"
+        } else {
+            ""
+        };
+        vec![
+            format!(
+                "{} [main{} {}] {}",
+                self.glyphs.commit,
+                root_marker,
+                short_hash(&metadata.hash),
+                subject(metadata)
+            ),
+            format!(
+                "{} {} changed - immortalized forever!",
+                self.glyphs.files_changed,
+                plural(metadata.changes.len(), "file")
+            ),
+        ]
+    }
+
+    fn push(&self, metadata: &CommitMetadata) -> Vec<String> {
+        let glyphs = &self.glyphs;
+        // A root commit creates the branch rather than advancing it
+        let summary = match &metadata.parent_hash {
+            Some(parent_hash) => format!(
+                "   {}..{} {} SUCCESS",
+                short_hash(parent_hash),
+                short_hash(&metadata.hash),
+                glyphs.success
+            ),
+            None => format!("   * [new branch] main -> main {} SUCCESS", glyphs.success),
+        };
+        vec![
+            format!("{} Launching code into the cloud...", glyphs.launch),
+            format!("{} Compressing digital dreams: 100% (5/5)", glyphs.package),
+            format!("{} Signing with invisible ink: done.", glyphs.sign),
+            format!("{} Beaming to origin/main via satellite...", glyphs.beam),
+            summary,
+        ]
+    }
+
+    fn revert(&self, metadata: &CommitMetadata) -> Vec<String> {
+        vec![
+            format!(
+                "{} [main] Revert \"{}\"",
+                self.glyphs.revert,
+                subject(metadata)
+            ),
+            format!(
+                "{} {} changed - as if it never happened.",
+                self.glyphs.sweep,
+                plural(metadata.changes.len(), "file")
+            ),
+        ]
+    }
+}
+
+/// Output that looks like what `git` itself prints
+pub struct PlainNarrator;
+
+impl PlainNarrator {
+    /// ` 2 files changed, 5 insertions(+), 1 deletion(-)`
+    fn stat_line(metadata: &CommitMetadata) -> String {
+        let (additions, deletions) = metadata.line_counts();
+        let mut line = format!(" {} changed", plural(metadata.changes.len(), "file"));
+        if additions > 0 {
+            line += &format!(", {}(+)", plural(additions, "insertion"));
+        }
+        if deletions > 0 {
+            line += &format!(", {}(-)", plural(deletions, "deletion"));
+        }
+        line
+    }
+}
+
+impl Narrator for PlainNarrator {
+    fn intro(&self, metadata: &CommitMetadata) -> (String, Vec<String>) {
+        (
+            format!("git log -1 {}", short_hash(&metadata.hash)),
+            vec![
+                format!("commit {}", metadata.hash),
+                format!("Author: {}", metadata.author),
+                format!(
+                    "Date:   {}",
+                    metadata.date.format("%a %b %-d %H:%M:%S %Y %z")
+                ),
+                String::new(),
+                format!("    {}", subject(metadata)),
+            ],
+        )
+    }
+
+    fn summary(&self, metadata: &CommitMetadata) -> String {
+        format!("{} {}", short_hash(&metadata.hash), subject(metadata))
+    }
+
+    fn skipped(&self, path: &str, reason: &str) -> String {
+        format!("skipping {} ({})", path, reason)
+    }
+
+    fn truncated(&self, path: &str, remaining: usize) -> String {
+        format!("{}: ... {}", path, truncation_note(remaining))
+    }
+
+    fn more_files(&self, remaining: usize) -> String {
+        format!(
+            "({} more file{} not shown)",
+            remaining,
+            if remaining == 1 { "" } else { "s" }
+        )
+    }
+
+    fn binary(&self, size: usize) -> String {
+        format!("Binary file not shown ({})", human_size(size))
+    }
+
+    fn banner(&self, metadata: &CommitMetadata) -> String {
+        format!("{} {}", short_hash(&metadata.hash), subject(metadata))
+    }
+
+    fn commit(&self, metadata: &CommitMetadata) -> Vec<String> {
+        let root_marker = if metadata.parent_hash.is_none() {
+            " (root-commit)"
+        } else {
+            ""
+        };
+        vec![
+            format!(
+                "[main{} {}] {}",
+                root_marker,
+                short_hash(&metadata.hash),
+                subject(metadata)
+            ),
+            Self::stat_line(metadata),
+        ]
+    }
+
+    fn push(&self, metadata: &CommitMetadata) -> Vec<String> {
+        // A commit, its tree and one blob per file, give or take
+        let objects = metadata.changes.len() + 2;
+        let update = match &metadata.parent_hash {
+            Some(parent_hash) => format!(
+                "   {}..{}  main -> main",
+                short_hash(parent_hash),
+                short_hash(&metadata.hash)
+            ),
+            None => " * [new branch]      main -> main".to_string(),
+        };
+        vec![
+            format!("Enumerating objects: {}, done.", objects),
+            format!("Counting objects: 100% ({}/{}), done.", objects, objects),
+            format!("Writing objects: 100% ({}/{}), done.", objects, objects),
+            format!(
+                "Total {} (delta 0), reused 0 (delta 0), pack-reused 0",
+                objects
+            ),
+            "To origin".to_string(),
+            update,
+        ]
+    }
+
+    fn revert(&self, metadata: &CommitMetadata) -> Vec<String> {
+        vec![
+            format!(
+                "[main {}] Revert \"{}\"",
+                short_hash(&metadata.hash),
+                subject(metadata)
+            ),
+            Self::stat_line(metadata),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preview::sample_commit;
+
+    #[test]
+    fn test_plain_output_reads_like_git() {
+        let metadata = sample_commit();
+        let (command, intro) = PlainNarrator.intro(&metadata);
+        assert_eq!(command, "git log -1 c0ffee1");
+        assert_eq!(intro[0], format!("commit {}", metadata.hash));
+        assert_eq!(
+            PlainNarrator.commit(&metadata),
+            [
+                "[main c0ffee1] Greet the user by name".to_string(),
+                " 3 files changed, 6 insertions(+), 1 deletion(-)".to_string(),
+            ]
+        );
+        assert!(PlainNarrator
+            .push(&metadata)
+            .contains(&"   deadbee..c0ffee1  main -> main".to_string()));
+    }
+
+    #[test]
+    fn test_notes_truncated_changes() {
+        assert_eq!(truncation_note(1), "(truncated, 1 more change)");
+        assert_eq!(truncation_note(1842), "(truncated, 1,842 more changes)");
+        assert_eq!(thousands(999), "999");
+        assert_eq!(thousands(1_234_567), "1,234,567");
+    }
+
+    #[test]
+    fn test_formats_file_sizes() {
+        assert_eq!(human_size(512), "512 B");
+        assert_eq!(human_size(12_595), "12.3 KB");
+        assert_eq!(human_size(4 * 1024 * 1024), "4.0 MB");
+    }
+
+    #[test]
+    fn test_flavors_cover_the_same_messages() {
+        let metadata = sample_commit();
+        for flavor in [Flavor::Fun, Flavor::Plain] {
+            let narrator = narrator(flavor, Glyphs::ASCII);
+            let text = [
+                narrator.intro(&metadata).1,
+                vec![narrator.summary(&metadata)],
+                vec![narrator.skipped("Cargo.lock", "lock file")],
+                vec![narrator.truncated("Cargo.lock", 1842)],
+                vec![narrator.banner(&metadata)],
+                vec![narrator.binary(12_595)],
+                vec![narrator.more_files(23)],
+                narrator.commit(&metadata),
+                narrator.push(&metadata),
+                narrator.revert(&metadata),
+            ]
+            .concat();
+            assert!(text.iter().all(|line| line.is_ascii()), "{:?}", text);
+            assert!(narrator.commit(&metadata)[0].contains("Greet the user by name"));
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding},
+    Frame,
+};
+
+use crate::git::CommitMetadata;
+use crate::theme::Theme;
+use crate::widgets::{SelectableParagraph, WrapMode};
+
+// Text width the overlay grows to before wrapping
+const MAX_CONTENT_WIDTH: u16 = 76;
+
+/// The full commit message (subject, body and trailers) in an overlay,
+/// scrolled when it is taller than the screen
+#[derive(Default)]
+pub struct CommitMessagePane {
+    scroll: usize,
+    // Furthest the message could be scrolled at the last render
+    max_scroll: usize,
+    // Lines of text shown at the last render, scrolled by a page
+    page: usize,
+}
+
+impl CommitMessagePane {
+    pub fn reset_scroll(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// Scroll by `lines`, negative to scroll up
+    pub fn scroll_by(&mut self, lines: isize) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(lines)
+            .min(self.max_scroll);
+    }
+
+    pub fn page(&self) -> isize {
+        self.page.max(1) as isize
+    }
+
+    /// Draw the overlay centered in `size`
+    pub fn render(
+        &mut self,
+        f: &mut Frame,
+        size: Rect,
+        metadata: Option<&CommitMetadata>,
+        theme: &Theme,
+    ) {
+        let lines = match metadata {
+            Some(meta) => message_lines(&meta.message, theme),
+            None => vec![Line::from(Span::styled(
+                "No commit loaded",
+                Style::default().fg(theme.status_no_commit),
+            ))],
+        };
+
+        // Borders (2) and padding (2 horizontal, 1 vertical each side)
+        let longest = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+        let width = (longest.min(MAX_CONTENT_WIDTH) + 6).min(size.width);
+        let paragraph = SelectableParagraph::new(lines).wrap_mode(WrapMode::Word);
+        let line_count = paragraph.line_count(width.saturating_sub(6));
+        let height = (line_count as u16).saturating_add(4).min(size.height);
+        let area = Rect {
+            x: size.x + (size.width - width) / 2,
+            y: size.y + (size.height - height) / 2,
+            width,
+            height,
+        };
+
+        self.page = height.saturating_sub(4) as usize;
+        self.max_scroll = line_count.saturating_sub(self.page);
+        self.scroll = self.scroll.min(self.max_scroll);
+
+        let style = Style::default()
+            .fg(theme.file_tree_current_file_fg)
+            .bg(theme.editor_cursor_line_bg);
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Commit message ")
+            .padding(Padding::new(2, 2, 1, 1))
+            .style(style);
+        if self.max_scroll > 0 {
+            block = block.title_bottom(format!(
+                " {}/{} j/k to scroll ",
+                self.scroll + self.page,
+                line_count
+            ));
+        }
+
+        f.render_widget(Clear, area);
+        f.render_widget(
+            paragraph
+                .block(block)
+                .background_style(style)
+                .scroll(self.scroll),
+            area,
+        );
+    }
+}
+
+/// The subject in bold, the body as written, and trailers such as
+/// `Signed-off-by:` with their keys picked out
+fn message_lines(message: &str, theme: &Theme) -> Vec<Line<'static>> {
+    let text: Vec<&str> = message.trim_end().lines().map(str::trim_end).collect();
+    let trailers = trailer_start(&text).unwrap_or(text.len());
+
+    text.iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line = line.to_string();
+            if index == 0 {
+                return Line::from(Span::styled(
+                    line,
+                    Style::default()
+                        .fg(theme.status_message)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            if index >= trailers {
+                if let Some((key, value)) = line.split_once(':') {
+                    return Line::from(vec![
+                        Span::styled(format!("{}:", key), Style::default().fg(theme.status_hash)),
+                        Span::styled(
+                            value.to_string(),
+                            Style::default().fg(theme.status_no_commit),
+                        ),
+                    ]);
+                }
+            }
+            Line::from(Span::styled(
+                line,
+                Style::default().fg(theme.status_message),
+            ))
+        })
+        .collect()
+}
+
+/// Index of the first line of the trailer block: a final paragraph, after
+/// the subject, made up only of `Key: value` lines
+fn trailer_start(lines: &[&str]) -> Option<usize> {
+    let start = lines.iter().rposition(|line| line.is_empty())? + 1;
+    let is_trailer = |line: &&str| {
+        line.split_once(": ").is_some_and(|(key, _)| {
+            !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+    };
+    (start < lines.len() && lines[start..].iter().all(is_trailer)).then_some(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preview::sample_commit;
+    use crate::snapshot::buffer_text;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn test_finds_trailers() {
+        let message = "Fix the thing\n\nLonger explanation: with a colon.\n\nSigned-off-by: A <a@example.com>\nReviewed-by: B";
+        let lines: Vec<&str> = message.lines().collect();
+        assert_eq!(trailer_start(&lines), Some(4));
+        // A body paragraph that only looks like a trailer in part
+        assert_eq!(trailer_start(&["Subject", "", "Note: one", "more"]), None);
+        assert_eq!(trailer_start(&["Subject: only"]), None);
+    }
+
+    #[test]
+    fn test_scrolls_long_messages() {
+        let mut meta = sample_commit();
+        meta.message = std::iter::once("Subject".to_string())
+            .chain((0..40).map(|i| format!("body line {}", i)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut pane = CommitMessagePane::default();
+        let theme = Theme::default();
+        let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+        let mut draw = |pane: &mut CommitMessagePane| {
+            terminal
+                .draw(|f| pane.render(f, f.area(), Some(&meta), &theme))
+                .unwrap();
+            buffer_text(terminal.backend().buffer())
+        };
+
+        let top = draw(&mut pane);
+        assert!(top.contains("Subject"));
+        assert!(top.contains("8/41"));
+
+        pane.scroll_by(isize::MAX);
+        assert_eq!(pane.scroll, 33);
+        let bottom = draw(&mut pane);
+        assert!(bottom.contains("body line 39"));
+        assert!(!bottom.contains("Subject"));
+
+        pane.scroll_by(-pane.page());
+        assert_eq!(pane.scroll, 25);
+    }
+}
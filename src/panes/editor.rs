@@ -1,16 +1,24 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Padding},
+    widgets::{Block, Borders, Padding},
     Frame,
 };
 
-use crate::animation::{ActivePane, AnimationEngine};
+use std::ops::Range;
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::animation::{char_display_width, ActivePane, AnimationEngine, LineOrigin};
 use crate::theme::Theme;
-use crate::widgets::SelectableParagraph;
+use crate::widgets::{Minimap, SelectableParagraph};
 
-pub struct EditorPane;
+#[derive(Default)]
+pub struct EditorPane {
+    // Highlighted in every visible line while searching
+    search_query: Option<String>,
+}
 
 struct HighlightContext<'a> {
     line_content: &'a str,
@@ -23,19 +31,100 @@ struct HighlightContext<'a> {
     old_line_offsets: &'a [usize],
     new_line_offsets: &'a [usize],
     line_offset: isize,
+    tab_width: usize,
+    // Character ranges of search matches in this line
+    search_matches: &'a [Range<usize>],
     theme: &'a Theme,
 }
 
+/// Character ranges where `query` occurs in `line`, ignoring case
+pub fn find_matches(line: &str, query: &str) -> Vec<Range<usize>> {
+    let line: Vec<char> = line.chars().collect();
+    let query: Vec<char> = query.chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    let mut start = 0;
+    while start + query.len() <= line.len() {
+        let end = start + query.len();
+        let found = line[start..end]
+            .iter()
+            .zip(&query)
+            .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()));
+        if found {
+            matches.push(start..end);
+            start = end;
+        } else {
+            start += 1;
+        }
+    }
+    matches
+}
+
 impl EditorPane {
+    pub fn set_search_query(&mut self, query: Option<String>) {
+        self.search_query = query;
+    }
+
+    /// Columns left for line text when the pane is drawn in `area`, after
+    /// the border, padding, line numbers and separator, and the minimap
+    /// column when wrapped lines have to stay clear of it
+    pub fn text_width(area: Rect, engine: &AnimationEngine) -> usize {
+        let content_height = area.height.saturating_sub(2) as usize;
+        let line_count = engine.buffer.lines.len();
+        let line_num_width = format!("{}", line_count).len().max(3);
+        let show_minimap = line_count > content_height && area.width > 0;
+        let width = if show_minimap && engine.line_wrap() {
+            area.width - 1
+        } else {
+            area.width
+        };
+        // Left border and padding (1 + 1), right padding (2), line number and
+        // trailing space, separator
+        (width as usize).saturating_sub(4 + line_num_width + 1 + 2)
+    }
+
     pub fn render(&self, f: &mut Frame, area: Rect, engine: &AnimationEngine, theme: &Theme) {
+        let border = if engine.active_pane == ActivePane::Editor {
+            theme.border_active
+        } else {
+            theme.editor_border
+        };
         let block = Block::default()
+            .borders(Borders::LEFT)
+            .border_style(Style::default().fg(border))
             .style(Style::default().bg(theme.background_right))
             .padding(Padding::vertical(1));
 
         let content_height = area.height.saturating_sub(2) as usize; // Subtract top and bottom padding
-        let scroll_offset = engine.buffer.scroll_offset;
+        let scroll_offset = engine.buffer.display_offset();
         let buffer_lines = &engine.buffer.lines;
         let line_num_width = format!("{}", buffer_lines.len()).len().max(3);
+        let show_minimap = buffer_lines.len() > content_height && area.width > 0;
+
+        // Wrapped lines run into the right padding, so keep them clear of the minimap
+        let text_area = if show_minimap && engine.line_wrap() {
+            f.render_widget(
+                Block::default().style(Style::default().bg(theme.background_right)),
+                area,
+            );
+            Rect {
+                width: area.width - 1,
+                ..area
+            }
+        } else {
+            area
+        };
+
+        // Without wrapping, show only the horizontally scrolled window of each line
+        let column_window = (!engine.line_wrap()).then(|| {
+            (
+                engine.buffer.h_scroll_offset,
+                Self::text_width(area, engine),
+            )
+        });
 
         let visible_lines: Vec<Line> = buffer_lines
             .iter()
@@ -44,7 +133,14 @@ impl EditorPane {
             .enumerate()
             .map(|(idx, line_content)| {
                 let line_num = scroll_offset + idx;
-                self.build_line(line_content, line_num, line_num_width, engine, theme)
+                self.build_line(
+                    line_content,
+                    line_num,
+                    line_num_width,
+                    column_window,
+                    engine,
+                    theme,
+                )
             })
             .collect();
 
@@ -65,9 +161,25 @@ impl EditorPane {
             .selected_line(selected_line_index)
             .selected_style(Style::default().bg(theme.editor_cursor_line_bg))
             .background_style(Style::default().bg(theme.background_right))
-            .padding(Padding::horizontal(2))
+            .padding(Padding::new(1, 2, 0, 0))
             .dim(20, 0.6);
-        f.render_widget(content, area);
+        f.render_widget(content, text_area);
+
+        // Overview of the whole file in the last column of the right padding
+        if show_minimap {
+            let minimap_area = Rect::new(area.right() - 1, area.y + 1, 1, content_height as u16);
+            let minimap = Minimap::new(&engine.buffer.line_origins)
+                .window(scroll_offset, content_height)
+                .style(
+                    Style::default()
+                        .fg(theme.editor_separator)
+                        .bg(theme.background_right),
+                )
+                .window_style(Style::default().bg(theme.editor_cursor_line_bg))
+                .added_style(Style::default().fg(theme.file_tree_added))
+                .modified_style(Style::default().fg(theme.file_tree_modified));
+            f.render_widget(minimap, minimap_area);
+        }
     }
 
     fn build_line(
@@ -75,6 +187,7 @@ impl EditorPane {
         line_content: &str,
         line_num: usize,
         line_num_width: usize,
+        column_window: Option<(usize, usize)>,
         engine: &AnimationEngine,
         theme: &Theme,
     ) -> Line<'_> {
@@ -85,13 +198,21 @@ impl EditorPane {
 
         spans.push(self.render_line_number(line_num, is_cursor_line, line_num_width, theme));
 
-        spans.push(Span::styled(
-            "  ",
-            Style::default().fg(theme.editor_separator),
-        ));
+        // Diff gutter: the marker takes the first of the two separator columns
+        let (marker, marker_color, line_bg) = match engine.buffer.line_origin(line_num) {
+            LineOrigin::Added => ("+ ", theme.file_tree_added, Some(theme.diff_added_bg)),
+            LineOrigin::Modified => ("~ ", theme.file_tree_modified, Some(theme.diff_modified_bg)),
+            LineOrigin::Unchanged => ("  ", theme.editor_separator, None),
+        };
+        spans.push(Span::styled(marker, Style::default().fg(marker_color)));
 
         let show_cursor =
             is_cursor_line && engine.cursor_visible && engine.active_pane == ActivePane::Editor;
+        let search_matches = self
+            .search_query
+            .as_deref()
+            .map(|query| find_matches(line_content, query))
+            .unwrap_or_default();
 
         let line_spans = self.highlight_line(HighlightContext {
             line_content,
@@ -104,12 +225,21 @@ impl EditorPane {
             old_line_offsets: &engine.buffer.old_content_line_offsets,
             new_line_offsets: &engine.buffer.new_content_line_offsets,
             line_offset: engine.line_offset,
+            tab_width: engine.tab_width(),
+            search_matches: &search_matches,
             theme,
         });
 
-        spans.extend(line_spans);
+        match column_window {
+            Some((start, width)) => spans.extend(crop_columns(line_spans, start, width)),
+            None => spans.extend(line_spans),
+        }
 
-        Line::from(spans)
+        // Tint changed lines; syntax colors stay on top
+        match line_bg {
+            Some(bg) => Line::from(spans).style(Style::default().bg(bg)),
+            None => Line::from(spans),
+        }
     }
 
     fn render_line_number(
@@ -220,18 +350,31 @@ impl EditorPane {
         let mut spans = Vec::new();
 
         let mut relative_byte = 0;
+        let mut column = 0;
         for (char_idx, ch) in chars.iter().enumerate() {
             let char_byte_start = byte_offset + relative_byte;
             let char_byte_end = char_byte_start + ch.len_utf8();
             relative_byte += ch.len_utf8();
 
-            let color =
-                self.get_char_color(char_byte_start, char_byte_end, line_highlights, ctx.theme);
+            // Tabs expand to spaces up to the next tab stop
+            let ch_width = char_display_width(*ch, column, ctx.tab_width);
+            column += ch_width;
+            let text = if *ch == '\t' {
+                " ".repeat(ch_width)
+            } else {
+                ch.to_string()
+            };
+
+            let mut style =
+                self.get_char_style(char_byte_start, char_byte_end, line_highlights, ctx.theme);
+            if ctx.search_matches.iter().any(|m| m.contains(&char_idx)) {
+                style = style.bg(ctx.theme.editor_search_match_bg);
+            }
 
             if ctx.show_cursor && char_idx == ctx.cursor_col {
-                // Cursor character - bright highlight
+                // Cursor character - bright highlight covering its full display width
                 spans.push(Span::styled(
-                    ch.to_string(),
+                    text,
                     Style::default()
                         .bg(ctx.theme.editor_cursor_char_bg)
                         .fg(ctx.theme.editor_cursor_char_fg)
@@ -239,7 +382,7 @@ impl EditorPane {
                 ));
             } else {
                 // Normal character
-                spans.push(Span::styled(ch.to_string(), Style::default().fg(color)));
+                spans.push(Span::styled(text, style));
             }
         }
 
@@ -256,17 +399,99 @@ impl EditorPane {
         spans
     }
 
-    fn get_char_color(
+    fn get_char_style(
         &self,
         char_byte_start: usize,
         char_byte_end: usize,
         line_highlights: &[(usize, usize, crate::syntax::TokenType)],
         theme: &Theme,
-    ) -> Color {
+    ) -> Style {
         line_highlights
             .iter()
             .find(|h| char_byte_start >= h.0 && char_byte_end <= h.1)
-            .map(|h| h.2.color(theme))
-            .unwrap_or(theme.syntax_variable) // Use theme color instead of Color::White
+            .map(|h| theme.syntax_style(h.2))
+            .unwrap_or_else(|| Style::default().fg(theme.syntax_variable)) // Use theme color instead of Color::White
+    }
+}
+
+/// Keep only the display columns `start..start + width` of a line, replacing
+/// wide characters cut by either edge with spaces
+fn crop_columns(spans: Vec<Span<'_>>, start: usize, width: usize) -> Vec<Span<'_>> {
+    let end = start + width;
+    let mut column = 0;
+    let mut cropped = Vec::new();
+
+    for span in spans {
+        let mut content = String::new();
+        for ch in span.content.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            let ch_end = column + ch_width;
+            if column >= start && ch_end <= end {
+                content.push(ch);
+            } else if ch_end > start && column < end {
+                // Partially visible wide character
+                let visible = ch_end.min(end) - column.max(start);
+                content.extend(std::iter::repeat_n(' ', visible));
+            }
+            column = ch_end;
+        }
+        if !content.is_empty() {
+            cropped.push(Span::styled(content, span.style));
+        }
+        if column >= end {
+            break;
+        }
+    }
+
+    cropped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_covers_wide_char_and_expanded_tab() {
+        let theme = Theme::default();
+        let cursor_style = Style::default()
+            .bg(theme.editor_cursor_char_bg)
+            .fg(theme.editor_cursor_char_fg)
+            .add_modifier(Modifier::BOLD);
+        let ctx = |cursor_col| HighlightContext {
+            line_content: "漢\tx",
+            line_num: 0,
+            show_cursor: true,
+            cursor_col,
+            cursor_line: 0,
+            old_highlights: &[],
+            new_highlights: &[],
+            old_line_offsets: &[0],
+            new_line_offsets: &[0],
+            line_offset: 0,
+            tab_width: 4,
+            search_matches: &[],
+            theme: &theme,
+        };
+
+        // The tab starts at column 2, so it expands to the tab stop at column 4
+        let pane = EditorPane::default();
+        let spans = pane.apply_highlights(&[], 0, &ctx(1));
+        let texts: Vec<&str> = spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(texts, ["漢", "  ", "x"]);
+        assert_eq!(spans[1].style, cursor_style);
+
+        let spans = pane.apply_highlights(&[], 0, &ctx(0));
+        assert_eq!(spans[0].content, "漢");
+        assert_eq!(spans[0].style, cursor_style);
+    }
+
+    #[test]
+    fn test_find_matches_counts_characters() {
+        assert_eq!(find_matches("Foo foo fOO", "foo"), [0..3, 4..7, 8..11]);
+        assert_eq!(find_matches("aaaa", "aa"), [0..2, 2..4]);
+        // Ranges are in characters, not bytes
+        assert_eq!(find_matches("漢字 le café Café", "café"), [6..10, 11..15]);
+        assert!(find_matches("abc", "").is_empty());
+        assert!(find_matches("ab", "abc").is_empty());
     }
 }
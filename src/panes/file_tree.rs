@@ -1,19 +1,17 @@
-use std::collections::BTreeMap;
+use std::collections::HashSet;
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Padding},
     Frame,
 };
 
-use crate::git::{CommitMetadata, LineChangeType};
+use crate::git::CommitMetadata;
 use crate::theme::Theme;
-use crate::widgets::SelectableParagraph;
-
-type FileEntry = (usize, String, String, Color, usize, usize);
-type FileTree = BTreeMap<String, Vec<FileEntry>>;
+use crate::widgets::{SelectableParagraph, WrapMode};
+use crate::FileOrder;
 
 pub struct FileTreePane {
     cached_lines: Vec<Line<'static>>,
@@ -32,10 +30,14 @@ impl FileTreePane {
         }
     }
 
+    /// Show the files of `metadata` in `order`, with `current_file_index`
+    /// selected and any beyond the first `max_files` dimmed as not animated
     pub fn set_commit_metadata(
         &mut self,
         metadata: &CommitMetadata,
         current_file_index: usize,
+        order: FileOrder,
+        max_files: Option<usize>,
         theme: &Theme,
     ) {
         let metadata_id = metadata.hash.clone();
@@ -47,8 +49,14 @@ impl FileTreePane {
             return;
         }
 
+        let order = order.file_indices(metadata);
+        let skipped: HashSet<usize> = order
+            .iter()
+            .skip(max_files.unwrap_or(usize::MAX))
+            .copied()
+            .collect();
         let (lines, current_line_index) =
-            Self::build_tree_lines(metadata, current_file_index, theme);
+            Self::build_tree_lines(metadata, current_file_index, &order, &skipped, theme);
 
         self.cached_lines = lines;
         self.cached_current_line_index = current_line_index;
@@ -72,6 +80,7 @@ impl FileTreePane {
             .selected_style(Style::default().bg(theme.file_tree_current_file_bg))
             .background_style(Style::default().bg(theme.background_left))
             .padding(Padding::horizontal(2))
+            .wrap_mode(WrapMode::Word)
             .dim(20, 0.6);
         f.render_widget(content, area);
     }
@@ -79,12 +88,17 @@ impl FileTreePane {
     fn build_tree_lines(
         metadata: &CommitMetadata,
         current_file_index: usize,
+        order: &[usize],
+        skipped: &HashSet<usize>,
         theme: &Theme,
     ) -> (Vec<Line<'static>>, Option<usize>) {
-        // Build directory tree
-        let mut tree: FileTree = BTreeMap::new();
+        let mut lines = Vec::new();
+        let mut current_line_index = None;
+        let mut current_dir = None;
 
-        for (index, change) in metadata.changes.iter().enumerate() {
+        // `order` keeps each directory's files together
+        for &index in order {
+            let change = &metadata.changes[index];
             let (status_char, color) = match change.status.as_str() {
                 "A" => ("+", theme.file_tree_added),
                 "D" => ("-", theme.file_tree_deleted),
@@ -93,112 +107,71 @@ impl FileTreePane {
                 _ => (" ", theme.file_tree_default),
             };
 
-            // Count additions and deletions
-            let mut additions = 0;
-            let mut deletions = 0;
-            for hunk in &change.hunks {
-                for line in &hunk.lines {
-                    match line.change_type {
-                        LineChangeType::Addition => additions += 1,
-                        LineChangeType::Deletion => deletions += 1,
-                        _ => {}
-                    }
-                }
-            }
+            let (additions, deletions) = change.line_counts();
+            let (dir, filename) = change.path.rsplit_once('/').unwrap_or(("", &change.path));
 
-            let parts: Vec<&str> = change.path.split('/').collect();
-            if parts.len() == 1 {
-                // Root level file
-                tree.entry("".to_string()).or_default().push((
-                    index,
-                    change.path.clone(),
-                    status_char.to_string(),
-                    color,
-                    additions,
-                    deletions,
-                ));
-            } else {
-                // File in directory
-                let dir = parts[..parts.len() - 1].join("/");
-                let filename = parts[parts.len() - 1].to_string();
-                tree.entry(dir).or_default().push((
-                    index,
-                    filename,
-                    status_char.to_string(),
-                    color,
-                    additions,
-                    deletions,
-                ));
+            // Add directory header if not root
+            if current_dir != Some(dir) {
+                current_dir = Some(dir);
+                if !dir.is_empty() {
+                    let dir_text = format!("{}/", dir);
+                    let dir_spans = vec![Span::styled(
+                        dir_text,
+                        Style::default()
+                            .fg(theme.file_tree_directory)
+                            .add_modifier(Modifier::BOLD),
+                    )];
+                    lines.push(Line::from(dir_spans));
+                }
             }
-        }
-
-        let mut lines = Vec::new();
-        let mut current_line_index = None;
-        let sorted_dirs: Vec<_> = tree.keys().cloned().collect();
 
-        for dir in sorted_dirs {
-            let mut files = tree.get(&dir).unwrap().clone();
-            // Sort files by filename within each directory
-            files.sort_by(|a, b| a.1.cmp(&b.1));
+            let is_current = index == current_file_index;
 
-            // Add directory header if not root
-            if !dir.is_empty() {
-                let dir_text = format!("{}/", dir);
-                let dir_spans = vec![Span::styled(
-                    dir_text,
-                    Style::default()
-                        .fg(theme.file_tree_directory)
-                        .add_modifier(Modifier::BOLD),
-                )];
-                lines.push(Line::from(dir_spans));
+            // Track the line index of the current file (before adding the line)
+            if is_current {
+                current_line_index = Some(lines.len());
             }
 
-            // Add files
-            for (index, filename, status_char, color, additions, deletions) in &files {
-                let is_current = *index == current_file_index;
+            let indent = if dir.is_empty() { "" } else { "  " }.to_string();
+            let status_str = format!("{} ", status_char);
+            let additions_str = format!(" +{}", additions);
+            let deletions_str = format!(" -{}", deletions);
 
-                // Track the line index of the current file (before adding the line)
-                if is_current {
-                    current_line_index = Some(lines.len());
-                }
+            let fg_color = if is_current {
+                theme.file_tree_current_file_fg
+            } else {
+                theme.file_tree_default
+            };
 
-                let indent = if dir.is_empty() { "" } else { "  " }.to_string();
-                let status_str = format!("{} ", status_char);
-                let additions_str = format!(" +{}", additions);
-                let deletions_str = format!(" -{}", deletions);
-
-                let fg_color = if is_current {
-                    theme.file_tree_current_file_fg
-                } else {
-                    theme.file_tree_default
-                };
-
-                let modifier = if is_current {
-                    Modifier::BOLD
-                } else {
-                    Modifier::empty()
-                };
-
-                let spans = vec![
-                    Span::raw(indent),
-                    Span::styled(
-                        status_str,
-                        Style::default().fg(*color).add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(
-                        filename.to_string(),
-                        Style::default().fg(fg_color).add_modifier(modifier),
-                    ),
-                    Span::styled(
-                        additions_str,
-                        Style::default().fg(theme.file_tree_stats_added),
-                    ),
-                    Span::styled(
-                        deletions_str,
-                        Style::default().fg(theme.file_tree_stats_deleted),
-                    ),
-                ];
+            let modifier = if is_current {
+                Modifier::BOLD
+            } else {
+                Modifier::empty()
+            };
 
+            let spans = vec![
+                Span::raw(indent),
+                Span::styled(
+                    status_str,
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    filename.to_string(),
+                    Style::default().fg(fg_color).add_modifier(modifier),
+                ),
+                Span::styled(
+                    additions_str,
+                    Style::default().fg(theme.file_tree_stats_added),
+                ),
+                Span::styled(
+                    deletions_str,
+                    Style::default().fg(theme.file_tree_stats_deleted),
+                ),
+            ];
+
+            if skipped.contains(&index) {
+                lines.push(Line::from(spans).style(Style::default().add_modifier(Modifier::DIM)));
+            } else {
                 lines.push(Line::from(spans));
             }
         }
@@ -206,3 +179,89 @@ impl FileTreePane {
         (lines, current_line_index)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::{AnimationEngine, AnimationStep};
+    use crate::preview::sample_commit;
+
+    #[test]
+    fn test_lists_files_in_the_animated_order() {
+        let metadata = sample_commit();
+        let theme = Theme::default();
+        let tree_text = |order: FileOrder| {
+            let order = order.file_indices(&metadata);
+            let (lines, _) =
+                FileTreePane::build_tree_lines(&metadata, 0, &order, &HashSet::new(), &theme);
+            lines
+                .iter()
+                .map(|line| line.to_string().trim_end().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            tree_text(FileOrder::Path),
+            [
+                "~ README.md +2 -0",
+                "src/",
+                "  + greeting.rs +1 -0",
+                "  ~ main.rs +3 -1"
+            ]
+        );
+        // The directory with the most changed file comes first
+        assert_eq!(
+            tree_text(FileOrder::Changes),
+            [
+                "src/",
+                "  ~ main.rs +3 -1",
+                "  + greeting.rs +1 -0",
+                "~ README.md +2 -0"
+            ]
+        );
+        // Sizes come from the change, not the loaded content
+        let mut metadata = metadata.clone();
+        for change in &mut metadata.changes {
+            change.size = match change.path.as_str() {
+                "README.md" => 10,
+                "src/main.rs" => 20,
+                _ => 30,
+            };
+        }
+        let order = FileOrder::Size.file_indices(&metadata);
+        let paths: Vec<&str> = order
+            .iter()
+            .map(|&index| metadata.changes[index].path.as_str())
+            .collect();
+        assert_eq!(paths, ["src/greeting.rs", "src/main.rs", "README.md"]);
+
+        let mut engine = AnimationEngine::new(30);
+        engine.set_file_order(FileOrder::Changes);
+        engine.load_commit(&metadata);
+        let opened: Vec<&str> = engine
+            .steps()
+            .iter()
+            .filter_map(|step| match step {
+                AnimationStep::SwitchFile { path, .. } => Some(path.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(opened, ["src/main.rs", "src/greeting.rs", "README.md"]);
+    }
+
+    #[test]
+    fn test_wraps_between_words_not_inside_file_names() {
+        use crate::snapshot::buffer_text;
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let theme = Theme::default();
+        let mut pane = FileTreePane::new();
+        pane.set_commit_metadata(&sample_commit(), 0, FileOrder::Path, None, &theme);
+        let mut terminal = Terminal::new(TestBackend::new(15, 12)).unwrap();
+        terminal.draw(|f| pane.render(f, f.area(), &theme)).unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.contains("greeting.rs"), "{}", text);
+        assert!(text.contains("README.md"), "{}", text);
+    }
+}
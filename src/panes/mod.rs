@@ -1,9 +1,82 @@
+mod commit_message;
 mod editor;
 mod file_tree;
 mod status_bar;
 mod terminal;
 
-pub use editor::EditorPane;
+pub use commit_message::CommitMessagePane;
+pub use editor::{find_matches, EditorPane};
 pub use file_tree::FileTreePane;
-pub use status_bar::StatusBarPane;
-pub use terminal::TerminalPane;
+pub use status_bar::{ClockFormat, Progress, StatusBarPane};
+pub use terminal::{OutputMarkers, TerminalPane};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::{ActivePane, AnimationEngine};
+    use crate::preview::sample_commit;
+    use crate::theme::Theme;
+    use ratatui::{backend::TestBackend, layout::Rect, style::Color, Terminal};
+
+    #[test]
+    fn test_transparent_theme_leaves_editor_and_terminal_unpainted() {
+        let theme = Theme::default().with_transparent_background();
+        let mut engine = AnimationEngine::new(0);
+        engine.set_viewport_height(10);
+        engine.load_commit(&sample_commit());
+        engine.fast_forward();
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+        let editor_area = Rect::new(0, 0, 60, 12);
+        let terminal_area = Rect::new(0, 12, 60, 8);
+        terminal
+            .draw(|f| {
+                EditorPane::default().render(f, editor_area, &engine, &theme);
+                TerminalPane::default().render(f, terminal_area, &engine, &theme);
+            })
+            .unwrap();
+
+        // Only the cursor and changed-line highlights may paint a background
+        let highlights = [
+            Color::Reset,
+            theme.editor_cursor_line_bg,
+            theme.editor_cursor_char_bg,
+            theme.diff_added_bg,
+            theme.diff_modified_bg,
+            theme.terminal_cursor_bg,
+        ];
+        let buffer = terminal.backend().buffer();
+        for cell in buffer.content() {
+            assert!(highlights.contains(&cell.bg), "{:?}", cell);
+        }
+        let editor_cells = editor_area.area() as usize;
+        assert!(buffer.content()[..editor_cells]
+            .iter()
+            .any(|cell| cell.bg == Color::Reset));
+    }
+
+    #[test]
+    fn test_highlights_border_of_active_pane() {
+        let theme = Theme::default();
+        let render = |engine: &AnimationEngine| {
+            let mut terminal = Terminal::new(TestBackend::new(40, 20)).unwrap();
+            terminal
+                .draw(|f| {
+                    EditorPane::default().render(f, Rect::new(0, 0, 40, 12), engine, &theme);
+                    TerminalPane::default().render(f, Rect::new(0, 12, 40, 8), engine, &theme);
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (buffer[(0, 0)].fg, buffer[(0, 12)].fg)
+        };
+
+        let mut engine = AnimationEngine::new(0);
+        engine.active_pane = ActivePane::Terminal;
+        assert_eq!(render(&engine), (theme.editor_border, theme.border_active));
+        engine.active_pane = ActivePane::Editor;
+        assert_eq!(
+            render(&engine),
+            (theme.border_active, theme.terminal_border)
+        );
+    }
+}
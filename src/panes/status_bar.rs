@@ -1,48 +1,120 @@
+use std::time::{Duration, Instant};
+
 use ratatui::{
-    layout::Rect,
-    style::Style,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Padding},
+    widgets::{Block, Padding, Paragraph},
     Frame,
 };
 
-use crate::git::CommitMetadata;
+use chrono::{DateTime, Local, Utc};
+
+use crate::animation::{ActivePane, AnimationEngine};
+use crate::git::{format_relative_date, short_hash, CommitMetadata};
 use crate::theme::Theme;
-use crate::widgets::SelectableParagraph;
+use crate::widgets::{SelectableParagraph, WrapMode};
+use crate::DateFormat;
 
-pub struct StatusBarPane;
+/// How far a playback session has got
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    /// Commit `index` of `total` in the playback list
+    Position { index: usize, total: usize },
+    /// Commits played so far, when the list length is unknown or unbounded
+    Played(usize),
+}
+
+/// How the wall clock is shown with `--clock`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClockFormat {
+    /// 14:05
+    #[default]
+    H24,
+    /// 2:05 PM
+    H12,
+}
+
+#[derive(Default)]
+pub struct StatusBarPane {
+    date_format: DateFormat,
+    // Search input or active query, shown under the pause badge
+    search_prompt: Option<String>,
+    // Wall clock format and session start, when the clock is shown
+    clock: Option<(ClockFormat, Instant)>,
+}
 
 impl StatusBarPane {
+    pub fn set_date_format(&mut self, format: DateFormat) {
+        self.date_format = format;
+    }
+
+    pub fn set_search_prompt(&mut self, prompt: Option<String>) {
+        self.search_prompt = prompt;
+    }
+
+    /// Show the time and how long the session has run, counted from now
+    pub fn set_clock(&mut self, format: Option<ClockFormat>) {
+        self.clock = format.map(|format| (format, Instant::now()));
+    }
+
     pub fn render(
         &self,
         f: &mut Frame,
         area: Rect,
-        metadata: Option<&CommitMetadata>,
+        engine: &AnimationEngine,
+        progress: Option<Progress>,
+        paused: bool,
         theme: &Theme,
     ) {
         let block = Block::default()
             .style(Style::default().bg(theme.background_left))
             .padding(Padding::vertical(1));
 
-        let status_text = if let Some(meta) = metadata {
-            let hash_short = &meta.hash[..7.min(meta.hash.len())];
-            let date_str = meta.date.format("%Y-%m-%d %H:%M:%S").to_string();
+        let mut status_text = if let Some(meta) = engine.current_metadata() {
+            let hash_short = short_hash(&meta.hash);
+            let date_str = self.format_date(meta.date);
+            // Text width inside the horizontal padding
+            let width = area.width.saturating_sub(4) as usize;
 
             let mut lines = vec![
                 Line::from(vec![
                     Span::raw("hash: "),
                     Span::styled(hash_short, Style::default().fg(theme.status_hash)),
+                    Span::raw("  "),
+                    Self::active_pane_tag(&engine.active_pane, theme),
                 ]),
                 Line::from(vec![
                     Span::raw("author: "),
+                    Self::author_badge(&meta.author, theme),
+                    Span::raw(" "),
                     Span::styled(&meta.author, Style::default().fg(theme.status_author)),
                 ]),
                 Line::from(vec![
                     Span::raw("date: "),
                     Span::styled(date_str, Style::default().fg(theme.status_date)),
                 ]),
+                Self::stats_line(meta, width, theme),
             ];
 
+            if let Some(progress) = progress {
+                let (label, value) = match progress {
+                    Progress::Position { index, total } => {
+                        ("commit: ", format!("{}/{}", index, total))
+                    }
+                    Progress::Played(count) => ("played: ", count.to_string()),
+                };
+                lines.insert(
+                    3,
+                    Line::from(vec![
+                        Span::raw(label),
+                        Span::styled(value, Style::default().fg(theme.status_date)),
+                    ]),
+                );
+            }
+
+            lines.extend(Self::position_line(engine, theme));
+
             // Add commit message lines (skip empty lines)
             for msg_line in meta.message.lines() {
                 if !msg_line.trim().is_empty() {
@@ -61,11 +133,340 @@ impl StatusBarPane {
             )])]
         };
 
+        if let Some(prompt) = &self.search_prompt {
+            status_text.insert(
+                0,
+                Line::from(Span::styled(
+                    prompt.clone(),
+                    Style::default().fg(theme.editor_line_number_cursor),
+                )),
+            );
+        }
+
+        if paused {
+            status_text.insert(
+                0,
+                Line::from(vec![Span::styled(
+                    " PAUSED ",
+                    Style::default()
+                        .fg(theme.editor_cursor_char_fg)
+                        .bg(theme.editor_cursor_char_bg)
+                        .add_modifier(Modifier::BOLD),
+                )]),
+            );
+        }
+
         let content = SelectableParagraph::new(status_text)
             .block(block)
             .background_style(Style::default().bg(theme.background_left))
-            .padding(Padding::horizontal(2));
+            .padding(Padding::horizontal(2))
+            .wrap_mode(WrapMode::Word);
 
         f.render_widget(content, area);
+
+        // Right-aligned in the top padding row, clear of the commit details
+        if let Some((format, started)) = self.clock {
+            let width = area.width.saturating_sub(4);
+            let text = clock_text(Local::now(), started.elapsed(), format, width as usize);
+            let clock = Paragraph::new(text)
+                .alignment(Alignment::Right)
+                .style(Style::default().fg(theme.status_no_commit));
+            f.render_widget(
+                clock,
+                Rect::new(area.x + 2, area.y, width, area.height.min(1)),
+            );
+        }
+    }
+
+    fn format_date(&self, date: DateTime<Utc>) -> String {
+        let absolute = || date.format("%Y-%m-%d %H:%M:%S").to_string();
+        let relative = || format_relative_date(date, Utc::now());
+        match self.date_format {
+            DateFormat::Absolute => absolute(),
+            DateFormat::Relative => relative(),
+            DateFormat::Both => format!("{} ({})", absolute(), relative()),
+        }
+    }
+
+    /// Where the ghost is typing, in the color of the active pane's border
+    fn active_pane_tag(active_pane: &ActivePane, theme: &Theme) -> Span<'static> {
+        let label = match active_pane {
+            ActivePane::Editor => "[EDITOR]",
+            ActivePane::Terminal => "[TERMINAL]",
+        };
+        Span::styled(
+            label,
+            Style::default()
+                .fg(theme.border_active)
+                .add_modifier(Modifier::BOLD),
+        )
+    }
+
+    /// The file and line being edited, or the command being run, like an
+    /// editor's status line
+    fn position_line(engine: &AnimationEngine, theme: &Theme) -> Option<Line<'static>> {
+        match engine.active_pane {
+            ActivePane::Editor => {
+                let path = engine.current_file_path.clone()?;
+                let buffer = &engine.buffer;
+                Some(Line::from(vec![
+                    Span::raw("file: "),
+                    Span::styled(path, Style::default().fg(theme.status_hash)),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("{}/{}", buffer.cursor_line + 1, buffer.lines.len().max(1)),
+                        Style::default().fg(theme.status_date),
+                    ),
+                ]))
+            }
+            ActivePane::Terminal => {
+                let command = engine.current_command()?.trim();
+                (!command.is_empty()).then(|| {
+                    Line::from(vec![
+                        Span::raw("cmd: "),
+                        Span::styled(command.to_string(), Style::default().fg(theme.status_hash)),
+                    ])
+                })
+            }
+        }
+    }
+
+    /// Initials on a color picked from the theme's author palette, the same
+    /// for every commit by that author
+    fn author_badge(author: &str, theme: &Theme) -> Span<'static> {
+        let palette = theme.author_palette();
+        let color = palette[author_hash(author) as usize % palette.len()];
+        Span::styled(
+            format!(" {} ", author_initials(author)),
+            Style::default()
+                .fg(theme.background_left)
+                .bg(color)
+                .add_modifier(Modifier::BOLD),
+        )
+    }
+
+    /// `+N -M, K files`, dropping the file count when the pane is too narrow
+    fn stats_line(meta: &CommitMetadata, width: usize, theme: &Theme) -> Line<'static> {
+        let (additions, deletions) = meta.line_counts();
+        let file_count = meta.changes.len();
+
+        let counts = format!("+{} -{}", additions, deletions);
+        let files = format!(
+            ", {} {}",
+            file_count,
+            if file_count == 1 { "file" } else { "files" }
+        );
+
+        let mut spans = vec![
+            Span::styled(
+                format!("+{}", additions),
+                Style::default().fg(theme.file_tree_stats_added),
+            ),
+            Span::raw(" "),
+            Span::styled(
+                format!("-{}", deletions),
+                Style::default().fg(theme.file_tree_stats_deleted),
+            ),
+        ];
+        if counts.len() + files.len() <= width {
+            spans.push(Span::raw(files));
+        }
+        Line::from(spans)
+    }
+}
+
+/// Two uppercase letters for an author: the first letters of the first and
+/// last names, the first two letters of a single name, or the same taken
+/// from the local part of an email address
+fn author_initials(author: &str) -> String {
+    let name = author.split('<').next().unwrap_or_default().trim();
+    let name = if name.is_empty() {
+        author.trim_matches(|c: char| c == '<' || c == '>' || c.is_whitespace())
+    } else {
+        name
+    };
+    let name = match name.split_once('@') {
+        Some((local, _)) if !name.contains(char::is_whitespace) => local,
+        _ => name,
+    };
+
+    let words: Vec<&str> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    let letters: Vec<char> = match words.as_slice() {
+        [] => vec!['?'],
+        [word] => word.chars().take(2).collect(),
+        [first, .., last] => first.chars().take(1).chain(last.chars().take(1)).collect(),
+    };
+    letters.into_iter().flat_map(char::to_uppercase).collect()
+}
+
+/// FNV-1a, so an author keeps the same badge color across runs
+fn author_hash(author: &str) -> u32 {
+    author.trim().bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// `14:05  up 1:02:03`, dropping the session time when it does not fit
+fn clock_text(
+    now: DateTime<Local>,
+    elapsed: Duration,
+    format: ClockFormat,
+    width: usize,
+) -> String {
+    let time = match format {
+        ClockFormat::H24 => now.format("%H:%M").to_string(),
+        ClockFormat::H12 => now.format("%-I:%M %p").to_string(),
+    };
+    let secs = elapsed.as_secs();
+    let text = format!(
+        "{}  up {}:{:02}:{:02}",
+        time,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    );
+    if text.len() <= width {
+        text
+    } else {
+        time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preview::sample_commit;
+    use crate::snapshot::buffer_text;
+    use chrono::TimeZone;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn render_engine(width: u16, engine: &AnimationEngine) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, 16)).unwrap();
+        terminal
+            .draw(|f| {
+                StatusBarPane::default().render(
+                    f,
+                    f.area(),
+                    engine,
+                    Some(Progress::Position {
+                        index: 3,
+                        total: 12,
+                    }),
+                    false,
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+        buffer_text(terminal.backend().buffer())
+    }
+
+    fn played_engine() -> AnimationEngine {
+        let mut engine = AnimationEngine::new(0);
+        engine.load_commit(&sample_commit());
+        engine.fast_forward();
+        engine
+    }
+
+    fn render(width: u16) -> String {
+        render_engine(width, &played_engine())
+    }
+
+    #[test]
+    fn test_shows_active_pane() {
+        let mut engine = played_engine();
+        engine.active_pane = ActivePane::Editor;
+        assert!(render_engine(40, &engine).contains("[EDITOR]"));
+        engine.active_pane = ActivePane::Terminal;
+        assert!(render_engine(40, &engine).contains("[TERMINAL]"));
+
+        let idle = render_engine(40, &AnimationEngine::new(0));
+        assert!(idle.contains("No commit loaded"));
+        assert!(!idle.contains("[EDITOR]"));
+    }
+
+    #[test]
+    fn test_shows_file_position_and_command() {
+        let mut engine = AnimationEngine::new(0);
+        engine.load_commit(&sample_commit());
+        // Run up to the first file opened in the editor
+        while engine.current_file_path.is_none() {
+            engine.advance_frame();
+        }
+        let editing = render_engine(60, &engine);
+        let position = format!(
+            "file: README.md {}/{}",
+            engine.buffer.cursor_line + 1,
+            engine.buffer.lines.len()
+        );
+        assert!(editing.contains(&position), "{}", editing);
+
+        engine.fast_forward();
+        let pushed = render_engine(60, &engine);
+        assert!(pushed.contains("cmd: git push"), "{}", pushed);
+    }
+
+    #[test]
+    fn test_shows_commit_stats() {
+        let meta = sample_commit();
+        let (additions, deletions) = meta.line_counts();
+        let expected = format!(
+            "+{} -{}, {} files",
+            additions,
+            deletions,
+            meta.changes.len()
+        );
+        let wide = render(60);
+        assert!(wide.contains(&expected));
+        assert!(wide.contains("commit: 3/12"));
+
+        // Narrow panes keep the line counts and drop the file count
+        let narrow = render(14);
+        assert!(narrow.contains(&format!("+{} -{}", additions, deletions)));
+        assert!(!narrow.contains("files"));
+    }
+
+    #[test]
+    fn test_derives_author_initials() {
+        assert_eq!(author_initials("Jane Q. Doe"), "JD");
+        assert_eq!(author_initials("linus"), "LI");
+        assert_eq!(author_initials("x"), "X");
+        assert_eq!(author_initials("jane.doe@example.com"), "JD");
+        assert_eq!(author_initials("<bot@ci.example>"), "BO");
+        assert_eq!(author_initials("Jane Doe <jane@example.com>"), "JD");
+        assert_eq!(author_initials("  "), "?");
+        assert_eq!(author_initials("émile zola"), "ÉZ");
+    }
+
+    #[test]
+    fn test_author_badge_color_is_stable() {
+        // Known FNV-1a values, so the color cannot change between runs
+        assert_eq!(author_hash(""), 0x811c_9dc5);
+        assert_eq!(author_hash("a"), 0xe40c_292c);
+        assert_eq!(author_hash("Ghost Developer"), 0xccbd_5692);
+
+        let theme = Theme::default();
+        let badge = StatusBarPane::author_badge("Ghost Developer", &theme);
+        assert_eq!(badge.content, " GD ");
+        assert_eq!(badge.style.bg, Some(theme.status_date));
+        let badge = StatusBarPane::author_badge("Linus Torvalds", &theme);
+        assert_eq!(badge.style.bg, Some(theme.status_hash));
+    }
+
+    #[test]
+    fn test_formats_clock() {
+        let now = Local.with_ymd_and_hms(2024, 6, 15, 14, 5, 0).unwrap();
+        let elapsed = Duration::from_secs(3723);
+        assert_eq!(
+            clock_text(now, elapsed, ClockFormat::H24, 40),
+            "14:05  up 1:02:03"
+        );
+        assert_eq!(
+            clock_text(now, elapsed, ClockFormat::H12, 40),
+            "2:05 PM  up 1:02:03"
+        );
+        assert_eq!(clock_text(now, elapsed, ClockFormat::H24, 10), "14:05");
     }
 }
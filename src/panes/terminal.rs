@@ -2,76 +2,324 @@ use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Padding},
+    widgets::{Block, Borders, Padding},
     Frame,
 };
 
-use crate::animation::{ActivePane, AnimationEngine};
+use crate::animation::{ActivePane, AnimationEngine, TERMINAL_PROMPT};
 use crate::theme::Theme;
 use crate::widgets::SelectableParagraph;
 
-pub struct TerminalPane;
+/// Output words colored as success by default, after trimming punctuation
+pub const DEFAULT_SUCCESS_MARKERS: &[&str] = &["SUCCESS", "done"];
+/// Output words colored as failure by default
+pub const DEFAULT_ERROR_MARKERS: &[&str] = &["error", "fatal", "failed", "ERROR", "FAILED"];
+
+/// Parts of terminal output picked out in color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputToken {
+    Success,
+    Error,
+    Hash,
+    Path,
+}
+
+impl OutputToken {
+    fn classify(word: &str, markers: &OutputMarkers) -> Option<Self> {
+        if markers.success.iter().any(|marker| marker == word) {
+            Some(Self::Success)
+        } else if markers.error.iter().any(|marker| marker == word) {
+            Some(Self::Error)
+        } else if is_hash(word) {
+            Some(Self::Hash)
+        } else if is_path(word) {
+            Some(Self::Path)
+        } else {
+            None
+        }
+    }
+
+    fn style(self, theme: &Theme) -> Style {
+        let style = Style::default();
+        match self {
+            Self::Success => style
+                .fg(theme.terminal_success_color())
+                .add_modifier(Modifier::BOLD),
+            Self::Error => style
+                .fg(theme.terminal_error_color())
+                .add_modifier(Modifier::BOLD),
+            Self::Hash => style.fg(theme.terminal_hash_color()),
+            Self::Path => style.fg(theme.terminal_path_color()),
+        }
+    }
+}
+
+/// Words in the output that mark success or failure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputMarkers {
+    pub success: Vec<String>,
+    pub error: Vec<String>,
+}
+
+impl Default for OutputMarkers {
+    fn default() -> Self {
+        let owned = |markers: &[&str]| markers.iter().map(|m| m.to_string()).collect();
+        Self {
+            success: owned(DEFAULT_SUCCESS_MARKERS),
+            error: owned(DEFAULT_ERROR_MARKERS),
+        }
+    }
+}
+
+/// An abbreviated or full commit hash, or a range of two such as
+/// `abc1234..def5678`. A hash needs a letter, so plain numbers such as
+/// timestamps are left alone.
+fn is_hash(word: &str) -> bool {
+    word.split("..").all(|part| {
+        (7..=40).contains(&part.len())
+            && part.chars().all(|c| c.is_ascii_hexdigit())
+            && part.chars().any(|c| c.is_ascii_alphabetic())
+    })
+}
+
+/// `src/main.rs`, `Cargo.lock` or `origin/main`, but not `5/5` or an email address
+fn is_path(word: &str) -> bool {
+    if word.contains("..") || word.contains('@') || !word.chars().any(char::is_alphabetic) {
+        return false;
+    }
+    word.contains('/')
+        || word.rsplit_once('.').is_some_and(|(stem, extension)| {
+            !stem.is_empty()
+                && extension.starts_with(|c: char| c.is_ascii_alphabetic())
+                && extension.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+#[derive(Default)]
+pub struct TerminalPane {
+    markers: OutputMarkers,
+}
 
 impl TerminalPane {
+    pub fn set_markers(&mut self, markers: OutputMarkers) {
+        self.markers = markers;
+    }
+
     pub fn render(&self, f: &mut Frame, area: Rect, engine: &AnimationEngine, theme: &Theme) {
+        let border = if engine.active_pane == ActivePane::Terminal {
+            theme.border_active
+        } else {
+            theme.terminal_border
+        };
         let block = Block::default()
+            .borders(Borders::LEFT)
+            .border_style(Style::default().fg(border))
             .style(Style::default().bg(theme.background_right))
             .padding(Padding::vertical(1));
 
         // Get visible lines based on area height (subtract padding)
         let content_height = area.height.saturating_sub(2) as usize; // Subtract top and bottom padding
+        let lines = self.build_lines(engine, content_height, theme);
+
+        let content = SelectableParagraph::new(lines)
+            .block(block)
+            .background_style(Style::default().bg(theme.background_right))
+            .padding(Padding::new(1, 2, 0, 0));
+        f.render_widget(content, area);
+    }
+
+    fn build_lines(
+        &self,
+        engine: &AnimationEngine,
+        content_height: usize,
+        theme: &Theme,
+    ) -> Vec<Line<'static>> {
         let total_lines = engine.terminal_lines.len();
+        if total_lines == 0 {
+            return vec![Line::from("")];
+        }
 
-        let lines: Vec<Line> = if total_lines > 0 {
-            let start_idx = total_lines.saturating_sub(content_height);
-            engine.terminal_lines[start_idx..]
-                .iter()
-                .enumerate()
-                .map(|(idx, line)| {
-                    let is_last_line = start_idx + idx == total_lines - 1;
-                    let show_cursor = is_last_line
-                        && engine.cursor_visible
-                        && engine.active_pane == ActivePane::Terminal;
-
-                    if line.starts_with("~ ") {
-                        // Command line
-                        if show_cursor {
-                            // Add cursor at the end of the line
-                            let mut spans = vec![Span::styled(
-                                line.clone(),
-                                Style::default().fg(theme.terminal_command),
-                            )];
-                            spans.push(Span::styled(
-                                " ",
-                                Style::default()
-                                    .bg(theme.terminal_cursor_bg)
-                                    .fg(theme.terminal_cursor_fg)
-                                    .add_modifier(Modifier::BOLD),
-                            ));
-                            Line::from(spans)
-                        } else {
-                            Line::from(vec![Span::styled(
-                                line.clone(),
-                                Style::default().fg(theme.terminal_command),
-                            )])
-                        }
-                    } else {
-                        // Output line - normal style
-                        Line::from(vec![Span::styled(
-                            line.clone(),
-                            Style::default().fg(theme.terminal_output),
-                        )])
+        let command_style = Style::default()
+            .fg(theme.terminal_command)
+            .add_modifier(Modifier::BOLD);
+
+        let start_idx = total_lines.saturating_sub(content_height);
+        engine.terminal_lines[start_idx..]
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| {
+                let is_last_line = start_idx + idx == total_lines - 1;
+                let show_cursor = is_last_line
+                    && engine.cursor_visible
+                    && engine.active_pane == ActivePane::Terminal;
+
+                if let Some(command) = line.strip_prefix(TERMINAL_PROMPT) {
+                    // Command line, with the program name picked out
+                    let (program, args) = command
+                        .split_at(command.find(char::is_whitespace).unwrap_or(command.len()));
+                    let mut spans = vec![
+                        Span::styled(TERMINAL_PROMPT, command_style),
+                        Span::styled(
+                            program.to_string(),
+                            command_style.fg(theme.terminal_program_color()),
+                        ),
+                        Span::styled(args.to_string(), command_style),
+                    ];
+                    if show_cursor {
+                        // Add cursor at the end of the line
+                        spans.push(Span::styled(
+                            " ",
+                            Style::default()
+                                .bg(theme.terminal_cursor_bg)
+                                .fg(theme.terminal_cursor_fg)
+                                .add_modifier(Modifier::BOLD),
+                        ));
                     }
+                    Line::from(spans)
+                } else {
+                    Line::from(self.output_spans(line, theme))
+                }
+            })
+            .collect()
+    }
+
+    /// An output line in the output color, with hashes, paths and success
+    /// or error markers picked out
+    fn output_spans(&self, line: &str, theme: &Theme) -> Vec<Span<'static>> {
+        let plain = Style::default().fg(theme.terminal_output);
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        let mut push = |text: &str, style: Style| {
+            if text.is_empty() {
+                return;
+            }
+            match spans.last_mut() {
+                Some(last) if last.style == style => last.content.to_mut().push_str(text),
+                _ => spans.push(Span::styled(text.to_string(), style)),
+            }
+        };
+
+        for piece in line.split_inclusive(char::is_whitespace) {
+            let leading = |c: char| matches!(c, '[' | '(' | '"' | '\'');
+            let trailing = |c: char| {
+                c.is_whitespace() || matches!(c, ']' | ')' | '"' | '\'' | ',' | ':' | ';' | '.')
+            };
+            let rest = piece.trim_start_matches(leading);
+            let word = rest.trim_end_matches(trailing);
+            let start = piece.len() - rest.len();
+            let end = start + word.len();
+
+            match OutputToken::classify(word, &self.markers) {
+                Some(token) => {
+                    push(&piece[..start], plain);
+                    push(word, token.style(theme));
+                    push(&piece[end..], plain);
+                }
+                None => push(piece, plain),
+            }
+        }
+        if spans.is_empty() {
+            spans.push(Span::styled(String::new(), plain));
+        }
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::AnimationStep;
+
+    #[test]
+    fn test_typed_command_uses_command_style() {
+        let theme = Theme::default();
+        let mut engine = AnimationEngine::new(30);
+        engine.execute_step(AnimationStep::TerminalPrompt);
+        for ch in "git status".chars() {
+            engine.execute_step(AnimationStep::TerminalTypeChar { ch });
+        }
+        engine.execute_step(AnimationStep::TerminalOutput {
+            text: "nothing to commit".to_string(),
+        });
+
+        let lines = TerminalPane::default().build_lines(&engine, 10, &theme);
+        assert_eq!(lines.len(), 2);
+
+        assert_eq!(lines[0].to_string(), "~ git status");
+        let program = &lines[0].spans[1];
+        assert_eq!(program.content, "git");
+        assert_eq!(program.style.fg, Some(theme.terminal_program_color()));
+        let command = &lines[0].spans[2];
+        assert_eq!(command.content, " status");
+        assert_eq!(command.style.fg, Some(theme.terminal_command));
+        assert!(command.style.add_modifier.contains(Modifier::BOLD));
+
+        let output = &lines[1].spans[0];
+        assert_eq!(output.style.fg, Some(theme.terminal_output));
+        assert!(!output.style.add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_picks_out_hashes_paths_and_markers() {
+        let theme = Theme::default();
+        let mut pane = TerminalPane::default();
+        let colored = |pane: &TerminalPane, line: &str| -> Vec<(String, OutputToken)> {
+            let spans = pane.output_spans(line, &theme);
+            assert_eq!(Line::from(spans.clone()).to_string(), line);
+            spans
+                .iter()
+                .filter_map(|span| {
+                    let token = [
+                        OutputToken::Success,
+                        OutputToken::Error,
+                        OutputToken::Hash,
+                        OutputToken::Path,
+                    ]
+                    .into_iter()
+                    .find(|token| token.style(&theme) == span.style)?;
+                    Some((span.content.to_string(), token))
                 })
                 .collect()
-        } else {
-            vec![Line::from("")]
         };
 
-        let content = SelectableParagraph::new(lines)
-            .block(block)
-            .background_style(Style::default().bg(theme.background_right))
-            .padding(Padding::horizontal(2));
-        f.render_widget(content, area);
+        assert_eq!(
+            colored(&pane, "   deadbee..c0ffee1 * SUCCESS"),
+            [
+                ("deadbee..c0ffee1".to_string(), OutputToken::Hash),
+                ("SUCCESS".to_string(), OutputToken::Success),
+            ]
+        );
+        assert_eq!(
+            colored(&pane, "[main c0ffee1] Greet the user"),
+            [("c0ffee1".to_string(), OutputToken::Hash)]
+        );
+        assert_eq!(
+            colored(&pane, "Counting objects: 100% (5/5), done."),
+            [("done".to_string(), OutputToken::Success)]
+        );
+        assert_eq!(
+            colored(&pane, "skipping Cargo.lock (lock file)"),
+            [("Cargo.lock".to_string(), OutputToken::Path)]
+        );
+        assert_eq!(
+            colored(&pane, "fatal: src/main.rs not found"),
+            [
+                ("fatal".to_string(), OutputToken::Error),
+                ("src/main.rs".to_string(), OutputToken::Path),
+            ]
+        );
+        assert!(colored(&pane, "Author: Jane <jane@example.com>").is_empty());
+        assert!(colored(&pane, "Warping through spacetime...").is_empty());
+        // Plain numbers are not hashes
+        assert!(colored(&pane, "Elapsed 1700000000 ms").is_empty());
+
+        pane.set_markers(OutputMarkers {
+            success: vec!["ok".to_string()],
+            error: Vec::new(),
+        });
+        assert_eq!(
+            colored(&pane, "test result: ok. 3 passed"),
+            [("ok".to_string(), OutputToken::Success)]
+        );
+        assert!(colored(&pane, "fatal: not a repository").is_empty());
     }
 }
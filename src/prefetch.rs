@@ -0,0 +1,107 @@
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use anyhow::{anyhow, Result};
+
+use crate::git::{CommitMetadata, GitRepository};
+use crate::PlaybackOrder;
+
+/// Where a commit falls in the playback list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// 1-based
+    pub index: usize,
+    pub total: usize,
+}
+
+/// How the next commit to play is chosen
+#[derive(Debug, Clone)]
+pub struct CommitSelection {
+    pub order: PlaybackOrder,
+    pub loop_playback: bool,
+    pub commit_spec: Option<String>,
+    pub is_range_mode: bool,
+}
+
+impl CommitSelection {
+    /// Fetch the next commit according to playback mode and order
+    pub fn next_commit(&self, repo: &GitRepository) -> Result<CommitMetadata> {
+        if self.is_range_mode {
+            match self.order {
+                PlaybackOrder::Random => repo.random_range_commit(),
+                PlaybackOrder::Asc => repo.next_range_commit_asc(),
+                PlaybackOrder::Desc => repo.next_range_commit_desc(),
+            }
+        } else if let Some(commit_spec) = &self.commit_spec {
+            repo.get_commit(commit_spec)
+        } else {
+            match self.order {
+                PlaybackOrder::Random => repo.random_commit(),
+                PlaybackOrder::Asc => repo.next_asc_commit(),
+                PlaybackOrder::Desc => repo.next_desc_commit(),
+            }
+        }
+    }
+
+    /// Position of the commit `next_commit` returned last. Only sequential
+    /// playback has one; random order and single commits do not.
+    pub fn position(&self, repo: &GitRepository) -> Option<Position> {
+        let sequential = self.is_range_mode || self.commit_spec.is_none();
+        if !sequential || matches!(self.order, PlaybackOrder::Random) {
+            return None;
+        }
+        repo.total_count().map(|total| Position {
+            index: repo.current_index(),
+            total,
+        })
+    }
+
+    /// Like `next_commit`, but starts over from the beginning when looping
+    /// and every commit has been played. Any other error still ends playback.
+    fn next_commit_looping(&self, repo: &GitRepository) -> Result<CommitMetadata> {
+        match self.next_commit(repo) {
+            Err(_) if self.loop_playback && repo.is_exhausted() => {
+                repo.reset_index();
+                self.next_commit(repo)
+            }
+            result => result,
+        }
+    }
+}
+
+/// Extracts upcoming commits on a worker thread, one commit ahead of
+/// playback, so reading blobs and computing diffs does not stall the UI
+/// between commits.
+pub struct CommitPrefetcher {
+    receiver: Receiver<Result<(CommitMetadata, Option<Position>)>>,
+}
+
+impl CommitPrefetcher {
+    /// Move `repo` onto a worker thread and start extracting the commit
+    /// that follows the one currently playing
+    pub fn spawn(repo: GitRepository, selection: CommitSelection) -> Self {
+        // Rendezvous channel: the worker prepares one commit, then waits
+        // until it is taken before starting on the next
+        let (sender, receiver) = mpsc::sync_channel(0);
+
+        thread::spawn(move || loop {
+            let result = selection
+                .next_commit_looping(&repo)
+                .map(|metadata| (metadata, selection.position(&repo)));
+            let finished = result.is_err();
+            if sender.send(result).is_err() || finished {
+                break;
+            }
+        });
+
+        Self { receiver }
+    }
+
+    /// The next commit and its position, waiting for the worker if it is
+    /// still extracting it
+    pub fn next(&self) -> Result<(CommitMetadata, Option<Position>)> {
+        self.receiver
+            .recv()
+            .unwrap_or_else(|_| Err(anyhow!("Commit prefetch worker stopped")))
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::DateTime;
+
+use crate::git::{CommitMetadata, DiffHunk, FileChange, FileStatus, LineChange, LineChangeType};
+
+/// Build a small synthetic commit used by `theme preview`
+pub fn sample_commit() -> CommitMetadata {
+    let old_main = "use std::env;\n\nfn main() {\n    let args: Vec<String> = env::args().collect();\n    println!(\"Hello, world!\");\n}\n";
+    let new_main = "use std::env;\n\n/// Greet the user by name\nfn main() {\n    let args: Vec<String> = env::args().collect();\n    let name = args.get(1).map(String::as_str).unwrap_or(\"world\");\n    println!(\"Hello, {}!\", name);\n}\n";
+    let main_hunk = hunk(
+        1,
+        &[
+            (LineChangeType::Context, "use std::env;\n"),
+            (LineChangeType::Context, "\n"),
+            (LineChangeType::Addition, "/// Greet the user by name\n"),
+            (LineChangeType::Context, "fn main() {\n"),
+            (
+                LineChangeType::Context,
+                "    let args: Vec<String> = env::args().collect();\n",
+            ),
+            (
+                LineChangeType::Deletion,
+                "    println!(\"Hello, world!\");\n",
+            ),
+            (
+                LineChangeType::Addition,
+                "    let name = args.get(1).map(String::as_str).unwrap_or(\"world\");\n",
+            ),
+            (
+                LineChangeType::Addition,
+                "    println!(\"Hello, {}!\", name);\n",
+            ),
+            (LineChangeType::Context, "}\n"),
+        ],
+    );
+
+    let new_greeting = "pub const DEFAULT_NAME: &str = \"world\";\n";
+    let greeting_hunk = hunk(
+        0,
+        &[(
+            LineChangeType::Addition,
+            "pub const DEFAULT_NAME: &str = \"world\";\n",
+        )],
+    );
+
+    let old_readme = "# hello\n";
+    let new_readme = "# hello\n\nRun `hello <name>` to be greeted.\n";
+    let readme_hunk = hunk(
+        1,
+        &[
+            (LineChangeType::Context, "# hello\n"),
+            (LineChangeType::Addition, "\n"),
+            (
+                LineChangeType::Addition,
+                "Run `hello <name>` to be greeted.\n",
+            ),
+        ],
+    );
+
+    CommitMetadata {
+        hash: "c0ffee1234567890abcdef1234567890abcdef12".to_string(),
+        author: "Ghost Developer".to_string(),
+        date: DateTime::from_timestamp(1_700_000_000, 0).unwrap_or_default(),
+        message: "Greet the user by name\n\nAccept an optional name argument.".to_string(),
+        parent_hash: Some("deadbeef1234567890abcdef1234567890abcdef".to_string()),
+        changes: vec![
+            file_change(
+                "README.md",
+                FileStatus::Modified,
+                Some(old_readme),
+                new_readme,
+                readme_hunk,
+            ),
+            file_change(
+                "src/greeting.rs",
+                FileStatus::Added,
+                None,
+                new_greeting,
+                greeting_hunk,
+            ),
+            file_change(
+                "src/main.rs",
+                FileStatus::Modified,
+                Some(old_main),
+                new_main,
+                main_hunk,
+            ),
+        ],
+    }
+}
+
+fn hunk(old_start: usize, lines: &[(LineChangeType, &str)]) -> DiffHunk {
+    let old_lines = lines
+        .iter()
+        .filter(|(t, _)| !matches!(t, LineChangeType::Addition))
+        .count();
+    let new_lines = lines
+        .iter()
+        .filter(|(t, _)| !matches!(t, LineChangeType::Deletion))
+        .count();
+
+    DiffHunk {
+        old_start,
+        old_lines,
+        new_start: old_start.max(1),
+        new_lines,
+        lines: lines
+            .iter()
+            .map(|(change_type, content)| LineChange {
+                change_type: change_type.clone(),
+                content: content.to_string(),
+                old_line_no: None,
+                new_line_no: None,
+            })
+            .collect(),
+    }
+}
+
+fn file_change(
+    path: &str,
+    status: FileStatus,
+    old_content: Option<&str>,
+    new_content: &str,
+    hunk: DiffHunk,
+) -> FileChange {
+    FileChange {
+        path: path.to_string(),
+        old_path: None,
+        status,
+        is_binary: false,
+        size: new_content.len(),
+        is_excluded: false,
+        exclusion_reason: None,
+        old_content: old_content.map(String::from),
+        new_content: Some(new_content.to_string()),
+        hunks: vec![hunk],
+        diff: String::new(),
+    }
+}
@@ -0,0 +1,77 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config::Config;
+
+/// Where sequential playback stopped, saved on exit so `--resume` can pick
+/// up from the same commit in the next session
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaybackState {
+    /// Repository root
+    pub repo: PathBuf,
+    pub order: String,
+    /// Commits, ranges or playlist as given on the command line
+    #[serde(default)]
+    pub commits: Vec<String>,
+    /// Where history is walked from: a ref name, `--all` or `HEAD`
+    pub start: String,
+    /// Commits played before the one that was showing
+    #[serde(default)]
+    pub commit_index: usize,
+}
+
+impl PlaybackState {
+    /// The state saved by the last session, or None when there is none or
+    /// it cannot be read
+    pub fn load() -> Option<Self> {
+        let contents = fs::read_to_string(Self::path().ok()?).ok()?;
+        toml::from_str(&contents).ok()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        let contents = toml::to_string(self).context("Failed to serialize playback state")?;
+        fs::write(&path, contents)
+            .with_context(|| format!("Failed to write state file: {}", path.display()))
+    }
+
+    /// Whether `other` plays the same list of commits, so its index applies
+    pub fn same_playlist(&self, other: &Self) -> bool {
+        self.repo == other.repo
+            && self.order == other.order
+            && self.commits == other.commits
+            && self.start == other.start
+    }
+
+    fn path() -> Result<PathBuf> {
+        Ok(Config::config_path()?.with_file_name("state.toml"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips_and_matches_playlists() {
+        let state = PlaybackState {
+            repo: PathBuf::from("/src/project"),
+            order: "asc".to_string(),
+            commits: vec!["v1.0..v2.0".to_string()],
+            start: "HEAD".to_string(),
+            commit_index: 12,
+        };
+        let parsed: PlaybackState = toml::from_str(&toml::to_string(&state).unwrap()).unwrap();
+        assert_eq!(parsed, state);
+
+        let mut other = PlaybackState {
+            commit_index: 0,
+            ..state.clone()
+        };
+        assert!(state.same_playlist(&other));
+        other.order = "desc".to_string();
+        assert!(!state.same_playlist(&other));
+    }
+}
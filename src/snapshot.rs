@@ -0,0 +1,44 @@
+use ratatui::buffer::Buffer;
+use unicode_width::UnicodeWidthStr;
+
+/// Render a buffer as plain text, one line per row with trailing blanks
+/// trimmed. Styles are dropped.
+pub fn buffer_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut text = String::new();
+
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        // Cells hidden behind a wide character hold a placeholder symbol
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let symbol = buffer[(x, y)].symbol();
+            line.push_str(symbol);
+            skip = symbol.width().saturating_sub(1);
+        }
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn test_trims_rows_and_skips_wide_char_placeholders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        buffer.set_string(0, 0, "a漢b", Style::default());
+        buffer.set_string(2, 1, "x", Style::default());
+
+        assert_eq!(buffer_text(&buffer), "a漢b\n  x\n");
+    }
+}
@@ -0,0 +1,5 @@
+pub fn language() -> tree_sitter::Language {
+    tree_sitter_graphql::LANGUAGE.into()
+}
+
+pub const HIGHLIGHT_QUERY: &str = include_str!("queries/graphql_highlights.scm");
@@ -8,6 +8,7 @@ pub mod dart;
 pub mod elixir;
 pub mod erlang;
 pub mod go_lang;
+pub mod graphql;
 pub mod haskell;
 pub mod html;
 pub mod java;
@@ -17,11 +18,14 @@ pub mod kotlin;
 pub mod lua;
 pub mod markdown;
 pub mod php;
+pub mod protobuf;
 pub mod python;
 pub mod ruby;
 pub mod rust;
 pub mod scala;
+pub mod sql;
 pub mod swift;
+pub mod toml;
 pub mod typescript;
 pub mod xml;
 pub mod yaml;
@@ -31,6 +35,15 @@ use std::path::Path;
 use tree_sitter::Language;
 
 pub fn get_language(path: &Path) -> Option<(Language, &'static str)> {
+    // Well-known file names take precedence over the extension
+    if let Some(language) = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(get_language_by_name)
+    {
+        return Some(language);
+    }
+
     let extension = path.extension()?.to_str()?;
 
     match extension {
@@ -46,6 +59,7 @@ pub fn get_language(path: &Path) -> Option<(Language, &'static str)> {
         "ex" | "exs" => Some((elixir::language(), elixir::HIGHLIGHT_QUERY)),
         "erl" | "hrl" | "es" | "escript" => Some((erlang::language(), erlang::HIGHLIGHT_QUERY)),
         "go" => Some((go_lang::language(), go_lang::HIGHLIGHT_QUERY)),
+        "graphql" | "gql" => Some((graphql::language(), graphql::HIGHLIGHT_QUERY)),
         "hs" | "lhs" => Some((haskell::language(), haskell::HIGHLIGHT_QUERY)),
         "html" | "htm" => Some((html::language(), html::HIGHLIGHT_QUERY)),
         "java" => Some((java::language(), java::HIGHLIGHT_QUERY)),
@@ -55,11 +69,14 @@ pub fn get_language(path: &Path) -> Option<(Language, &'static str)> {
         "lua" => Some((lua::language(), lua::HIGHLIGHT_QUERY)),
         "md" | "markdown" => Some((markdown::language(), markdown::HIGHLIGHT_QUERY)),
         "php" | "php3" | "php4" | "php5" | "phtml" => Some((php::language(), php::HIGHLIGHT_QUERY)),
+        "proto" => Some((protobuf::language(), protobuf::HIGHLIGHT_QUERY)),
         "py" | "pyw" => Some((python::language(), python::HIGHLIGHT_QUERY)),
         "rb" | "rbw" | "rake" | "gemspec" => Some((ruby::language(), ruby::HIGHLIGHT_QUERY)),
         "rs" => Some((rust::language(), rust::HIGHLIGHT_QUERY)),
         "scala" | "sc" | "sbt" => Some((scala::language(), scala::HIGHLIGHT_QUERY)),
+        "sql" => Some((sql::language(), sql::HIGHLIGHT_QUERY)),
         "swift" => Some((swift::language(), swift::HIGHLIGHT_QUERY)),
+        "toml" => Some((toml::language(), toml::HIGHLIGHT_QUERY)),
         "ts" | "tsx" | "mts" | "cts" => Some((typescript::language(), typescript::HIGHLIGHT_QUERY)),
         "xml" | "svg" | "xsl" | "xslt" => Some((xml::language(), xml::HIGHLIGHT_QUERY)),
         "yaml" | "yml" => Some((yaml::language(), yaml::HIGHLIGHT_QUERY)),
@@ -67,3 +84,283 @@ pub fn get_language(path: &Path) -> Option<(Language, &'static str)> {
         _ => None,
     }
 }
+
+/// Tokens that open and close a one-line comment in the language of `path`,
+/// falling back to the `#!` line or modeline of `source`. The closing token
+/// is empty for languages with line comments; JSON has no comments at all
+pub fn comment_delimiters(path: &Path, source: &str) -> Option<(&'static str, &'static str)> {
+    let (_, query) = get_language(path).or_else(|| get_language_from_content(source))?;
+    let languages = [
+        (bash::HIGHLIGHT_QUERY, ("#", "")),
+        (c::HIGHLIGHT_QUERY, ("//", "")),
+        (clojure::HIGHLIGHT_QUERY, (";;", "")),
+        (cpp::HIGHLIGHT_QUERY, ("//", "")),
+        (csharp::HIGHLIGHT_QUERY, ("//", "")),
+        (css::HIGHLIGHT_QUERY, ("/*", "*/")),
+        (dart::HIGHLIGHT_QUERY, ("//", "")),
+        (elixir::HIGHLIGHT_QUERY, ("#", "")),
+        (erlang::HIGHLIGHT_QUERY, ("%", "")),
+        (go_lang::HIGHLIGHT_QUERY, ("//", "")),
+        (graphql::HIGHLIGHT_QUERY, ("#", "")),
+        (haskell::HIGHLIGHT_QUERY, ("--", "")),
+        (html::HIGHLIGHT_QUERY, ("<!--", "-->")),
+        (java::HIGHLIGHT_QUERY, ("//", "")),
+        (javascript::HIGHLIGHT_QUERY, ("//", "")),
+        (kotlin::HIGHLIGHT_QUERY, ("//", "")),
+        (lua::HIGHLIGHT_QUERY, ("--", "")),
+        (markdown::HIGHLIGHT_QUERY, ("<!--", "-->")),
+        (php::HIGHLIGHT_QUERY, ("//", "")),
+        (protobuf::HIGHLIGHT_QUERY, ("//", "")),
+        (python::HIGHLIGHT_QUERY, ("#", "")),
+        (ruby::HIGHLIGHT_QUERY, ("#", "")),
+        (rust::HIGHLIGHT_QUERY, ("//", "")),
+        (scala::HIGHLIGHT_QUERY, ("//", "")),
+        (sql::HIGHLIGHT_QUERY, ("--", "")),
+        (swift::HIGHLIGHT_QUERY, ("//", "")),
+        (toml::HIGHLIGHT_QUERY, ("#", "")),
+        (typescript::HIGHLIGHT_QUERY, ("//", "")),
+        (xml::HIGHLIGHT_QUERY, ("<!--", "-->")),
+        (yaml::HIGHLIGHT_QUERY, ("#", "")),
+        (zig::HIGHLIGHT_QUERY, ("//", "")),
+    ];
+    languages
+        .into_iter()
+        .find(|(language_query, _)| *language_query == query)
+        .map(|(_, delimiters)| delimiters)
+}
+
+/// Match well-known file names, including extensionless files and dotfiles
+fn get_language_by_name(file_name: &str) -> Option<(Language, &'static str)> {
+    match file_name {
+        ".bashrc" | ".bash_profile" | ".bash_aliases" | ".bash_logout" | ".profile" | ".zshrc"
+        | ".zshenv" | ".zprofile" | ".envrc" | "PKGBUILD" | "APKBUILD" => {
+            Some((bash::language(), bash::HIGHLIGHT_QUERY))
+        }
+        "Rakefile" | "Gemfile" | "Guardfile" | "Vagrantfile" | "Podfile" | "Brewfile"
+        | "Fastfile" | ".irbrc" => Some((ruby::language(), ruby::HIGHLIGHT_QUERY)),
+        "Cargo.toml" | "Cargo.lock" | "Pipfile" | "poetry.lock" | "uv.lock" => {
+            Some((toml::language(), toml::HIGHLIGHT_QUERY))
+        }
+        ".babelrc" | ".eslintrc" | ".prettierrc" | ".swcrc" => {
+            Some((json::language(), json::HIGHLIGHT_QUERY))
+        }
+        ".clang-format" | ".clang-tidy" | ".clangd" => {
+            Some((yaml::language(), yaml::HIGHLIGHT_QUERY))
+        }
+        _ => None,
+    }
+}
+
+/// Detect a language from a `#!` interpreter line, e.g. `#!/bin/bash` or
+/// `#!/usr/bin/env python3`
+pub fn get_language_from_shebang(source: &str) -> Option<(Language, &'static str)> {
+    let line = source.lines().next()?.strip_prefix("#!")?;
+    let mut words = line.split_whitespace();
+    let mut interpreter = words.next()?.rsplit('/').next()?;
+
+    // `#!/usr/bin/env [-S] <interpreter>`
+    if interpreter == "env" {
+        interpreter = words.find(|word| !word.starts_with('-'))?;
+    }
+
+    // Strip version suffixes such as python3 or python3.12
+    let name = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+
+    match name {
+        "sh" | "bash" | "zsh" | "dash" | "ksh" => Some((bash::language(), bash::HIGHLIGHT_QUERY)),
+        "python" => Some((python::language(), python::HIGHLIGHT_QUERY)),
+        "ruby" => Some((ruby::language(), ruby::HIGHLIGHT_QUERY)),
+        "node" => Some((javascript::language(), javascript::HIGHLIGHT_QUERY)),
+        "lua" => Some((lua::language(), lua::HIGHLIGHT_QUERY)),
+        "php" => Some((php::language(), php::HIGHLIGHT_QUERY)),
+        "escript" => Some((erlang::language(), erlang::HIGHLIGHT_QUERY)),
+        "elixir" => Some((elixir::language(), elixir::HIGHLIGHT_QUERY)),
+        _ => None,
+    }
+}
+
+/// Detect a language from the text of a file whose name does not give it
+/// away: its `#!` line, or failing that its modeline
+pub fn get_language_from_content(source: &str) -> Option<(Language, &'static str)> {
+    get_language_from_shebang(source).or_else(|| get_language_from_modeline(source))
+}
+
+/// Detect a language from an Emacs mode line (`# -*- mode: ruby -*-`) in
+/// the first two lines, or a Vim modeline (`# vim: set ft=python:`) in the
+/// first or last five, where the editors themselves look for them
+pub fn get_language_from_modeline(source: &str) -> Option<(Language, &'static str)> {
+    let emacs = source.lines().take(2).find_map(emacs_mode);
+    let filetype = emacs.or_else(|| {
+        source
+            .lines()
+            .take(5)
+            .chain(source.lines().rev().take(5))
+            .find_map(vim_filetype)
+    })?;
+    get_language_by_filetype(filetype)
+}
+
+/// `python` from `-*- mode: python; coding: utf-8 -*-` or `-*- python -*-`
+fn emacs_mode(line: &str) -> Option<&str> {
+    let (_, rest) = line.split_once("-*-")?;
+    let (variables, _) = rest.split_once("-*-")?;
+    let variables = variables.trim();
+    if !variables.contains(':') {
+        return Some(variables).filter(|mode| !mode.is_empty());
+    }
+    variables.split(';').find_map(|variable| {
+        let (name, value) = variable.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case("mode")
+            .then_some(value.trim())
+    })
+}
+
+/// `python` from `vim: set ft=python:` or `vi: filetype=python ts=4`
+fn vim_filetype(line: &str) -> Option<&str> {
+    let options = ["vim:", "vi:", "ex:"].iter().find_map(|marker| {
+        line.match_indices(marker)
+            .find(|(index, _)| {
+                line[..*index]
+                    .chars()
+                    .next_back()
+                    .is_none_or(char::is_whitespace)
+            })
+            .map(|(index, _)| &line[index + marker.len()..])
+    })?;
+    options
+        .split(|c: char| c.is_whitespace() || c == ':')
+        .find_map(|option| match option.split_once('=')? {
+            ("ft" | "filetype" | "syntax" | "syn", value) => Some(value),
+            _ => None,
+        })
+}
+
+/// Match an editor's name for a language, such as Vim's `filetype` or an
+/// Emacs major mode, falling back to treating it as a file extension
+fn get_language_by_filetype(filetype: &str) -> Option<(Language, &'static str)> {
+    let filetype = filetype.to_ascii_lowercase();
+    let name = filetype.trim_end_matches("-mode").trim_end_matches("-ts");
+    match name {
+        "shell" | "shell-script" => Some((bash::language(), bash::HIGHLIGHT_QUERY)),
+        "c++" => Some((cpp::language(), cpp::HIGHLIGHT_QUERY)),
+        "clojure" => Some((clojure::language(), clojure::HIGHLIGHT_QUERY)),
+        "csharp" => Some((csharp::language(), csharp::HIGHLIGHT_QUERY)),
+        "elixir" => Some((elixir::language(), elixir::HIGHLIGHT_QUERY)),
+        "erlang" => Some((erlang::language(), erlang::HIGHLIGHT_QUERY)),
+        "haskell" => Some((haskell::language(), haskell::HIGHLIGHT_QUERY)),
+        "javascript" | "js2" => Some((javascript::language(), javascript::HIGHLIGHT_QUERY)),
+        "kotlin" => Some((kotlin::language(), kotlin::HIGHLIGHT_QUERY)),
+        "python" => Some((python::language(), python::HIGHLIGHT_QUERY)),
+        "ruby" => Some((ruby::language(), ruby::HIGHLIGHT_QUERY)),
+        "rust" => Some((rust::language(), rust::HIGHLIGHT_QUERY)),
+        "typescript" => Some((typescript::language(), typescript::HIGHLIGHT_QUERY)),
+        "nxml" => Some((xml::language(), xml::HIGHLIGHT_QUERY)),
+        "conf-toml" => Some((toml::language(), toml::HIGHLIGHT_QUERY)),
+        _ => get_language(Path::new(&format!("modeline.{}", name))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shebang_query(source: &str) -> Option<&'static str> {
+        get_language_from_shebang(source).map(|(_, query)| query)
+    }
+
+    fn path_query(path: &str) -> Option<&'static str> {
+        get_language(Path::new(path)).map(|(_, query)| query)
+    }
+
+    #[test]
+    fn test_well_known_file_names() {
+        assert_eq!(path_query("home/.bashrc"), Some(bash::HIGHLIGHT_QUERY));
+        assert_eq!(path_query("Rakefile"), Some(ruby::HIGHLIGHT_QUERY));
+        assert_eq!(
+            path_query("crates/foo/Cargo.lock"),
+            Some(toml::HIGHLIGHT_QUERY)
+        );
+        assert_eq!(path_query("src/main.rs"), Some(rust::HIGHLIGHT_QUERY));
+        assert_eq!(path_query("Dockerfile"), None);
+    }
+
+    #[test]
+    fn test_shebang_detection() {
+        assert_eq!(
+            shebang_query("#!/bin/bash\necho hi\n"),
+            Some(bash::HIGHLIGHT_QUERY)
+        );
+        assert_eq!(
+            shebang_query("#!/usr/bin/env zsh\n"),
+            Some(bash::HIGHLIGHT_QUERY)
+        );
+        assert_eq!(
+            shebang_query("#!/usr/bin/env -S python3.12 -u\n"),
+            Some(python::HIGHLIGHT_QUERY)
+        );
+        assert_eq!(
+            shebang_query("#!/usr/bin/node\n"),
+            Some(javascript::HIGHLIGHT_QUERY)
+        );
+    }
+
+    #[test]
+    fn test_modeline_detection() {
+        let modeline_query =
+            |source: &str| get_language_from_modeline(source).map(|(_, query)| query);
+        assert_eq!(
+            modeline_query("# vim: set ft=python:\nimport os\n"),
+            Some(python::HIGHLIGHT_QUERY)
+        );
+        assert_eq!(
+            modeline_query("echo hi\n\n# vi: filetype=sh ts=4\n"),
+            Some(bash::HIGHLIGHT_QUERY)
+        );
+        assert_eq!(
+            modeline_query("#!/usr/bin/unknown\n# -*- mode: Ruby; coding: utf-8 -*-\n"),
+            Some(ruby::HIGHLIGHT_QUERY)
+        );
+        assert_eq!(
+            modeline_query(";; -*- clojure -*-\n"),
+            Some(clojure::HIGHLIGHT_QUERY)
+        );
+        assert_eq!(
+            modeline_query("// vim: ft=rs\n"),
+            Some(rust::HIGHLIGHT_QUERY)
+        );
+        // Markers inside other words, modelines without a language, and
+        // modelines in the middle of a long file are not picked up
+        assert_eq!(modeline_query("regex:ft=python\n"), None);
+        assert_eq!(modeline_query("# -*- coding: utf-8 -*-\n"), None);
+        let buried = format!("{}# vim: ft=python\n{}", "x\n".repeat(10), "x\n".repeat(10));
+        assert_eq!(modeline_query(&buried), None);
+
+        // A #! line wins over a modeline
+        assert_eq!(
+            get_language_from_content("#!/bin/sh\n# vim: ft=python\n").map(|(_, query)| query),
+            Some(bash::HIGHLIGHT_QUERY)
+        );
+    }
+
+    #[test]
+    fn test_comment_delimiters() {
+        let delimiters = |path: &str, source: &str| comment_delimiters(Path::new(path), source);
+        assert_eq!(delimiters("src/main.rs", ""), Some(("//", "")));
+        assert_eq!(delimiters("Gemfile", ""), Some(("#", "")));
+        assert_eq!(delimiters("docs/index.md", ""), Some(("<!--", "-->")));
+        assert_eq!(
+            delimiters("bin/run", "#!/usr/bin/env lua\n"),
+            Some(("--", ""))
+        );
+        assert_eq!(delimiters("package.json", ""), None);
+        assert_eq!(delimiters("Dockerfile", ""), None);
+    }
+
+    #[test]
+    fn test_shebang_detection_requires_interpreter_line() {
+        assert_eq!(shebang_query("echo hi\n#!/bin/bash\n"), None);
+        assert_eq!(shebang_query("#!/usr/bin/unknown\n"), None);
+        assert_eq!(shebang_query(""), None);
+    }
+}
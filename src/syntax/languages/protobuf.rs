@@ -0,0 +1,5 @@
+pub fn language() -> tree_sitter::Language {
+    tree_sitter_proto::LANGUAGE.into()
+}
+
+pub const HIGHLIGHT_QUERY: &str = include_str!("queries/protobuf_highlights.scm");
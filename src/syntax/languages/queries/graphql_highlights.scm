@@ -0,0 +1,149 @@
+; Types
+;------
+
+(named_type
+  (name) @type)
+
+(scalar_type_definition
+  (name) @type)
+
+(object_type_definition
+  (name) @type)
+
+(interface_type_definition
+  (name) @type)
+
+(union_type_definition
+  (name) @type)
+
+(enum_type_definition
+  (name) @type)
+
+(input_object_type_definition
+  (name) @type)
+
+(scalar_type_extension
+  (name) @type)
+
+(object_type_extension
+  (name) @type)
+
+(interface_type_extension
+  (name) @type)
+
+(union_type_extension
+  (name) @type)
+
+(enum_type_extension
+  (name) @type)
+
+(input_object_type_extension
+  (name) @type)
+
+; Fields and arguments
+;---------------------
+
+(field_definition
+  (name) @property)
+
+(field
+  (name) @property)
+
+(alias
+  (name) @property)
+
+(object_field
+  (name) @property)
+
+(input_value_definition
+  (name) @parameter)
+
+(argument
+  (name) @parameter)
+
+(variable) @variable
+
+; Operations, fragments and directives
+;-------------------------------------
+
+(operation_definition
+  (name) @function)
+
+(fragment_name
+  (name) @function)
+
+(directive
+  "@" @attribute
+  (name) @attribute)
+
+(directive_definition
+  "@" @attribute
+  (name) @attribute)
+
+(directive_location) @constant
+
+(enum_value
+  (name) @constant)
+
+; Literals
+;---------
+
+(string_value) @string
+
+[
+  (int_value)
+  (float_value)
+] @number
+
+[
+  (boolean_value)
+  (null_value)
+] @constant
+
+(comment) @comment
+
+; Keywords
+;---------
+
+[
+  "query"
+  "mutation"
+  "subscription"
+  "fragment"
+  "on"
+  "schema"
+  "type"
+  "interface"
+  "union"
+  "enum"
+  "input"
+  "scalar"
+  "extend"
+  "implements"
+  "directive"
+  "repeatable"
+] @keyword
+
+; Punctuation
+;------------
+
+[
+  "="
+  "|"
+  "&"
+  "!"
+  "..."
+] @operator
+
+[
+  ":"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+] @punctuation.bracket
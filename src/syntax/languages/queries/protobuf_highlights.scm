@@ -0,0 +1,108 @@
+; Keywords
+;---------
+
+[
+  "syntax"
+  "edition"
+  "package"
+  "option"
+  "import"
+  "weak"
+  "public"
+  "service"
+  "rpc"
+  "stream"
+  "returns"
+  "message"
+  "enum"
+  "oneof"
+  "map"
+  "extend"
+  "extensions"
+  "optional"
+  "required"
+  "repeated"
+  "reserved"
+  "to"
+  "max"
+] @keyword
+
+; Types
+;------
+
+[
+  (key_type)
+  (type)
+  (message_name)
+  (enum_name)
+  (service_name)
+] @type
+
+(rpc_name) @function
+
+(package
+  (full_ident) @namespace)
+
+; Fields
+;-------
+
+(field
+  (identifier) @property)
+
+(oneof_field
+  (identifier) @property)
+
+(map_field
+  (identifier) @property)
+
+(enum_field
+  (identifier) @constant)
+
+(oneof
+  (identifier) @property)
+
+; Literals
+;---------
+
+[
+  (string)
+  "\"proto3\""
+  "\"proto2\""
+] @string
+
+[
+  (int_lit)
+  (float_lit)
+] @number
+
+[
+  (true)
+  (false)
+] @constant
+
+(comment) @comment
+
+; Punctuation
+;------------
+
+[
+  "="
+  "-"
+] @operator
+
+[
+  ";"
+  ","
+  "."
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+  "<"
+  ">"
+] @punctuation.bracket
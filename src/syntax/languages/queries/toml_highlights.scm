@@ -0,0 +1,69 @@
+; Keys
+;-----
+
+(pair
+  [
+    (bare_key)
+    (quoted_key)
+  ] @property)
+
+(pair
+  (dotted_key
+    [
+      (bare_key)
+      (quoted_key)
+    ] @property))
+
+(table
+  (bare_key) @type)
+
+(table
+  (dotted_key
+    (bare_key) @type))
+
+(table_array_element
+  (bare_key) @type)
+
+(table_array_element
+  (dotted_key
+    (bare_key) @type))
+
+; Literals
+;---------
+
+(boolean) @keyword
+
+(comment) @comment
+
+(string) @string
+
+[
+  (integer)
+  (float)
+] @number
+
+[
+  (offset_date_time)
+  (local_date_time)
+  (local_date)
+  (local_time)
+] @string.special
+
+; Punctuation
+;------------
+
+[
+  "."
+  ","
+] @punctuation.delimiter
+
+"=" @operator
+
+[
+  "["
+  "]"
+  "[["
+  "]]"
+  "{"
+  "}"
+] @punctuation.bracket
@@ -1,12 +1,13 @@
 pub mod languages;
 
-use crate::theme::Theme;
-use ratatui::style::Color;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
 use std::path::Path;
 use streaming_iterator::StreamingIterator;
 use tree_sitter::{Language, Parser, Query, QueryCursor};
 
-pub use languages::get_language;
+pub use languages::{comment_delimiters, get_language, get_language_from_content};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TokenType {
@@ -25,26 +26,6 @@ pub enum TokenType {
     Variable,
 }
 
-impl TokenType {
-    pub fn color(&self, theme: &Theme) -> Color {
-        match self {
-            TokenType::Comment => theme.syntax_comment,
-            TokenType::Constant => theme.syntax_constant,
-            TokenType::Function => theme.syntax_function,
-            TokenType::Keyword => theme.syntax_keyword,
-            TokenType::Label => theme.syntax_label,
-            TokenType::Number => theme.syntax_number,
-            TokenType::Operator => theme.syntax_operator,
-            TokenType::Parameter => theme.syntax_parameter,
-            TokenType::Property => theme.syntax_property,
-            TokenType::Punctuation => theme.syntax_punctuation,
-            TokenType::String => theme.syntax_string,
-            TokenType::Type => theme.syntax_type,
-            TokenType::Variable => theme.syntax_variable,
-        }
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct HighlightSpan {
     pub start: usize,
@@ -57,8 +38,42 @@ pub struct Highlighter {
     language: Option<Language>,
     query: Option<Query>,
     query_source: Option<String>,
-    cached_tree: Option<tree_sitter::Tree>,
-    cached_source: String,
+    cache: HighlightCache,
+}
+
+// Number of highlighted sources kept for reuse (e.g. when looping a range)
+const HIGHLIGHT_CACHE_SIZE: usize = 64;
+
+/// Least-recently-used cache of highlight results. Entries are keyed by the
+/// highlight query together with the source text, so switching a file to a
+/// different language never returns stale spans.
+#[derive(Default)]
+struct HighlightCache {
+    entries: VecDeque<(u64, Vec<HighlightSpan>)>,
+}
+
+impl HighlightCache {
+    fn key(query_source: &str, source: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        query_source.hash(&mut hasher);
+        source.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn get(&mut self, key: u64) -> Option<Vec<HighlightSpan>> {
+        let index = self.entries.iter().position(|(k, _)| *k == key)?;
+        let entry = self.entries.remove(index)?;
+        let spans = entry.1.clone();
+        self.entries.push_back(entry);
+        Some(spans)
+    }
+
+    fn insert(&mut self, key: u64, spans: Vec<HighlightSpan>) {
+        if self.entries.len() >= HIGHLIGHT_CACHE_SIZE {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, spans));
+    }
 }
 
 impl Clone for Highlighter {
@@ -77,8 +92,7 @@ impl Clone for Highlighter {
             language: self.language.clone(),
             query,
             query_source: self.query_source.clone(),
-            cached_tree: None,
-            cached_source: String::new(),
+            cache: HighlightCache::default(),
         }
     }
 }
@@ -90,20 +104,27 @@ impl Highlighter {
             language: None,
             query: None,
             query_source: None,
-            cached_tree: None,
-            cached_source: String::new(),
+            cache: HighlightCache::default(),
         }
     }
 
     pub fn set_language_from_path(&mut self, path: &str) -> bool {
-        if let Some((language, query_source)) = get_language(Path::new(path)) {
+        self.set_language(get_language(Path::new(path)))
+    }
+
+    /// Detect the language from the `#!` line or modeline of `source`, for
+    /// files without a recognized name or extension
+    pub fn set_language_from_content(&mut self, source: &str) -> bool {
+        self.set_language(get_language_from_content(source))
+    }
+
+    fn set_language(&mut self, language: Option<(Language, &'static str)>) -> bool {
+        if let Some((language, query_source)) = language {
             if self.parser.set_language(&language).is_ok() {
                 if let Ok(query) = Query::new(&language, query_source) {
                     self.language = Some(language);
                     self.query = Some(query);
                     self.query_source = Some(query_source.to_string());
-                    self.cached_tree = None;
-                    self.cached_source = String::new();
                     return true;
                 }
             }
@@ -112,33 +133,36 @@ impl Highlighter {
         self.language = None;
         self.query = None;
         self.query_source = None;
-        self.cached_tree = None;
-        self.cached_source = String::new();
         false
     }
 
     pub fn highlight(&mut self, source: &str) -> Vec<HighlightSpan> {
+        let Some(query_source) = &self.query_source else {
+            return Vec::new();
+        };
+
+        // Previously highlighted source: nothing to reparse
+        let key = HighlightCache::key(query_source, source);
+        if let Some(spans) = self.cache.get(key) {
+            return spans;
+        }
+
+        let spans = self.highlight_uncached(source);
+        self.cache.insert(key, spans.clone());
+        spans
+    }
+
+    fn highlight_uncached(&mut self, source: &str) -> Vec<HighlightSpan> {
         let mut spans = Vec::new();
 
         let Some(query) = &self.query else {
             return spans;
         };
 
-        // Use incremental parsing only if source hasn't changed
-        let old_tree = if self.cached_source == source {
-            self.cached_tree.as_ref()
-        } else {
-            None
-        };
-
-        let Some(tree) = self.parser.parse(source, old_tree) else {
+        let Some(tree) = self.parser.parse(source, None) else {
             return spans;
         };
 
-        // Cache the tree and source for next incremental parse (clone needed because matches borrows tree)
-        self.cached_tree = Some(tree.clone());
-        self.cached_source = source.to_string();
-
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(query, tree.root_node(), source.as_bytes());
 
@@ -209,3 +233,103 @@ impl Default for Highlighter {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_is_keyed_by_language() {
+        let source = "# heading\n";
+        let mut highlighter = Highlighter::new();
+
+        highlighter.set_language_from_path("script.py");
+        let python = highlighter.highlight(source);
+        assert!(matches!(python[0].token_type, TokenType::Comment));
+
+        highlighter.set_language_from_path("script.rs");
+        assert_ne!(highlighter.highlight(source).len(), python.len());
+
+        highlighter.set_language_from_path("script.py");
+        assert_eq!(highlighter.highlight(source).len(), python.len());
+        assert_eq!(highlighter.cache.entries.len(), 2);
+    }
+
+    fn token_at(source: &str, spans: &[HighlightSpan], text: &str) -> Option<TokenType> {
+        let start = source.find(text)?;
+        spans
+            .iter()
+            .find(|span| span.start == start && span.end == start + text.len())
+            .map(|span| span.token_type)
+    }
+
+    #[test]
+    fn test_highlights_graphql() {
+        let source = "# The API\ntype Query {\n  user(id: ID!): User @deprecated\n}\n\nquery Viewer($first: Int = 10) {\n  viewer { name(format: \"short\") }\n}\n";
+        let mut highlighter = Highlighter::new();
+        assert!(highlighter.set_language_from_path("schema.graphql"));
+        let spans = highlighter.highlight(source);
+
+        assert_eq!(
+            token_at(source, &spans, "# The API"),
+            Some(TokenType::Comment)
+        );
+        assert_eq!(token_at(source, &spans, "type"), Some(TokenType::Keyword));
+        assert_eq!(token_at(source, &spans, "query"), Some(TokenType::Keyword));
+        assert_eq!(token_at(source, &spans, "User"), Some(TokenType::Type));
+        assert_eq!(token_at(source, &spans, "user"), Some(TokenType::Property));
+        assert_eq!(
+            token_at(source, &spans, "viewer"),
+            Some(TokenType::Property)
+        );
+        assert_eq!(token_at(source, &spans, "10"), Some(TokenType::Number));
+        assert_eq!(
+            token_at(source, &spans, "\"short\""),
+            Some(TokenType::String)
+        );
+        assert!(highlighter.set_language_from_path("queries/viewer.gql"));
+    }
+
+    #[test]
+    fn test_highlights_protobuf() {
+        let source = "syntax = \"proto3\";\n\n// A user\nmessage User {\n  repeated string emails = 2;\n  Role role = 3;\n}\n\nservice Users {\n  rpc Get(User) returns (User);\n}\n";
+        let mut highlighter = Highlighter::new();
+        assert!(highlighter.set_language_from_path("api/users.proto"));
+        let spans = highlighter.highlight(source);
+
+        assert_eq!(
+            token_at(source, &spans, "// A user"),
+            Some(TokenType::Comment)
+        );
+        assert_eq!(
+            token_at(source, &spans, "message"),
+            Some(TokenType::Keyword)
+        );
+        assert_eq!(
+            token_at(source, &spans, "service"),
+            Some(TokenType::Keyword)
+        );
+        assert_eq!(token_at(source, &spans, "User"), Some(TokenType::Type));
+        assert_eq!(token_at(source, &spans, "string"), Some(TokenType::Type));
+        assert_eq!(
+            token_at(source, &spans, "emails"),
+            Some(TokenType::Property)
+        );
+        assert_eq!(token_at(source, &spans, "2"), Some(TokenType::Number));
+        assert_eq!(token_at(source, &spans, "Get"), Some(TokenType::Function));
+        assert_eq!(
+            token_at(source, &spans, "\"proto3\""),
+            Some(TokenType::String)
+        );
+    }
+
+    #[test]
+    fn test_cache_size_is_bounded() {
+        let mut highlighter = Highlighter::new();
+        highlighter.set_language_from_path("main.rs");
+        for i in 0..HIGHLIGHT_CACHE_SIZE + 10 {
+            highlighter.highlight(&format!("fn f{}() {{}}", i));
+        }
+        assert_eq!(highlighter.cache.entries.len(), HIGHLIGHT_CACHE_SIZE);
+    }
+}
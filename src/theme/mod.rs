@@ -1,9 +1,18 @@
 mod themes;
 
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::syntax::TokenType;
 use anyhow::{Context, Result};
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+/// A color scheme. Besides the built-in themes, themes can be loaded from
+/// TOML or JSON files whose keys are the field names below; every color is
+/// required except the optional `terminal_*` output colors, and
+/// `syntax_modifiers` is optional.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Theme {
     // Background colors
     pub background_left: Color,  // FileTree and StatusBar side (darker)
@@ -16,6 +25,10 @@ pub struct Theme {
     pub editor_cursor_char_bg: Color,
     pub editor_cursor_char_fg: Color,
     pub editor_cursor_line_bg: Color,
+    // Tint behind lines added or changed by the current commit
+    pub diff_added_bg: Color,
+    pub diff_modified_bg: Color,
+    pub editor_search_match_bg: Color,
 
     // File tree colors
     pub file_tree_added: Color,
@@ -34,6 +47,19 @@ pub struct Theme {
     pub terminal_output: Color,
     pub terminal_cursor_bg: Color,
     pub terminal_cursor_fg: Color,
+    // Program names in commands, and success or error markers, commit
+    // hashes and paths in the output. Left out, they take the colors that
+    // mean the same elsewhere.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_program: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_success: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_error: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_hash: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_path: Option<Color>,
 
     // Status bar colors
     pub status_hash: Color,
@@ -42,8 +68,12 @@ pub struct Theme {
     pub status_message: Color,
     pub status_no_commit: Color,
 
-    // Separator colors
+    // Separator and pane border colors
     pub separator: Color,
+    pub editor_border: Color,
+    pub terminal_border: Color,
+    // Border of the pane currently being typed into
+    pub border_active: Color,
 
     // Syntax highlighting colors
     pub syntax_keyword: Color,
@@ -59,6 +89,72 @@ pub struct Theme {
     pub syntax_parameter: Color,
     pub syntax_property: Color,
     pub syntax_label: Color,
+
+    // Syntax highlighting modifiers (bold, italic, ...)
+    #[serde(default)]
+    pub syntax_modifiers: SyntaxModifiers,
+}
+
+/// Text modifiers applied on top of the syntax color for each token type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyntaxModifiers {
+    pub comment: Modifier,
+    pub constant: Modifier,
+    pub function: Modifier,
+    pub keyword: Modifier,
+    pub label: Modifier,
+    pub number: Modifier,
+    pub operator: Modifier,
+    pub parameter: Modifier,
+    pub property: Modifier,
+    pub punctuation: Modifier,
+    pub string: Modifier,
+    #[serde(rename = "type")]
+    pub type_: Modifier,
+    pub variable: Modifier,
+}
+
+impl Default for SyntaxModifiers {
+    /// Italic comments and bold keywords, everything else plain
+    fn default() -> Self {
+        Self {
+            comment: Modifier::ITALIC,
+            constant: Modifier::empty(),
+            function: Modifier::empty(),
+            keyword: Modifier::BOLD,
+            label: Modifier::empty(),
+            number: Modifier::empty(),
+            operator: Modifier::empty(),
+            parameter: Modifier::empty(),
+            property: Modifier::empty(),
+            punctuation: Modifier::empty(),
+            string: Modifier::empty(),
+            type_: Modifier::empty(),
+            variable: Modifier::empty(),
+        }
+    }
+}
+
+impl SyntaxModifiers {
+    /// Modifier for the given token type
+    pub fn get(&self, token_type: TokenType) -> Modifier {
+        match token_type {
+            TokenType::Comment => self.comment,
+            TokenType::Constant => self.constant,
+            TokenType::Function => self.function,
+            TokenType::Keyword => self.keyword,
+            TokenType::Label => self.label,
+            TokenType::Number => self.number,
+            TokenType::Operator => self.operator,
+            TokenType::Parameter => self.parameter,
+            TokenType::Property => self.property,
+            TokenType::Punctuation => self.punctuation,
+            TokenType::String => self.string,
+            TokenType::Type => self.type_,
+            TokenType::Variable => self.variable,
+        }
+    }
 }
 
 impl Default for Theme {
@@ -68,27 +164,57 @@ impl Default for Theme {
 }
 
 impl Theme {
-    /// Load theme by name
+    /// Load a theme by name: a built-in theme, a theme file in the user
+    /// themes directory, or a path to a `.toml`/`.json` theme file
     pub fn load(name: &str) -> Result<Self> {
+        if is_theme_path(name) {
+            return Self::load_file(Path::new(name));
+        }
+        if let Some(theme) = Self::builtin(name) {
+            return Ok(theme);
+        }
+        if let Some(path) = user_theme_path(name) {
+            return Self::load_file(&path);
+        }
+        Err(anyhow::anyhow!("Unknown theme: {}", name))
+            .with_context(|| format!("Available themes: {}", Self::available_themes().join(", ")))
+    }
+
+    fn builtin(name: &str) -> Option<Self> {
         match name {
-            "ayu-dark" => Ok(themes::ayu_dark()),
-            "catppuccin" => Ok(themes::catppuccin()),
-            "dracula" => Ok(themes::dracula()),
-            "everforest" => Ok(themes::everforest()),
-            "github-dark" => Ok(themes::github_dark()),
-            "gruvbox" => Ok(themes::gruvbox()),
-            "material" => Ok(themes::material()),
-            "monokai" => Ok(themes::monokai()),
-            "night-owl" => Ok(themes::night_owl()),
-            "nord" => Ok(themes::nord()),
-            "one-dark" => Ok(themes::one_dark()),
-            "rose-pine" => Ok(themes::rose_pine()),
-            "solarized-dark" => Ok(themes::solarized_dark()),
-            "solarized-light" => Ok(themes::solarized_light()),
-            "tokyo-night" => Ok(themes::tokyo_night()),
-            _ => Err(anyhow::anyhow!("Unknown theme: {}", name))
-                .context("Available themes: ayu-dark, catppuccin, dracula, everforest, github-dark, gruvbox, material, monokai, night-owl, nord, one-dark, rose-pine, solarized-dark, solarized-light, tokyo-night"),
+            "ayu-dark" => Some(themes::ayu_dark()),
+            "catppuccin" => Some(themes::catppuccin()),
+            "dracula" => Some(themes::dracula()),
+            "everforest" => Some(themes::everforest()),
+            "github-dark" => Some(themes::github_dark()),
+            "gruvbox" => Some(themes::gruvbox()),
+            "material" => Some(themes::material()),
+            "monokai" => Some(themes::monokai()),
+            "night-owl" => Some(themes::night_owl()),
+            "nord" => Some(themes::nord()),
+            "one-dark" => Some(themes::one_dark()),
+            "rose-pine" => Some(themes::rose_pine()),
+            "solarized-dark" => Some(themes::solarized_dark()),
+            "solarized-light" => Some(themes::solarized_light()),
+            "tokyo-night" => Some(themes::tokyo_night()),
+            _ => None,
+        }
+    }
+
+    /// Load a theme from a TOML file, or JSON if the extension is `.json`
+    pub fn load_file(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read theme file: {}", path.display()))?;
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+
+        if is_json {
+            serde_json::from_str(&contents).map_err(anyhow::Error::from)
+        } else {
+            toml::from_str(&contents).map_err(anyhow::Error::from)
         }
+        .with_context(|| format!("Invalid theme file: {}", path.display()))
     }
 
     /// Remove background colors for transparent terminal background
@@ -98,9 +224,92 @@ impl Theme {
         self
     }
 
-    /// List all available built-in themes
-    pub fn available_themes() -> Vec<&'static str> {
-        vec![
+    /// Color used to highlight a token of the given type
+    pub fn syntax_color(&self, token_type: TokenType) -> Color {
+        match token_type {
+            TokenType::Comment => self.syntax_comment,
+            TokenType::Constant => self.syntax_constant,
+            TokenType::Function => self.syntax_function,
+            TokenType::Keyword => self.syntax_keyword,
+            TokenType::Label => self.syntax_label,
+            TokenType::Number => self.syntax_number,
+            TokenType::Operator => self.syntax_operator,
+            TokenType::Parameter => self.syntax_parameter,
+            TokenType::Property => self.syntax_property,
+            TokenType::Punctuation => self.syntax_punctuation,
+            TokenType::String => self.syntax_string,
+            TokenType::Type => self.syntax_type,
+            TokenType::Variable => self.syntax_variable,
+        }
+    }
+
+    /// Full style (color and modifiers) used to highlight a token of the given type
+    pub fn syntax_style(&self, token_type: TokenType) -> Style {
+        Style::default()
+            .fg(self.syntax_color(token_type))
+            .add_modifier(self.syntax_modifiers.get(token_type))
+    }
+
+    pub fn terminal_program_color(&self) -> Color {
+        self.terminal_program.unwrap_or(self.syntax_keyword)
+    }
+
+    pub fn terminal_success_color(&self) -> Color {
+        self.terminal_success.unwrap_or(self.file_tree_added)
+    }
+
+    pub fn terminal_error_color(&self) -> Color {
+        self.terminal_error.unwrap_or(self.file_tree_deleted)
+    }
+
+    pub fn terminal_hash_color(&self) -> Color {
+        self.terminal_hash.unwrap_or(self.status_hash)
+    }
+
+    pub fn terminal_path_color(&self) -> Color {
+        self.terminal_path.unwrap_or(self.file_tree_directory)
+    }
+
+    /// Accent colors that author badges are picked from
+    pub fn author_palette(&self) -> [Color; 8] {
+        [
+            self.status_author,
+            self.status_hash,
+            self.status_date,
+            self.syntax_keyword,
+            self.syntax_string,
+            self.syntax_number,
+            self.syntax_type,
+            self.file_tree_deleted,
+        ]
+    }
+
+    /// The theme as a TOML theme file that `load_file` reads back
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self).context("Failed to serialize theme")
+    }
+
+    /// Names of all available themes: the built-in ones followed by any
+    /// theme files in the user themes directory
+    pub fn available_themes() -> Vec<String> {
+        let mut names: Vec<String> = Self::builtin_themes()
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        for name in user_theme_names() {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Theme name that picks a different built-in theme for each commit
+    pub const RANDOM: &'static str = "random";
+
+    /// Names of the built-in themes
+    pub fn builtin_themes() -> &'static [&'static str] {
+        &[
             "ayu-dark",
             "catppuccin",
             "dracula",
@@ -119,3 +328,157 @@ impl Theme {
         ]
     }
 }
+
+/// Directory searched for user theme files (`~/.config/gitlogue/themes`)
+pub fn user_themes_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".config").join("gitlogue").join("themes"))
+}
+
+// Theme arguments that name a file rather than a theme
+fn is_theme_path(name: &str) -> bool {
+    name.contains('/') || name.contains('\\') || name.ends_with(".toml") || name.ends_with(".json")
+}
+
+fn user_theme_path(name: &str) -> Option<PathBuf> {
+    let dir = user_themes_dir()?;
+    ["toml", "json"]
+        .iter()
+        .map(|ext| dir.join(format!("{}.{}", name, ext)))
+        .find(|path| path.is_file())
+}
+
+fn user_theme_names() -> Vec<String> {
+    let Some(entries) = user_themes_dir().and_then(|dir| fs::read_dir(dir).ok()) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == "toml" || ext == "json")
+        })
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLOR_FIELDS: &[&str] = &[
+        "background_left",
+        "background_right",
+        "editor_line_number",
+        "editor_line_number_cursor",
+        "editor_separator",
+        "editor_cursor_char_bg",
+        "editor_cursor_char_fg",
+        "editor_cursor_line_bg",
+        "diff_added_bg",
+        "diff_modified_bg",
+        "editor_search_match_bg",
+        "file_tree_added",
+        "file_tree_deleted",
+        "file_tree_modified",
+        "file_tree_renamed",
+        "file_tree_directory",
+        "file_tree_current_file_bg",
+        "file_tree_current_file_fg",
+        "file_tree_default",
+        "file_tree_stats_added",
+        "file_tree_stats_deleted",
+        "terminal_command",
+        "terminal_output",
+        "terminal_cursor_bg",
+        "terminal_cursor_fg",
+        "status_hash",
+        "status_author",
+        "status_date",
+        "status_message",
+        "status_no_commit",
+        "separator",
+        "editor_border",
+        "terminal_border",
+        "border_active",
+        "syntax_keyword",
+        "syntax_type",
+        "syntax_function",
+        "syntax_variable",
+        "syntax_string",
+        "syntax_number",
+        "syntax_comment",
+        "syntax_operator",
+        "syntax_punctuation",
+        "syntax_constant",
+        "syntax_parameter",
+        "syntax_property",
+        "syntax_label",
+    ];
+
+    fn write_theme(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("gitlogue-{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_loads_theme_files() {
+        let toml: String = COLOR_FIELDS
+            .iter()
+            .map(|field| format!("{} = \"#102030\"\n", field))
+            .collect::<String>()
+            + "terminal_hash = \"#405060\"\n"
+            + "[syntax_modifiers]\ntype = \"BOLD\"\n";
+        let path = write_theme("theme.toml", &toml);
+        let theme = Theme::load(path.to_str().unwrap()).unwrap();
+        assert_eq!(theme.syntax_keyword, Color::Rgb(0x10, 0x20, 0x30));
+        // Terminal output colors left out fall back to related colors
+        assert_eq!(theme.terminal_hash_color(), Color::Rgb(0x40, 0x50, 0x60));
+        assert_eq!(theme.terminal_success_color(), theme.file_tree_added);
+        assert_eq!(theme.syntax_modifiers.type_, Modifier::BOLD);
+        // Modifiers left out keep their defaults
+        assert_eq!(theme.syntax_modifiers.comment, Modifier::ITALIC);
+        let _ = fs::remove_file(&path);
+
+        let json = format!(
+            "{{{}}}",
+            COLOR_FIELDS
+                .iter()
+                .map(|field| format!("\"{}\": \"red\"", field))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let path = write_theme("theme.json", &json);
+        let theme = Theme::load(path.to_str().unwrap()).unwrap();
+        assert_eq!(theme.separator, Color::Red);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_dumped_themes_load_back() {
+        for name in Theme::builtin_themes() {
+            let theme = Theme::load(name).unwrap();
+            let dumped = theme.to_toml().unwrap();
+            let path = write_theme(&format!("{}.toml", name), &dumped);
+            let reloaded = Theme::load_file(&path).unwrap();
+            assert_eq!(reloaded.to_toml().unwrap(), dumped, "{name}");
+            assert_eq!(reloaded.syntax_modifiers, theme.syntax_modifiers);
+            let _ = fs::remove_file(&path);
+        }
+    }
+
+    #[test]
+    fn test_reports_missing_theme_fields() {
+        let path = write_theme("partial.toml", "background_left = \"#000000\"\n");
+        let error = format!("{:#}", Theme::load(path.to_str().unwrap()).unwrap_err());
+        assert!(error.contains("Invalid theme file"), "{error}");
+        assert!(
+            error.contains("missing field `background_right`"),
+            "{error}"
+        );
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Ayu Dark inspired color scheme
@@ -13,6 +13,9 @@ pub fn ayu_dark() -> Theme {
         editor_cursor_char_bg: Color::Rgb(255, 180, 84),
         editor_cursor_char_fg: Color::Rgb(15, 20, 25),
         editor_cursor_line_bg: Color::Rgb(22, 29, 37),
+        diff_added_bg: Color::Rgb(41, 52, 40),
+        diff_modified_bg: Color::Rgb(51, 44, 34),
+        editor_search_match_bg: Color::Rgb(111, 84, 49),
 
         file_tree_added: Color::Rgb(186, 230, 126),
         file_tree_deleted: Color::Rgb(242, 97, 103),
@@ -29,6 +32,11 @@ pub fn ayu_dark() -> Theme {
         terminal_output: Color::Rgb(62, 68, 82),
         terminal_cursor_bg: Color::Rgb(255, 180, 84),
         terminal_cursor_fg: Color::Rgb(15, 20, 25),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(229, 181, 103),
         status_author: Color::Rgb(186, 230, 126),
@@ -37,6 +45,9 @@ pub fn ayu_dark() -> Theme {
         status_no_commit: Color::Rgb(62, 68, 82),
 
         separator: Color::Rgb(62, 68, 82),
+        editor_border: Color::Rgb(62, 68, 82),
+        terminal_border: Color::Rgb(62, 68, 82),
+        border_active: Color::Rgb(89, 182, 215),
 
         syntax_keyword: Color::Rgb(255, 140, 99),
         syntax_type: Color::Rgb(229, 181, 103),
@@ -51,5 +62,7 @@ pub fn ayu_dark() -> Theme {
         syntax_parameter: Color::Rgb(255, 214, 111),
         syntax_property: Color::Rgb(115, 184, 205),
         syntax_label: Color::Rgb(255, 140, 99),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
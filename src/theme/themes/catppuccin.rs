@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Catppuccin Mocha inspired color scheme
@@ -13,6 +13,9 @@ pub fn catppuccin() -> Theme {
         editor_cursor_char_bg: Color::Rgb(245, 194, 231),
         editor_cursor_char_fg: Color::Rgb(30, 30, 46),
         editor_cursor_line_bg: Color::Rgb(49, 50, 68),
+        diff_added_bg: Color::Rgb(50, 60, 63),
+        diff_modified_bg: Color::Rgb(63, 52, 59),
+        editor_search_match_bg: Color::Rgb(116, 96, 120),
 
         file_tree_added: Color::Rgb(166, 227, 161),
         file_tree_deleted: Color::Rgb(243, 139, 168),
@@ -29,6 +32,11 @@ pub fn catppuccin() -> Theme {
         terminal_output: Color::Rgb(108, 112, 134),
         terminal_cursor_bg: Color::Rgb(245, 194, 231),
         terminal_cursor_fg: Color::Rgb(30, 30, 46),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(249, 226, 175),
         status_author: Color::Rgb(166, 227, 161),
@@ -37,6 +45,9 @@ pub fn catppuccin() -> Theme {
         status_no_commit: Color::Rgb(108, 112, 134),
 
         separator: Color::Rgb(108, 112, 134),
+        editor_border: Color::Rgb(108, 112, 134),
+        terminal_border: Color::Rgb(108, 112, 134),
+        border_active: Color::Rgb(137, 180, 250),
 
         syntax_keyword: Color::Rgb(203, 166, 247),
         syntax_type: Color::Rgb(249, 226, 175),
@@ -51,5 +62,7 @@ pub fn catppuccin() -> Theme {
         syntax_parameter: Color::Rgb(245, 194, 231),
         syntax_property: Color::Rgb(166, 227, 161),
         syntax_label: Color::Rgb(203, 166, 247),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
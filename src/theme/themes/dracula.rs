@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Dracula inspired color scheme
@@ -13,6 +13,9 @@ pub fn dracula() -> Theme {
         editor_cursor_char_bg: Color::Rgb(255, 121, 198),
         editor_cursor_char_fg: Color::Rgb(40, 42, 54),
         editor_cursor_line_bg: Color::Rgb(68, 71, 90),
+        diff_added_bg: Color::Rgb(46, 73, 64),
+        diff_modified_bg: Color::Rgb(72, 63, 62),
+        editor_search_match_bg: Color::Rgb(126, 74, 112),
 
         file_tree_added: Color::Rgb(80, 250, 123),
         file_tree_deleted: Color::Rgb(255, 85, 85),
@@ -29,6 +32,11 @@ pub fn dracula() -> Theme {
         terminal_output: Color::Rgb(98, 114, 164),
         terminal_cursor_bg: Color::Rgb(255, 121, 198),
         terminal_cursor_fg: Color::Rgb(40, 42, 54),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(241, 250, 140),
         status_author: Color::Rgb(80, 250, 123),
@@ -37,6 +45,9 @@ pub fn dracula() -> Theme {
         status_no_commit: Color::Rgb(98, 114, 164),
 
         separator: Color::Rgb(98, 114, 164),
+        editor_border: Color::Rgb(98, 114, 164),
+        terminal_border: Color::Rgb(98, 114, 164),
+        border_active: Color::Rgb(139, 233, 253),
 
         syntax_keyword: Color::Rgb(255, 121, 198),
         syntax_type: Color::Rgb(139, 233, 253),
@@ -51,5 +62,7 @@ pub fn dracula() -> Theme {
         syntax_parameter: Color::Rgb(255, 184, 108),
         syntax_property: Color::Rgb(80, 250, 123),
         syntax_label: Color::Rgb(255, 121, 198),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
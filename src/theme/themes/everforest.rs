@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Everforest Dark inspired color scheme
@@ -13,6 +13,9 @@ pub fn everforest() -> Theme {
         editor_cursor_char_bg: Color::Rgb(131, 192, 146),
         editor_cursor_char_fg: Color::Rgb(45, 52, 46),
         editor_cursor_line_bg: Color::Rgb(57, 64, 58),
+        diff_added_bg: Color::Rgb(58, 73, 61),
+        diff_modified_bg: Color::Rgb(71, 72, 58),
+        editor_search_match_bg: Color::Rgb(79, 108, 86),
 
         file_tree_added: Color::Rgb(131, 192, 146),
         file_tree_deleted: Color::Rgb(230, 126, 128),
@@ -29,6 +32,11 @@ pub fn everforest() -> Theme {
         terminal_output: Color::Rgb(125, 135, 116),
         terminal_cursor_bg: Color::Rgb(131, 192, 146),
         terminal_cursor_fg: Color::Rgb(45, 52, 46),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(219, 188, 127),
         status_author: Color::Rgb(131, 192, 146),
@@ -37,6 +45,9 @@ pub fn everforest() -> Theme {
         status_no_commit: Color::Rgb(125, 135, 116),
 
         separator: Color::Rgb(125, 135, 116),
+        editor_border: Color::Rgb(125, 135, 116),
+        terminal_border: Color::Rgb(125, 135, 116),
+        border_active: Color::Rgb(131, 192, 146),
 
         syntax_keyword: Color::Rgb(230, 126, 128),
         syntax_type: Color::Rgb(219, 188, 127),
@@ -51,5 +62,7 @@ pub fn everforest() -> Theme {
         syntax_parameter: Color::Rgb(219, 188, 127),
         syntax_property: Color::Rgb(125, 192, 192),
         syntax_label: Color::Rgb(230, 126, 128),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// GitHub Dark inspired color scheme
@@ -13,6 +13,9 @@ pub fn github_dark() -> Theme {
         editor_cursor_char_bg: Color::Rgb(88, 166, 255),
         editor_cursor_char_fg: Color::Rgb(22, 27, 34),
         editor_cursor_line_bg: Color::Rgb(33, 38, 45),
+        diff_added_bg: Color::Rgb(28, 51, 41),
+        diff_modified_bg: Color::Rgb(52, 39, 35),
+        editor_search_match_bg: Color::Rgb(48, 83, 122),
 
         file_tree_added: Color::Rgb(63, 185, 80),
         file_tree_deleted: Color::Rgb(248, 81, 73),
@@ -29,6 +32,11 @@ pub fn github_dark() -> Theme {
         terminal_output: Color::Rgb(110, 118, 129),
         terminal_cursor_bg: Color::Rgb(88, 166, 255),
         terminal_cursor_fg: Color::Rgb(22, 27, 34),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(219, 171, 9),
         status_author: Color::Rgb(63, 185, 80),
@@ -37,6 +45,9 @@ pub fn github_dark() -> Theme {
         status_no_commit: Color::Rgb(110, 118, 129),
 
         separator: Color::Rgb(48, 54, 61),
+        editor_border: Color::Rgb(48, 54, 61),
+        terminal_border: Color::Rgb(48, 54, 61),
+        border_active: Color::Rgb(88, 166, 255),
 
         syntax_keyword: Color::Rgb(255, 123, 114),
         syntax_type: Color::Rgb(255, 186, 77),
@@ -51,5 +62,7 @@ pub fn github_dark() -> Theme {
         syntax_parameter: Color::Rgb(255, 186, 77),
         syntax_property: Color::Rgb(121, 192, 255),
         syntax_label: Color::Rgb(210, 153, 255),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Gruvbox Dark inspired color scheme
@@ -13,6 +13,9 @@ pub fn gruvbox() -> Theme {
         editor_cursor_char_bg: Color::Rgb(254, 128, 25),
         editor_cursor_char_fg: Color::Rgb(40, 40, 40),
         editor_cursor_line_bg: Color::Rgb(60, 56, 54),
+        diff_added_bg: Color::Rgb(62, 62, 40),
+        diff_modified_bg: Color::Rgb(72, 53, 38),
+        editor_search_match_bg: Color::Rgb(126, 75, 34),
 
         file_tree_added: Color::Rgb(184, 187, 38),
         file_tree_deleted: Color::Rgb(251, 73, 52),
@@ -29,6 +32,11 @@ pub fn gruvbox() -> Theme {
         terminal_output: Color::Rgb(146, 131, 116),
         terminal_cursor_bg: Color::Rgb(254, 128, 25),
         terminal_cursor_fg: Color::Rgb(40, 40, 40),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(250, 189, 47),
         status_author: Color::Rgb(184, 187, 38),
@@ -37,6 +45,9 @@ pub fn gruvbox() -> Theme {
         status_no_commit: Color::Rgb(146, 131, 116),
 
         separator: Color::Rgb(146, 131, 116),
+        editor_border: Color::Rgb(146, 131, 116),
+        terminal_border: Color::Rgb(146, 131, 116),
+        border_active: Color::Rgb(131, 165, 152),
 
         syntax_keyword: Color::Rgb(251, 73, 52),
         syntax_type: Color::Rgb(250, 189, 47),
@@ -51,5 +62,7 @@ pub fn gruvbox() -> Theme {
         syntax_parameter: Color::Rgb(254, 128, 25),
         syntax_property: Color::Rgb(184, 187, 38),
         syntax_label: Color::Rgb(251, 73, 52),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Material Theme inspired color scheme
@@ -13,6 +13,9 @@ pub fn material() -> Theme {
         editor_cursor_char_bg: Color::Rgb(255, 203, 107),
         editor_cursor_char_fg: Color::Rgb(38, 50, 56),
         editor_cursor_line_bg: Color::Rgb(55, 71, 79),
+        diff_added_bg: Color::Rgb(62, 77, 69),
+        diff_modified_bg: Color::Rgb(71, 73, 64),
+        editor_search_match_bg: Color::Rgb(125, 111, 76),
 
         file_tree_added: Color::Rgb(195, 232, 141),
         file_tree_deleted: Color::Rgb(255, 83, 112),
@@ -29,6 +32,11 @@ pub fn material() -> Theme {
         terminal_output: Color::Rgb(84, 110, 122),
         terminal_cursor_bg: Color::Rgb(255, 203, 107),
         terminal_cursor_fg: Color::Rgb(38, 50, 56),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(255, 203, 107),
         status_author: Color::Rgb(195, 232, 141),
@@ -37,6 +45,9 @@ pub fn material() -> Theme {
         status_no_commit: Color::Rgb(84, 110, 122),
 
         separator: Color::Rgb(84, 110, 122),
+        editor_border: Color::Rgb(84, 110, 122),
+        terminal_border: Color::Rgb(84, 110, 122),
+        border_active: Color::Rgb(128, 203, 196),
 
         syntax_keyword: Color::Rgb(199, 146, 234),
         syntax_type: Color::Rgb(255, 203, 107),
@@ -51,5 +62,7 @@ pub fn material() -> Theme {
         syntax_parameter: Color::Rgb(255, 203, 107),
         syntax_property: Color::Rgb(128, 203, 196),
         syntax_label: Color::Rgb(199, 146, 234),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
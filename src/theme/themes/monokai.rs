@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Monokai inspired color scheme
@@ -13,6 +13,9 @@ pub fn monokai() -> Theme {
         editor_cursor_char_bg: Color::Rgb(253, 151, 31),
         editor_cursor_char_fg: Color::Rgb(39, 40, 34),
         editor_cursor_line_bg: Color::Rgb(51, 51, 45),
+        diff_added_bg: Color::Rgb(58, 68, 36),
+        diff_modified_bg: Color::Rgb(71, 57, 34),
+        editor_search_match_bg: Color::Rgb(125, 84, 33),
 
         file_tree_added: Color::Rgb(166, 226, 46),
         file_tree_deleted: Color::Rgb(249, 38, 114),
@@ -29,6 +32,11 @@ pub fn monokai() -> Theme {
         terminal_output: Color::Rgb(117, 113, 94),
         terminal_cursor_bg: Color::Rgb(253, 151, 31),
         terminal_cursor_fg: Color::Rgb(39, 40, 34),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(230, 219, 116),
         status_author: Color::Rgb(166, 226, 46),
@@ -37,6 +45,9 @@ pub fn monokai() -> Theme {
         status_no_commit: Color::Rgb(117, 113, 94),
 
         separator: Color::Rgb(117, 113, 94),
+        editor_border: Color::Rgb(117, 113, 94),
+        terminal_border: Color::Rgb(117, 113, 94),
+        border_active: Color::Rgb(102, 217, 239),
 
         syntax_keyword: Color::Rgb(249, 38, 114),
         syntax_type: Color::Rgb(102, 217, 239),
@@ -51,5 +62,7 @@ pub fn monokai() -> Theme {
         syntax_parameter: Color::Rgb(253, 151, 31),
         syntax_property: Color::Rgb(166, 226, 46),
         syntax_label: Color::Rgb(249, 38, 114),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Night Owl inspired color scheme
@@ -13,6 +13,9 @@ pub fn night_owl() -> Theme {
         editor_cursor_char_bg: Color::Rgb(122, 162, 247),
         editor_cursor_char_fg: Color::Rgb(1, 22, 39),
         editor_cursor_line_bg: Color::Rgb(1, 41, 72),
+        diff_added_bg: Color::Rgb(27, 52, 49),
+        diff_modified_bg: Color::Rgb(39, 51, 52),
+        editor_search_match_bg: Color::Rgb(49, 78, 122),
 
         file_tree_added: Color::Rgb(173, 219, 103),
         file_tree_deleted: Color::Rgb(239, 83, 80),
@@ -29,6 +32,11 @@ pub fn night_owl() -> Theme {
         terminal_output: Color::Rgb(78, 121, 147),
         terminal_cursor_bg: Color::Rgb(122, 162, 247),
         terminal_cursor_fg: Color::Rgb(1, 22, 39),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(255, 203, 107),
         status_author: Color::Rgb(173, 219, 103),
@@ -37,6 +45,9 @@ pub fn night_owl() -> Theme {
         status_no_commit: Color::Rgb(78, 121, 147),
 
         separator: Color::Rgb(1, 76, 134),
+        editor_border: Color::Rgb(1, 76, 134),
+        terminal_border: Color::Rgb(1, 76, 134),
+        border_active: Color::Rgb(122, 162, 247),
 
         syntax_keyword: Color::Rgb(199, 146, 234),
         syntax_type: Color::Rgb(255, 203, 107),
@@ -51,5 +62,7 @@ pub fn night_owl() -> Theme {
         syntax_parameter: Color::Rgb(255, 203, 107),
         syntax_property: Color::Rgb(122, 162, 247),
         syntax_label: Color::Rgb(255, 88, 116),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
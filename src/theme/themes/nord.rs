@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Nord inspired color scheme
@@ -13,6 +13,9 @@ pub fn nord() -> Theme {
         editor_cursor_char_bg: Color::Rgb(136, 192, 208),
         editor_cursor_char_fg: Color::Rgb(46, 52, 64),
         editor_cursor_line_bg: Color::Rgb(59, 66, 82),
+        diff_added_bg: Color::Rgb(64, 73, 75),
+        diff_modified_bg: Color::Rgb(74, 75, 75),
+        editor_search_match_bg: Color::Rgb(82, 108, 122),
 
         file_tree_added: Color::Rgb(163, 190, 140),
         file_tree_deleted: Color::Rgb(191, 97, 106),
@@ -29,6 +32,11 @@ pub fn nord() -> Theme {
         terminal_output: Color::Rgb(76, 86, 106),
         terminal_cursor_bg: Color::Rgb(136, 192, 208),
         terminal_cursor_fg: Color::Rgb(46, 52, 64),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(235, 203, 139),
         status_author: Color::Rgb(163, 190, 140),
@@ -37,6 +45,9 @@ pub fn nord() -> Theme {
         status_no_commit: Color::Rgb(76, 86, 106),
 
         separator: Color::Rgb(76, 86, 106),
+        editor_border: Color::Rgb(76, 86, 106),
+        terminal_border: Color::Rgb(76, 86, 106),
+        border_active: Color::Rgb(136, 192, 208),
 
         syntax_keyword: Color::Rgb(180, 142, 173),
         syntax_type: Color::Rgb(136, 192, 208),
@@ -51,5 +62,7 @@ pub fn nord() -> Theme {
         syntax_parameter: Color::Rgb(235, 203, 139),
         syntax_property: Color::Rgb(163, 190, 140),
         syntax_label: Color::Rgb(180, 142, 173),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// One Dark inspired color scheme
@@ -13,6 +13,9 @@ pub fn one_dark() -> Theme {
         editor_cursor_char_bg: Color::Rgb(97, 175, 239),
         editor_cursor_char_fg: Color::Rgb(40, 44, 52),
         editor_cursor_line_bg: Color::Rgb(47, 52, 61),
+        diff_added_bg: Color::Rgb(57, 67, 62),
+        diff_modified_bg: Color::Rgb(65, 60, 59),
+        editor_search_match_bg: Color::Rgb(63, 96, 127),
 
         file_tree_added: Color::Rgb(152, 195, 121),
         file_tree_deleted: Color::Rgb(224, 108, 117),
@@ -29,6 +32,11 @@ pub fn one_dark() -> Theme {
         terminal_output: Color::Rgb(92, 99, 112),
         terminal_cursor_bg: Color::Rgb(97, 175, 239),
         terminal_cursor_fg: Color::Rgb(40, 44, 52),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(229, 192, 123),
         status_author: Color::Rgb(152, 195, 121),
@@ -37,6 +45,9 @@ pub fn one_dark() -> Theme {
         status_no_commit: Color::Rgb(92, 99, 112),
 
         separator: Color::Rgb(92, 99, 112),
+        editor_border: Color::Rgb(92, 99, 112),
+        terminal_border: Color::Rgb(92, 99, 112),
+        border_active: Color::Rgb(97, 175, 239),
 
         syntax_keyword: Color::Rgb(198, 120, 221),
         syntax_type: Color::Rgb(229, 192, 123),
@@ -51,5 +62,7 @@ pub fn one_dark() -> Theme {
         syntax_parameter: Color::Rgb(229, 192, 123),
         syntax_property: Color::Rgb(152, 195, 121),
         syntax_label: Color::Rgb(198, 120, 221),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Rose Pine inspired color scheme
@@ -13,6 +13,9 @@ pub fn rose_pine() -> Theme {
         editor_cursor_char_bg: Color::Rgb(235, 188, 186),
         editor_cursor_char_fg: Color::Rgb(35, 33, 54),
         editor_cursor_line_bg: Color::Rgb(42, 39, 63),
+        diff_added_bg: Color::Rgb(53, 59, 78),
+        diff_modified_bg: Color::Rgb(67, 57, 64),
+        editor_search_match_bg: Color::Rgb(115, 95, 107),
 
         file_tree_added: Color::Rgb(156, 207, 216),
         file_tree_deleted: Color::Rgb(235, 111, 146),
@@ -29,6 +32,11 @@ pub fn rose_pine() -> Theme {
         terminal_output: Color::Rgb(110, 106, 134),
         terminal_cursor_bg: Color::Rgb(235, 188, 186),
         terminal_cursor_fg: Color::Rgb(35, 33, 54),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(246, 193, 119),
         status_author: Color::Rgb(156, 207, 216),
@@ -37,6 +45,9 @@ pub fn rose_pine() -> Theme {
         status_no_commit: Color::Rgb(110, 106, 134),
 
         separator: Color::Rgb(110, 106, 134),
+        editor_border: Color::Rgb(110, 106, 134),
+        terminal_border: Color::Rgb(110, 106, 134),
+        border_active: Color::Rgb(156, 207, 216),
 
         syntax_keyword: Color::Rgb(196, 167, 231),
         syntax_type: Color::Rgb(246, 193, 119),
@@ -51,5 +62,7 @@ pub fn rose_pine() -> Theme {
         syntax_parameter: Color::Rgb(246, 193, 119),
         syntax_property: Color::Rgb(156, 207, 216),
         syntax_label: Color::Rgb(196, 167, 231),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
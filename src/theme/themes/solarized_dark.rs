@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Solarized Dark color scheme
@@ -13,6 +13,9 @@ pub fn solarized_dark() -> Theme {
         editor_cursor_char_bg: Color::Rgb(38, 139, 210),
         editor_cursor_char_fg: Color::Rgb(0, 43, 54),
         editor_cursor_line_bg: Color::Rgb(7, 54, 66),
+        diff_added_bg: Color::Rgb(20, 60, 46),
+        diff_modified_bg: Color::Rgb(27, 57, 46),
+        editor_search_match_bg: Color::Rgb(15, 81, 116),
 
         file_tree_added: Color::Rgb(133, 153, 0),
         file_tree_deleted: Color::Rgb(220, 50, 47),
@@ -29,6 +32,11 @@ pub fn solarized_dark() -> Theme {
         terminal_output: Color::Rgb(88, 110, 117),
         terminal_cursor_bg: Color::Rgb(38, 139, 210),
         terminal_cursor_fg: Color::Rgb(0, 43, 54),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(181, 137, 0),
         status_author: Color::Rgb(133, 153, 0),
@@ -37,6 +45,9 @@ pub fn solarized_dark() -> Theme {
         status_no_commit: Color::Rgb(88, 110, 117),
 
         separator: Color::Rgb(88, 110, 117),
+        editor_border: Color::Rgb(88, 110, 117),
+        terminal_border: Color::Rgb(88, 110, 117),
+        border_active: Color::Rgb(38, 139, 210),
 
         syntax_keyword: Color::Rgb(203, 75, 22),
         syntax_type: Color::Rgb(181, 137, 0),
@@ -51,5 +62,7 @@ pub fn solarized_dark() -> Theme {
         syntax_parameter: Color::Rgb(181, 137, 0),
         syntax_property: Color::Rgb(42, 161, 152),
         syntax_label: Color::Rgb(211, 54, 130),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
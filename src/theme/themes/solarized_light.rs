@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Solarized Light color scheme
@@ -13,6 +13,9 @@ pub fn solarized_light() -> Theme {
         editor_cursor_char_bg: Color::Rgb(38, 139, 210),
         editor_cursor_char_fg: Color::Rgb(253, 246, 227),
         editor_cursor_line_bg: Color::Rgb(238, 232, 213),
+        diff_added_bg: Color::Rgb(235, 232, 193),
+        diff_modified_bg: Color::Rgb(242, 230, 193),
+        editor_search_match_bg: Color::Rgb(167, 203, 220),
 
         file_tree_added: Color::Rgb(133, 153, 0),
         file_tree_deleted: Color::Rgb(220, 50, 47),
@@ -29,6 +32,11 @@ pub fn solarized_light() -> Theme {
         terminal_output: Color::Rgb(147, 161, 161),
         terminal_cursor_bg: Color::Rgb(38, 139, 210),
         terminal_cursor_fg: Color::Rgb(253, 246, 227),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(181, 137, 0),
         status_author: Color::Rgb(133, 153, 0),
@@ -37,6 +45,9 @@ pub fn solarized_light() -> Theme {
         status_no_commit: Color::Rgb(147, 161, 161),
 
         separator: Color::Rgb(147, 161, 161),
+        editor_border: Color::Rgb(147, 161, 161),
+        terminal_border: Color::Rgb(147, 161, 161),
+        border_active: Color::Rgb(38, 139, 210),
 
         syntax_keyword: Color::Rgb(203, 75, 22),
         syntax_type: Color::Rgb(181, 137, 0),
@@ -51,5 +62,7 @@ pub fn solarized_light() -> Theme {
         syntax_parameter: Color::Rgb(181, 137, 0),
         syntax_property: Color::Rgb(42, 161, 152),
         syntax_label: Color::Rgb(211, 54, 130),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
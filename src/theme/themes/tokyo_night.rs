@@ -1,4 +1,4 @@
-use super::super::Theme;
+use super::super::{SyntaxModifiers, Theme};
 use ratatui::style::Color;
 
 /// Tokyo Night inspired color scheme
@@ -13,6 +13,9 @@ pub fn tokyo_night() -> Theme {
         editor_cursor_char_bg: Color::Rgb(122, 162, 247),
         editor_cursor_char_fg: Color::Rgb(26, 27, 38),
         editor_cursor_line_bg: Color::Rgb(42, 47, 68),
+        diff_added_bg: Color::Rgb(46, 54, 48),
+        diff_modified_bg: Color::Rgb(60, 47, 47),
+        editor_search_match_bg: Color::Rgb(64, 81, 122),
 
         file_tree_added: Color::Rgb(158, 206, 106),
         file_tree_deleted: Color::Rgb(247, 118, 142),
@@ -29,6 +32,11 @@ pub fn tokyo_night() -> Theme {
         terminal_output: Color::Rgb(86, 95, 137),
         terminal_cursor_bg: Color::Rgb(122, 162, 247),
         terminal_cursor_fg: Color::Rgb(26, 27, 38),
+        terminal_program: None,
+        terminal_success: None,
+        terminal_error: None,
+        terminal_hash: None,
+        terminal_path: None,
 
         status_hash: Color::Rgb(255, 213, 128),
         status_author: Color::Rgb(158, 206, 106),
@@ -37,6 +45,9 @@ pub fn tokyo_night() -> Theme {
         status_no_commit: Color::Rgb(86, 95, 137),
 
         separator: Color::Rgb(86, 95, 137),
+        editor_border: Color::Rgb(86, 95, 137),
+        terminal_border: Color::Rgb(86, 95, 137),
+        border_active: Color::Rgb(125, 207, 255),
 
         syntax_keyword: Color::Rgb(187, 154, 247),
         syntax_type: Color::Rgb(125, 207, 255),
@@ -51,5 +62,7 @@ pub fn tokyo_night() -> Theme {
         syntax_parameter: Color::Rgb(255, 213, 128),
         syntax_property: Color::Rgb(158, 206, 106),
         syntax_label: Color::Rgb(187, 154, 247),
+
+        syntax_modifiers: SyntaxModifiers::default(),
     }
 }
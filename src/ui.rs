@@ -1,87 +1,196 @@
-use std::io;
+use std::collections::VecDeque;
+use std::io::{self, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+        MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use ratatui::{
-    backend::CrosstermBackend,
+    backend::{CrosstermBackend, TestBackend},
     layout::{Constraint, Direction, Layout, Rect},
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Padding, Paragraph},
-    Frame, Terminal,
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame, Terminal, TerminalOptions, Viewport,
 };
 use unicode_width::UnicodeWidthStr;
 
 use crate::animation::AnimationEngine;
-use crate::git::{CommitMetadata, GitRepository};
-use crate::panes::{EditorPane, FileTreePane, StatusBarPane, TerminalPane};
+use crate::cast::{CastWriter, OutputCapture};
+use crate::git::CommitMetadata;
+use crate::panes::{
+    find_matches, ClockFormat, CommitMessagePane, EditorPane, FileTreePane, OutputMarkers,
+    Progress, StatusBarPane, TerminalPane,
+};
+use crate::prefetch::{CommitPrefetcher, Position};
+use crate::resume::PlaybackState;
+use crate::snapshot;
 use crate::theme::Theme;
-use crate::PlaybackOrder;
+use crate::{DateFormat, TerminalPosition};
+
+// Runtime speed adjustment bounds (milliseconds per character)
+const MIN_SPEED_MS: u64 = 1;
+const MAX_SPEED_MS: u64 = 1000;
+const SPEED_STEP_FACTOR: f64 = 1.25;
+
+// Editor lines scrolled per mouse wheel notch while paused
+const MOUSE_SCROLL_LINES: isize = 3;
+
+// Layout ratio bounds (percent), and the fewest cells a pane is shrunk to
+pub const MIN_LAYOUT_PCT: u16 = 10;
+pub const MAX_LAYOUT_PCT: u16 = 90;
+const MIN_PANE_CELLS: u16 = 3;
+
+// Commits kept to step back to with `p`
+const HISTORY_LEN: usize = 16;
+
+// Listed in the `?` help overlay
+const KEYBINDINGS: &[(&str, &str)] = &[
+    ("Space", "Pause or resume playback"),
+    ("+ / ]", "Type faster"),
+    ("- / [", "Type slower"),
+    ("n", "Skip to the next commit"),
+    ("p", "Go back to the previous commit"),
+    ("r", "Replay the current commit"),
+    ("Wheel", "Scroll the editor while paused"),
+    ("/", "Search the editor while paused"),
+    ("n / N", "Next or previous match while searching"),
+    ("f", "Toggle full-screen editor"),
+    ("m", "Show the full commit message (j/k to scroll)"),
+    ("?", "Show or hide this help"),
+    ("q / Esc", "Quit"),
+];
+
+/// Where each pane is drawn; panes that are not shown have no area
+struct PaneAreas {
+    file_tree: Rect,
+    left_separator: Rect,
+    status_bar: Rect,
+    editor: Rect,
+    right_separator: Option<Rect>,
+    terminal: Option<Rect>,
+}
+
+/// Windows drawn over the panes; playback pauses while one is open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Overlay {
+    Help,
+    CommitMessage,
+}
 
 #[derive(Debug, Clone, PartialEq)]
 enum UIState {
     Playing,
+    Paused,
     WaitingForNext { resume_at: Instant },
     Finished,
 }
 
-pub struct UI<'a> {
+pub struct UI {
     state: UIState,
-    speed_ms: u64,
     file_tree: FileTreePane,
     editor: EditorPane,
     terminal: TerminalPane,
     status_bar: StatusBarPane,
+    commit_message: CommitMessagePane,
     engine: AnimationEngine,
-    repo: Option<&'a GitRepository>,
+    metadata: Option<CommitMetadata>,
+    // Position of the current commit in sequential playback
+    position: Option<Position>,
+    // Source of further commits; None when only a single commit is played
+    prefetcher: Option<CommitPrefetcher>,
+    // Commits played before the current one, oldest first, and those
+    // stepped back over with `p`, next one last
+    history: VecDeque<(CommitMetadata, Option<Position>)>,
+    upcoming: Vec<(CommitMetadata, Option<Position>)>,
     should_exit: Arc<AtomicBool>,
     theme: Theme,
-    order: PlaybackOrder,
-    loop_playback: bool,
-    commit_spec: Option<String>,
-    is_range_mode: bool,
+    // Themes to pick from for each commit; empty keeps `theme` fixed
+    theme_rotation: Vec<Theme>,
+    // Index of the current theme in `theme_rotation`
+    rotation_index: Option<usize>,
+    theme_rng: StdRng,
+    max_commits: Option<usize>,
+    commits_played: usize,
+    // Saved with the position of the current commit on a clean exit
+    resume_state: Option<PlaybackState>,
+    overlay: Option<Overlay>,
+    // Give the editor the whole screen
+    editor_only: bool,
+    terminal_position: TerminalPosition,
+    // Share of the width given to the left column (file tree + commit info)
+    file_tree_width_pct: u16,
+    // Share of the height given to the editor and file tree
+    editor_height_pct: u16,
+    // Playback was paused by opening an overlay, so closing it resumes
+    resume_after_overlay: bool,
+    // Query being typed after `/` (None when not typing)
+    search_input: Option<String>,
+    // Query highlighted in the editor while paused
+    search_query: Option<String>,
+    // Line of the match last jumped to with n/N
+    search_line: Option<usize>,
+    // Redraw at least once a second so the status bar clock keeps ticking
+    show_clock: bool,
+    last_draw: Instant,
 }
 
-impl<'a> UI<'a> {
+impl UI {
     pub fn new(
-        speed_ms: u64,
-        repo: Option<&'a GitRepository>,
+        engine: AnimationEngine,
+        prefetcher: Option<CommitPrefetcher>,
         theme: Theme,
-        order: PlaybackOrder,
-        loop_playback: bool,
-        commit_spec: Option<String>,
-        is_range_mode: bool,
     ) -> Self {
         let should_exit = Arc::new(AtomicBool::new(false));
         Self::setup_signal_handler(should_exit.clone());
 
         Self {
             state: UIState::Playing,
-            speed_ms,
             file_tree: FileTreePane::new(),
-            editor: EditorPane,
-            terminal: TerminalPane,
-            status_bar: StatusBarPane,
-            engine: AnimationEngine::new(speed_ms),
-            repo,
+            editor: EditorPane::default(),
+            terminal: TerminalPane::default(),
+            status_bar: StatusBarPane::default(),
+            commit_message: CommitMessagePane::default(),
+            engine,
+            metadata: None,
+            position: None,
+            prefetcher,
+            history: VecDeque::new(),
+            upcoming: Vec::new(),
             should_exit,
             theme,
-            order,
-            loop_playback,
-            commit_spec,
-            is_range_mode,
+            theme_rotation: Vec::new(),
+            rotation_index: None,
+            theme_rng: StdRng::from_os_rng(),
+            max_commits: None,
+            commits_played: 0,
+            resume_state: None,
+            overlay: None,
+            editor_only: false,
+            terminal_position: TerminalPosition::default(),
+            file_tree_width_pct: 30,
+            editor_height_pct: 80,
+            resume_after_overlay: false,
+            search_input: None,
+            search_query: None,
+            search_line: None,
+            show_clock: false,
+            last_draw: Instant::now(),
         }
     }
 
     fn setup_signal_handler(should_exit: Arc<AtomicBool>) {
-        ctrlc::set_handler(move || {
+        let result = ctrlc::set_handler(move || {
             // Restore terminal state before exiting
             let _ = disable_raw_mode();
             let _ = execute!(
@@ -93,15 +202,159 @@ impl<'a> UI<'a> {
             should_exit.store(true, Ordering::SeqCst);
             // Exit immediately for external signals (SIGTERM)
             std::process::exit(0);
-        })
-        .expect("Error setting Ctrl-C handler");
+        });
+        // A UI created earlier in the same process already installed one
+        if let Err(err) = result {
+            if !matches!(err, ctrlc::Error::MultipleHandlers) {
+                panic!("Error setting Ctrl-C handler: {}", err);
+            }
+        }
+    }
+
+    pub fn set_terminal_history(&mut self, max_lines: usize) {
+        self.engine.set_max_terminal_lines(max_lines);
+    }
+
+    /// Words colored as success or failure in the terminal output
+    pub fn set_output_markers(&mut self, markers: OutputMarkers) {
+        self.terminal.set_markers(markers);
+    }
+
+    pub fn set_date_format(&mut self, format: DateFormat) {
+        self.status_bar.set_date_format(format);
+    }
+
+    pub fn set_clock(&mut self, format: ClockFormat) {
+        self.status_bar.set_clock(Some(format));
+        self.show_clock = true;
+    }
+
+    /// Set the pane split ratios in percent, clamped to
+    /// `MIN_LAYOUT_PCT..=MAX_LAYOUT_PCT` so no pane disappears
+    pub fn set_layout(&mut self, file_tree_width_pct: u16, editor_height_pct: u16) {
+        self.file_tree_width_pct = file_tree_width_pct.clamp(MIN_LAYOUT_PCT, MAX_LAYOUT_PCT);
+        self.editor_height_pct = editor_height_pct.clamp(MIN_LAYOUT_PCT, MAX_LAYOUT_PCT);
+    }
+
+    pub fn set_terminal_position(&mut self, position: TerminalPosition) {
+        self.terminal_position = position;
+    }
+
+    pub fn set_editor_only(&mut self, editor_only: bool) {
+        self.editor_only = editor_only;
+    }
+
+    /// Switch to a randomly chosen theme from `themes` on every commit,
+    /// seeded like commit selection for reproducible playback
+    pub fn set_theme_rotation(&mut self, themes: Vec<Theme>, seed: Option<u64>) {
+        self.theme_rotation = themes;
+        self.rotation_index = None;
+        if let Some(seed) = seed {
+            self.theme_rng = StdRng::seed_from_u64(seed);
+        }
+    }
+
+    /// Pick the next theme in rotation, never repeating the current one
+    fn rotate_theme(&mut self) {
+        let count = self.theme_rotation.len();
+        if count == 0 {
+            return;
+        }
+        let index = match self.rotation_index {
+            Some(current) if count > 1 => {
+                let index = self.theme_rng.random_range(0..count - 1);
+                if index >= current {
+                    index + 1
+                } else {
+                    index
+                }
+            }
+            Some(current) => current,
+            None => self.theme_rng.random_range(0..count),
+        };
+        self.rotation_index = Some(index);
+        self.theme = self.theme_rotation[index].clone();
+    }
+
+    /// Stop after this many commits have been played (None = unlimited)
+    pub fn set_max_commits(&mut self, max_commits: Option<usize>) {
+        self.max_commits = max_commits;
+    }
+
+    pub fn set_resume_state(&mut self, state: Option<PlaybackState>) {
+        self.resume_state = state;
+    }
+
+    pub fn load_commit(&mut self, metadata: CommitMetadata, position: Option<Position>) {
+        self.remember_current();
+        self.commits_played += 1;
+        self.show_commit(metadata, position);
     }
 
-    pub fn load_commit(&mut self, metadata: CommitMetadata) {
+    fn show_commit(&mut self, metadata: CommitMetadata, position: Option<Position>) {
         self.engine.load_commit(&metadata);
+        self.rotate_theme();
+        self.search_input = None;
+        self.set_search(None);
+        self.metadata = Some(metadata);
+        self.position = position;
         self.state = UIState::Playing;
     }
 
+    /// Keep the current commit to come back to with `p`
+    fn remember_current(&mut self) {
+        if let Some(metadata) = self.metadata.take() {
+            self.history.push_back((metadata, self.position));
+            if self.history.len() > HISTORY_LEN {
+                self.history.pop_front();
+            }
+        }
+    }
+
+    /// Play the commit before the current one again. Commits stepped back
+    /// over are played again, in order, before new ones are fetched.
+    fn previous_commit(&mut self) {
+        let Some((metadata, position)) = self.history.pop_back() else {
+            return;
+        };
+        if let Some(current) = self.metadata.take() {
+            self.upcoming.push((current, self.position));
+        }
+        self.show_commit(metadata, position);
+    }
+
+    /// Restart the current commit's animation from the time-travel intro
+    fn replay_commit(&mut self) {
+        if let Some(metadata) = &self.metadata {
+            self.engine.terminal_lines.clear();
+            self.engine.load_commit(metadata);
+            self.state = UIState::Playing;
+        }
+    }
+
+    /// Shown as `commit: 3/12` when the playback list is known (capped by
+    /// `--max-commits`), otherwise as a count of commits played so far
+    fn progress(&self) -> Option<Progress> {
+        if let Some(position) = self.position {
+            let total = match self.max_commits {
+                Some(max) => position.total.min(max),
+                None => position.total,
+            };
+            return Some(Progress::Position {
+                index: position.index,
+                total,
+            });
+        }
+        self.prefetcher
+            .is_some()
+            .then_some(Progress::Played(self.commits_played))
+    }
+
+    fn reached_commit_limit(&self) -> bool {
+        self.max_commits
+            .is_some_and(|max| self.commits_played >= max)
+    }
+
     pub fn run(&mut self) -> Result<()> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -112,10 +365,25 @@ impl<'a> UI<'a> {
         let result = self.run_loop(&mut terminal);
 
         self.cleanup(&mut terminal)?;
+        // Losing the position is not worth failing an otherwise clean exit
+        if result.is_ok() {
+            if let Err(e) = self.save_resume_state() {
+                eprintln!("Warning: could not save playback position: {:#}", e);
+            }
+        }
 
         result
     }
 
+    /// Record the commit that was showing, so `--resume` replays it next time
+    fn save_resume_state(&mut self) -> Result<()> {
+        let (Some(state), Some(position)) = (self.resume_state.as_mut(), self.position) else {
+            return Ok(());
+        };
+        state.commit_index = position.index.saturating_sub(1);
+        state.save()
+    }
+
     fn cleanup(&mut self, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
         disable_raw_mode()?;
         execute!(
@@ -127,6 +395,175 @@ impl<'a> UI<'a> {
         Ok(())
     }
 
+    /// Render one static frame of a fully played commit and wait for a key press
+    pub fn run_preview(&mut self, metadata: CommitMetadata) -> Result<()> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let result = (|| -> Result<()> {
+            let size = terminal.size()?;
+            self.update_viewport(size.width, size.height);
+            self.engine.load_commit(&metadata);
+            self.engine.fast_forward();
+
+            loop {
+                terminal.draw(|f| self.render(f))?;
+                match event::read()? {
+                    Event::Key(_) => break,
+                    Event::Resize(width, height) => self.update_viewport(width, height),
+                    _ => {}
+                }
+            }
+            Ok(())
+        })();
+
+        self.cleanup(&mut terminal)?;
+
+        result
+    }
+
+    /// Play the loaded commit headlessly and write it to `out` as an
+    /// asciinema v2 cast of the given terminal size
+    pub fn record_cast(&mut self, out: impl Write, width: u16, height: u16) -> Result<()> {
+        let capture = OutputCapture::default();
+        let backend = CrosstermBackend::new(capture.clone());
+        let mut terminal = Terminal::with_options(
+            backend,
+            TerminalOptions {
+                viewport: Viewport::Fixed(Rect::new(0, 0, width, height)),
+            },
+        )?;
+        self.update_viewport(width, height);
+
+        let mut cast = CastWriter::new(out, width, height)?;
+        let mut elapsed_ms = 0;
+        while let Some(held_ms) = self.engine.advance_frame() {
+            terminal.draw(|f| self.render(f))?;
+            cast.write_output(elapsed_ms, &capture.take())?;
+            elapsed_ms += held_ms;
+        }
+
+        // Restore the cursor once the last frame has been held for its duration
+        cast.write_output(elapsed_ms, "\x1b[?25h")?;
+        cast.finish()?;
+        Ok(())
+    }
+
+    /// Play the loaded commit headlessly and write a plain-text snapshot of
+    /// the layout every frame interval, stopping after `frames` snapshots or
+    /// when the animation ends
+    pub fn record_frames(
+        &mut self,
+        mut out: impl Write,
+        width: u16,
+        height: u16,
+        frames: usize,
+    ) -> Result<()> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height))?;
+        self.update_viewport(width, height);
+
+        let interval_ms = self.engine.frame_interval().as_millis() as u64;
+        // Simulated time at which the currently rendered state is replaced
+        let mut shown_until_ms = 0;
+        'frames: for frame in 0..frames {
+            let time_ms = frame as u64 * interval_ms;
+            while shown_until_ms <= time_ms {
+                match self.engine.advance_frame() {
+                    Some(held_ms) => shown_until_ms += held_ms.max(1),
+                    None => break 'frames,
+                }
+            }
+
+            terminal.draw(|f| self.render(f))?;
+            writeln!(out, "--- frame {} ({}ms) ---", frame, time_ms)?;
+            write!(
+                out,
+                "{}",
+                snapshot::buffer_text(terminal.backend().buffer())
+            )?;
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+
+    fn update_viewport(&mut self, width: u16, height: u16) {
+        let size = Rect::new(0, 0, width, height);
+        let editor_area = if self.editor_only {
+            size
+        } else {
+            self.pane_layout(size).editor
+        };
+        // The editor pane pads one row above and below its text
+        self.engine
+            .set_viewport_height(editor_area.height.saturating_sub(2) as usize);
+        self.engine
+            .set_content_width(EditorPane::text_width(editor_area, &self.engine));
+    }
+
+    /// Split the screen into the left column (file tree above the commit
+    /// info) and the right column (editor above the terminal), moving or
+    /// dropping the terminal according to `terminal_position`
+    fn pane_layout(&self, size: Rect) -> PaneAreas {
+        let left_width = split_length(size.width, self.file_tree_width_pct);
+        let main_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(left_width), Constraint::Fill(1)])
+            .split(size);
+        let (left, right) = (main_layout[0], main_layout[1]);
+
+        // Both columns share the same split so the separators line up
+        let top_height = split_length(size.height.saturating_sub(1), self.editor_height_pct);
+        let column = |area: Rect| {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(top_height),
+                    Constraint::Length(1), // Horizontal separator
+                    Constraint::Fill(1),
+                ])
+                .split(area);
+            (parts[0], parts[1], parts[2])
+        };
+        let (file_tree, left_separator, left_bottom) = column(left);
+
+        match self.terminal_position {
+            TerminalPosition::Bottom => {
+                let (editor, right_separator, terminal) = column(right);
+                PaneAreas {
+                    file_tree,
+                    left_separator,
+                    status_bar: left_bottom,
+                    editor,
+                    right_separator: Some(right_separator),
+                    terminal: Some(terminal),
+                }
+            }
+            TerminalPosition::Left => {
+                let (editor, right_separator, status_bar) = column(right);
+                PaneAreas {
+                    file_tree,
+                    left_separator,
+                    status_bar,
+                    editor,
+                    right_separator: Some(right_separator),
+                    terminal: Some(left_bottom),
+                }
+            }
+            TerminalPosition::Hidden => PaneAreas {
+                file_tree,
+                left_separator,
+                status_bar: left_bottom,
+                editor: right,
+                right_separator: None,
+                terminal: None,
+            },
+        }
+    }
+
     fn run_loop(&mut self, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
         loop {
             // Check for Ctrl+C signal
@@ -136,32 +573,69 @@ impl<'a> UI<'a> {
 
             // Update viewport dimensions for scroll calculation
             let size = terminal.size()?;
-            // Editor area: 70% (right column) × 80% (editor pane) = 56% of total height
-            let viewport_height = (size.height as f32 * 0.70 * 0.80) as usize;
-            // Editor width: 70% (right column)
-            let content_width = (size.width as f32 * 0.70) as usize;
-            self.engine.set_viewport_height(viewport_height);
-            self.engine.set_content_width(content_width);
+            self.update_viewport(size.width, size.height);
 
-            // Tick the animation engine
-            let needs_redraw = self.engine.tick();
+            // Tick the animation engine (frozen while paused, but keep redrawing)
+            let needs_redraw = if self.state == UIState::Paused || self.overlay.is_some() {
+                true
+            } else {
+                self.engine.tick()
+            };
 
-            if needs_redraw {
+            let clock_due = self.show_clock && self.last_draw.elapsed() >= Duration::from_secs(1);
+            if needs_redraw || clock_due {
                 terminal.draw(|f| self.render(f))?;
+                self.last_draw = Instant::now();
             }
 
             // Poll for keyboard events at frame rate
-            if event::poll(std::time::Duration::from_millis(8))? {
-                if let Event::Key(key) = event::read()? {
-                    match key.code {
-                        KeyCode::Esc | KeyCode::Char('q') => {
-                            self.state = UIState::Finished;
+            if event::poll(self.engine.frame_interval())? {
+                match event::read()? {
+                    Event::Mouse(mouse) => self.handle_mouse(mouse),
+                    Event::Key(key) => {
+                        if let Some(overlay) = self.overlay {
+                            self.handle_overlay_key(overlay, key);
+                            continue;
                         }
-                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                            self.state = UIState::Finished;
+                        if self.search_input.is_some() {
+                            self.handle_search_key(key);
+                            continue;
+                        }
+                        match key.code {
+                            KeyCode::Esc if self.search_query.is_some() => self.set_search(None),
+                            KeyCode::Char('n') if self.search_query.is_some() => {
+                                self.jump_to_match(true);
+                            }
+                            KeyCode::Char('N') if self.search_query.is_some() => {
+                                self.jump_to_match(false);
+                            }
+                            KeyCode::Char('/') if self.state == UIState::Paused => {
+                                self.search_input = Some(String::new());
+                                self.update_search_prompt();
+                            }
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                self.state = UIState::Finished;
+                            }
+                            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                self.state = UIState::Finished;
+                            }
+                            KeyCode::Char(' ') => self.toggle_pause(),
+                            KeyCode::Char('n') => self.advance_to_next_commit(),
+                            KeyCode::Char('p') => self.previous_commit(),
+                            KeyCode::Char('r') => self.replay_commit(),
+                            KeyCode::Char('?') => self.toggle_overlay(Overlay::Help),
+                            KeyCode::Char('m') => self.toggle_overlay(Overlay::CommitMessage),
+                            KeyCode::Char('f') => self.editor_only = !self.editor_only,
+                            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char(']') => {
+                                self.adjust_speed(true);
+                            }
+                            KeyCode::Char('-') | KeyCode::Char('_') | KeyCode::Char('[') => {
+                                self.adjust_speed(false);
+                            }
+                            _ => {}
                         }
-                        _ => {}
                     }
+                    _ => {}
                 }
             }
 
@@ -169,77 +643,25 @@ impl<'a> UI<'a> {
             match self.state {
                 UIState::Playing => {
                     if self.engine.is_finished() {
-                        if self.repo.is_some() {
+                        if self.prefetcher.is_some() && !self.reached_commit_limit() {
                             // Schedule next commit
                             // Wait time proportional to speed (100x the typing speed)
                             self.state = UIState::WaitingForNext {
                                 resume_at: Instant::now()
-                                    + Duration::from_millis(self.speed_ms * 100),
+                                    + Duration::from_millis(self.engine.speed_ms() * 100),
                             };
                         } else {
-                            // Single commit mode without loop - quit
+                            // Single commit mode without loop, or commit limit reached - quit
                             self.state = UIState::Finished;
                         }
                     }
                 }
                 UIState::WaitingForNext { resume_at } => {
-                    if Instant::now() >= resume_at {
-                        if let Some(repo) = self.repo {
-                            let result = if self.is_range_mode {
-                                match self.order {
-                                    PlaybackOrder::Random => repo.random_range_commit(),
-                                    PlaybackOrder::Asc => repo.next_range_commit_asc(),
-                                    PlaybackOrder::Desc => repo.next_range_commit_desc(),
-                                }
-                            } else if self.commit_spec.is_some() {
-                                repo.get_commit(self.commit_spec.as_ref().unwrap())
-                            } else {
-                                match self.order {
-                                    PlaybackOrder::Random => repo.random_commit(),
-                                    PlaybackOrder::Asc => repo.next_asc_commit(),
-                                    PlaybackOrder::Desc => repo.next_desc_commit(),
-                                }
-                            };
-                            match result {
-                                Ok(metadata) => {
-                                    self.load_commit(metadata);
-                                }
-                                Err(_) => {
-                                    if self.loop_playback {
-                                        repo.reset_index();
-                                        let restart_result = if self.is_range_mode {
-                                            match self.order {
-                                                PlaybackOrder::Random => repo.random_range_commit(),
-                                                PlaybackOrder::Asc => repo.next_range_commit_asc(),
-                                                PlaybackOrder::Desc => {
-                                                    repo.next_range_commit_desc()
-                                                }
-                                            }
-                                        } else {
-                                            match self.order {
-                                                PlaybackOrder::Random => repo.random_commit(),
-                                                PlaybackOrder::Asc => repo.next_asc_commit(),
-                                                PlaybackOrder::Desc => repo.next_desc_commit(),
-                                            }
-                                        };
-                                        match restart_result {
-                                            Ok(metadata) => {
-                                                self.load_commit(metadata);
-                                            }
-                                            Err(_) => {
-                                                self.state = UIState::Finished;
-                                            }
-                                        }
-                                    } else {
-                                        self.state = UIState::Finished;
-                                    }
-                                }
-                            }
-                        } else {
-                            self.state = UIState::Finished;
-                        }
+                    if self.overlay.is_none() && Instant::now() >= resume_at {
+                        self.advance_to_next_commit();
                     }
                 }
+                UIState::Paused => {}
                 UIState::Finished => {
                     break;
                 }
@@ -249,90 +671,217 @@ impl<'a> UI<'a> {
         Ok(())
     }
 
-    fn render(&mut self, f: &mut Frame) {
-        let size = f.area();
+    /// Abandon the current animation and load the next commit (or finish)
+    fn advance_to_next_commit(&mut self) {
+        if let Some((metadata, position)) = self.upcoming.pop() {
+            self.remember_current();
+            self.show_commit(metadata, position);
+            return;
+        }
 
-        // Split horizontally: left column | right column
-        let main_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(30), // Left column (file tree + commit info)
-                Constraint::Percentage(70), // Right column (editor + terminal)
-            ])
-            .margin(0)
-            .spacing(0)
-            .split(size);
+        let Some(prefetcher) = &self.prefetcher else {
+            self.state = UIState::Finished;
+            return;
+        };
 
-        // Split left column vertically: file tree | separator | commit info
-        let left_layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Percentage(80), // File tree
-                Constraint::Length(1),      // Horizontal separator
-                Constraint::Percentage(20), // Commit info
-            ])
-            .margin(0)
-            .spacing(0)
-            .split(main_layout[0]);
-
-        // Split right column vertically: editor | separator | terminal
-        let right_layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Percentage(80), // Editor
-                Constraint::Length(1),      // Horizontal separator
-                Constraint::Percentage(20), // Terminal
-            ])
-            .margin(0)
-            .spacing(0)
-            .split(main_layout[1]);
+        if self.reached_commit_limit() {
+            self.state = UIState::Finished;
+            return;
+        }
 
-        let separator_color = self.theme.separator;
+        match prefetcher.next() {
+            Ok((metadata, position)) => self.load_commit(metadata, position),
+            Err(_) => self.state = UIState::Finished,
+        }
+    }
 
-        // Update file tree data if needed
-        if let Some(metadata) = self.engine.current_metadata() {
-            self.file_tree.set_commit_metadata(
-                metadata,
-                self.engine.current_file_index,
-                &self.theme,
-            );
+    fn toggle_pause(&mut self) {
+        match self.state {
+            UIState::Playing => {
+                self.engine.suspend();
+                self.state = UIState::Paused;
+            }
+            UIState::Paused => {
+                self.search_input = None;
+                self.set_search(None);
+                self.engine.resume();
+                self.state = UIState::Playing;
+            }
+            _ => {}
         }
+    }
 
-        // Render file tree
-        self.file_tree.render(f, left_layout[0], &self.theme);
+    /// Edit the search query typed after `/`; Enter searches, Esc cancels
+    fn handle_search_key(&mut self, key: KeyEvent) {
+        let Some(input) = self.search_input.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.state = UIState::Finished;
+            }
+            KeyCode::Esc => self.search_input = None,
+            KeyCode::Enter => {
+                let query = self.search_input.take();
+                self.set_search(query);
+                self.jump_to_match(true);
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(ch) => input.push(ch),
+            _ => {}
+        }
+        self.update_search_prompt();
+    }
 
-        // Render horizontal separator between file tree and commit info (left column)
-        let left_sep = Paragraph::new(Line::from("─".repeat(left_layout[1].width as usize))).style(
-            Style::default()
-                .fg(separator_color)
-                .bg(self.theme.background_left),
-        );
-        f.render_widget(left_sep, left_layout[1]);
+    /// Highlight `query` in the editor, or clear the search with None
+    fn set_search(&mut self, query: Option<String>) {
+        self.search_query = query.filter(|query| !query.is_empty());
+        self.search_line = None;
+        self.editor.set_search_query(self.search_query.clone());
+        self.update_search_prompt();
+    }
 
-        // Render commit info
-        self.status_bar.render(
-            f,
-            left_layout[2],
-            self.engine.current_metadata(),
-            &self.theme,
-        );
+    fn update_search_prompt(&mut self) {
+        let prompt = match (&self.search_input, &self.search_query) {
+            (Some(input), _) => Some(format!("/{}_", input)),
+            (None, Some(query)) => {
+                let count: usize = self
+                    .engine
+                    .buffer
+                    .lines
+                    .iter()
+                    .map(|line| find_matches(line, query).len())
+                    .sum();
+                Some(format!(
+                    "/{} ({} {})",
+                    query,
+                    count,
+                    if count == 1 { "match" } else { "matches" }
+                ))
+            }
+            (None, None) => None,
+        };
+        self.status_bar.set_search_prompt(prompt);
+    }
 
-        // Render editor
-        self.editor
-            .render(f, right_layout[0], &self.engine, &self.theme);
+    /// Scroll to the next (or previous) line containing a match, wrapping
+    /// around the buffer
+    fn jump_to_match(&mut self, forward: bool) {
+        let Some(query) = &self.search_query else {
+            return;
+        };
+        let lines: Vec<usize> = self
+            .engine
+            .buffer
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !find_matches(line, query).is_empty())
+            .map(|(index, _)| index)
+            .collect();
+        let (Some(&first), Some(&last)) = (lines.first(), lines.last()) else {
+            return;
+        };
 
-        // Render horizontal separator between editor and terminal (right column)
-        let right_sep = Paragraph::new(Line::from("─".repeat(right_layout[1].width as usize)))
-            .style(
-                Style::default()
-                    .fg(separator_color)
-                    .bg(self.theme.background_right),
-            );
-        f.render_widget(right_sep, right_layout[1]);
+        let next = match (self.search_line, forward) {
+            // The first jump starts from the top of the view
+            (None, true) => {
+                let top = self.engine.buffer.display_offset();
+                lines.iter().copied().find(|&line| line >= top)
+            }
+            (None, false) => {
+                let top = self.engine.buffer.display_offset();
+                lines.iter().copied().rev().find(|&line| line < top)
+            }
+            (Some(current), true) => lines.iter().copied().find(|&line| line > current),
+            (Some(current), false) => lines.iter().copied().rev().find(|&line| line < current),
+        };
+        let line = next.unwrap_or(if forward { first } else { last });
+        self.search_line = Some(line);
+        self.engine.scroll_editor_to_line(line);
+    }
 
-        // Render terminal
-        self.terminal
-            .render(f, right_layout[2], &self.engine, &self.theme);
+    /// Scroll the editor with the mouse wheel while paused
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if self.state != UIState::Paused {
+            return;
+        }
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.engine.scroll_editor(-MOUSE_SCROLL_LINES),
+            MouseEventKind::ScrollDown => self.engine.scroll_editor(MOUSE_SCROLL_LINES),
+            _ => {}
+        }
+    }
+
+    /// Show or hide an overlay, pausing playback while it is open
+    fn toggle_overlay(&mut self, overlay: Overlay) {
+        if self.overlay.is_none() {
+            self.overlay = Some(overlay);
+            self.commit_message.reset_scroll();
+            self.resume_after_overlay = self.state == UIState::Playing;
+            if self.resume_after_overlay {
+                self.toggle_pause();
+            }
+        } else {
+            self.overlay = None;
+            if self.resume_after_overlay && self.state == UIState::Paused {
+                self.resume_after_overlay = false;
+                self.toggle_pause();
+            }
+        }
+    }
+
+    /// Keys while an overlay is open: close it, quit, or scroll the message
+    fn handle_overlay_key(&mut self, overlay: Overlay, key: KeyEvent) {
+        let message = &mut self.commit_message;
+        match (overlay, key.code) {
+            (_, KeyCode::Char('q')) => self.state = UIState::Finished,
+            (_, KeyCode::Char('c')) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.state = UIState::Finished;
+            }
+            (Overlay::Help, KeyCode::Char('?') | KeyCode::Esc)
+            | (Overlay::CommitMessage, KeyCode::Char('m') | KeyCode::Esc) => {
+                self.toggle_overlay(overlay);
+            }
+            (Overlay::CommitMessage, KeyCode::Char('j') | KeyCode::Down) => message.scroll_by(1),
+            (Overlay::CommitMessage, KeyCode::Char('k') | KeyCode::Up) => message.scroll_by(-1),
+            (Overlay::CommitMessage, KeyCode::PageDown | KeyCode::Char(' ')) => {
+                message.scroll_by(message.page());
+            }
+            (Overlay::CommitMessage, KeyCode::PageUp) => message.scroll_by(-message.page()),
+            (Overlay::CommitMessage, KeyCode::Home | KeyCode::Char('g')) => {
+                message.scroll_by(isize::MIN);
+            }
+            (Overlay::CommitMessage, KeyCode::End | KeyCode::Char('G')) => {
+                message.scroll_by(isize::MAX);
+            }
+            _ => {}
+        }
+    }
+
+    /// Speed up (shorter delay per character) or slow down playback
+    fn adjust_speed(&mut self, faster: bool) {
+        let current = self.engine.speed_ms();
+        let next = if faster {
+            (current as f64 / SPEED_STEP_FACTOR) as u64
+        } else {
+            ((current as f64 * SPEED_STEP_FACTOR) as u64).max(current + 1)
+        };
+        let next = next.clamp(MIN_SPEED_MS, MAX_SPEED_MS);
+        self.engine.set_speed(next);
+    }
+
+    fn render(&mut self, f: &mut Frame) {
+        let size = f.area();
+
+        if self.editor_only {
+            // The other panes keep following the animation but are not drawn
+            self.render_editor(f, size);
+        } else {
+            self.render_panes(f, size);
+        }
 
         // Render dialog if present
         if let Some(ref title) = self.engine.dialog_title {
@@ -380,5 +929,234 @@ impl<'a> UI<'a> {
             let dialog = Paragraph::new(dialog_text).block(block);
             f.render_widget(dialog, dialog_area);
         }
+
+        match self.overlay {
+            Some(Overlay::Help) => self.render_help(f, size),
+            Some(Overlay::CommitMessage) => {
+                self.commit_message
+                    .render(f, size, self.engine.current_metadata(), &self.theme);
+            }
+            None => {}
+        }
+    }
+
+    /// Draw the editor, first telling the engine how wide its text area is;
+    /// that changes with the line count and the minimap, not just on resize
+    fn render_editor(&mut self, f: &mut Frame, area: Rect) {
+        self.engine
+            .set_content_width(EditorPane::text_width(area, &self.engine));
+        self.editor.render(f, area, &self.engine, &self.theme);
+    }
+
+    fn render_panes(&mut self, f: &mut Frame, size: Rect) {
+        let areas = self.pane_layout(size);
+        let separator_color = self.theme.separator;
+        let separator = |area: Rect, bg| {
+            Paragraph::new(Line::from("─".repeat(area.width as usize)))
+                .style(Style::default().fg(separator_color).bg(bg))
+        };
+
+        // Update file tree data if needed
+        if let Some(metadata) = self.engine.current_metadata() {
+            self.file_tree.set_commit_metadata(
+                metadata,
+                self.engine.current_file_index,
+                self.engine.file_order(),
+                self.engine.max_files(),
+                &self.theme,
+            );
+        }
+
+        self.file_tree.render(f, areas.file_tree, &self.theme);
+        f.render_widget(
+            separator(areas.left_separator, self.theme.background_left),
+            areas.left_separator,
+        );
+        self.render_editor(f, areas.editor);
+        if let Some(area) = areas.right_separator {
+            f.render_widget(separator(area, self.theme.background_right), area);
+        }
+
+        self.status_bar.render(
+            f,
+            areas.status_bar,
+            &self.engine,
+            self.progress(),
+            self.state == UIState::Paused,
+            &self.theme,
+        );
+        if let Some(area) = areas.terminal {
+            self.terminal.render(f, area, &self.engine, &self.theme);
+        }
+    }
+
+    fn render_help(&self, f: &mut Frame, size: Rect) {
+        let key_width = KEYBINDINGS
+            .iter()
+            .map(|(key, _)| key.width())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<Line> = KEYBINDINGS
+            .iter()
+            .map(|(key, description)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}  ", key, width = key_width),
+                        Style::default()
+                            .fg(self.theme.file_tree_directory)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        *description,
+                        Style::default().fg(self.theme.file_tree_current_file_fg),
+                    ),
+                ])
+            })
+            .collect();
+
+        // Content plus borders (2) and padding (2 horizontal, 1 vertical each side)
+        let content_width = lines.iter().map(Line::width).max().unwrap_or(0);
+        let width = ((content_width + 6) as u16).min(size.width);
+        let height = ((lines.len() + 4) as u16).min(size.height);
+        let area = Rect {
+            x: size.x + (size.width - width) / 2,
+            y: size.y + (size.height - height) / 2,
+            width,
+            height,
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Keyboard shortcuts ")
+            .padding(Padding::new(2, 2, 1, 1))
+            .style(
+                Style::default()
+                    .fg(self.theme.file_tree_current_file_fg)
+                    .bg(self.theme.editor_cursor_line_bg),
+            );
+
+        f.render_widget(Clear, area);
+        f.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// The first part of `total` cells split at `pct` percent, leaving at least
+/// `MIN_PANE_CELLS` on each side when there is room for that
+fn split_length(total: u16, pct: u16) -> u16 {
+    let length = (total as u32 * pct as u32 / 100) as u16;
+    if total < MIN_PANE_CELLS * 2 {
+        return length;
+    }
+    length.clamp(MIN_PANE_CELLS, total - MIN_PANE_CELLS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_length_keeps_panes_visible() {
+        assert_eq!(split_length(100, 30), 30);
+        assert_eq!(split_length(100, 80), 80);
+        // Small areas still leave a few cells on each side
+        assert_eq!(split_length(10, 10), MIN_PANE_CELLS);
+        assert_eq!(split_length(10, 90), 10 - MIN_PANE_CELLS);
+        assert_eq!(split_length(4, 50), 2);
+    }
+
+    #[test]
+    fn test_search_jumps_between_matching_lines() {
+        let mut ui = UI::new(AnimationEngine::new(0), None, Theme::default());
+        ui.engine.set_viewport_height(4);
+        let content: String = (0..30)
+            .map(|i| match i {
+                5 => "let needle = 1;\n".to_string(),
+                20 => "// NEEDLE again\n".to_string(),
+                _ => format!("line {}\n", i),
+            })
+            .collect();
+        ui.engine.buffer = crate::animation::EditorBuffer::from_content(&content);
+        ui.state = UIState::Paused;
+
+        ui.search_input = Some(String::new());
+        for ch in "Needle".chars() {
+            ui.handle_search_key(KeyEvent::from(KeyCode::Char(ch)));
+        }
+        ui.handle_search_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(ui.search_query.as_deref(), Some("Needle"));
+        assert_eq!(ui.search_line, Some(5));
+        assert_eq!(ui.engine.buffer.scroll_offset, 3);
+
+        ui.jump_to_match(true);
+        assert_eq!(ui.search_line, Some(20));
+        ui.jump_to_match(true);
+        assert_eq!(ui.search_line, Some(5));
+        ui.jump_to_match(false);
+        assert_eq!(ui.search_line, Some(20));
+
+        ui.toggle_pause();
+        assert_eq!(ui.search_query, None);
+    }
+
+    #[test]
+    fn test_steps_back_through_played_commits() {
+        let commit = |subject: &str| {
+            let mut metadata = crate::preview::sample_commit();
+            metadata.message = subject.to_string();
+            metadata
+        };
+        let subject = |ui: &UI| ui.metadata.as_ref().unwrap().message.clone();
+
+        let mut ui = UI::new(AnimationEngine::new(0), None, Theme::default());
+        ui.previous_commit();
+        assert!(ui.metadata.is_none());
+        for name in ["first", "second", "third"] {
+            ui.load_commit(commit(name), None);
+        }
+
+        ui.previous_commit();
+        ui.previous_commit();
+        assert_eq!(subject(&ui), "first");
+        ui.previous_commit();
+        assert_eq!(subject(&ui), "first");
+
+        ui.advance_to_next_commit();
+        assert_eq!(subject(&ui), "second");
+        ui.advance_to_next_commit();
+        assert_eq!(subject(&ui), "third");
+        assert_eq!(ui.commits_played, 3);
+        // Nothing left to step forward to, and no more commits to fetch
+        ui.advance_to_next_commit();
+        assert_eq!(ui.state, UIState::Finished);
+    }
+
+    #[test]
+    fn test_renders_while_terminal_shrinks() {
+        let mut ui = UI::new(AnimationEngine::new(0), None, Theme::default());
+        ui.load_commit(crate::preview::sample_commit(), None);
+        for _ in 0..200 {
+            ui.engine.advance_frame();
+        }
+
+        for (position, editor_only) in [
+            (TerminalPosition::Bottom, false),
+            (TerminalPosition::Left, false),
+            (TerminalPosition::Hidden, true),
+        ] {
+            ui.set_terminal_position(position);
+            ui.set_editor_only(editor_only);
+            for overlay in [None, Some(Overlay::Help), Some(Overlay::CommitMessage)] {
+                ui.overlay = overlay;
+                for size in (0..=40).rev() {
+                    let (width, height) = (size * 3, size);
+                    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+                    ui.update_viewport(width, height);
+                    ui.engine.advance_frame();
+                    terminal.draw(|f| ui.render(f)).unwrap();
+                    let buffer = &ui.engine.buffer;
+                    assert!(buffer.display_offset() < buffer.lines.len().max(1));
+                }
+            }
+        }
     }
 }
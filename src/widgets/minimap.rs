@@ -0,0 +1,142 @@
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+
+use crate::animation::LineOrigin;
+
+const MINIMAP_SYMBOL: &str = "▐";
+
+/// A one-column overview of a whole file: each row stands for a slice of
+/// lines, colored by whether any of them were added or changed, with the
+/// rows covering the visible window highlighted
+pub struct Minimap<'a> {
+    origins: &'a [LineOrigin],
+    window_start: usize,
+    window_len: usize,
+    style: Style,
+    window_style: Style,
+    added_style: Style,
+    modified_style: Style,
+}
+
+impl<'a> Minimap<'a> {
+    pub fn new(origins: &'a [LineOrigin]) -> Self {
+        Self {
+            origins,
+            window_start: 0,
+            window_len: 0,
+            style: Style::default(),
+            window_style: Style::default(),
+            added_style: Style::default(),
+            modified_style: Style::default(),
+        }
+    }
+
+    /// The range of lines currently shown in the editor
+    pub fn window(mut self, start: usize, len: usize) -> Self {
+        self.window_start = start;
+        self.window_len = len;
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Patched over the rows inside the visible window
+    pub fn window_style(mut self, style: Style) -> Self {
+        self.window_style = style;
+        self
+    }
+
+    pub fn added_style(mut self, style: Style) -> Self {
+        self.added_style = style;
+        self
+    }
+
+    pub fn modified_style(mut self, style: Style) -> Self {
+        self.modified_style = style;
+        self
+    }
+
+    /// Lines summarized by `row` out of `height` rows
+    fn row_lines(&self, row: usize, height: usize) -> std::ops::Range<usize> {
+        let total = self.origins.len();
+        let start = row * total / height;
+        let end = ((row + 1) * total / height).max(start + 1).min(total);
+        start..end
+    }
+}
+
+impl Widget for Minimap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() || self.origins.is_empty() {
+            return;
+        }
+
+        let height = area.height as usize;
+        let window_end = self.window_start + self.window_len;
+        for row in 0..height {
+            let lines = self.row_lines(row, height);
+            if lines.is_empty() {
+                continue;
+            }
+
+            let origins = &self.origins[lines.clone()];
+            let mut style = self.style;
+            if origins.contains(&LineOrigin::Added) {
+                style = style.patch(self.added_style);
+            } else if origins.contains(&LineOrigin::Modified) {
+                style = style.patch(self.modified_style);
+            }
+            if lines.start < window_end && lines.end > self.window_start {
+                style = style.patch(self.window_style);
+            }
+
+            let y = area.y + row as u16;
+            for x in area.left()..area.right() {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_symbol(MINIMAP_SYMBOL).set_style(style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_summarizes_lines_and_highlights_window() {
+        let mut origins = vec![LineOrigin::Unchanged; 20];
+        origins[15] = LineOrigin::Added;
+        origins[3] = LineOrigin::Modified;
+
+        let area = Rect::new(0, 0, 1, 5);
+        let mut buf = Buffer::empty(area);
+        Minimap::new(&origins)
+            .window(4, 4)
+            .style(Style::default().fg(Color::Gray))
+            .window_style(Style::default().bg(Color::Blue))
+            .added_style(Style::default().fg(Color::Green))
+            .modified_style(Style::default().fg(Color::Yellow))
+            .render(area, &mut buf);
+
+        let fg: Vec<Color> = (0..5).map(|y| buf[(0, y)].fg).collect();
+        assert_eq!(
+            fg,
+            [
+                Color::Yellow,
+                Color::Gray,
+                Color::Gray,
+                Color::Green,
+                Color::Gray
+            ]
+        );
+        let in_window: Vec<bool> = (0..5).map(|y| buf[(0, y)].bg == Color::Blue).collect();
+        assert_eq!(in_window, [false, true, false, false, false]);
+        assert_eq!(buf[(0, 0)].symbol(), MINIMAP_SYMBOL);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod minimap;
 pub mod selectable_paragraph;
 
-pub use selectable_paragraph::SelectableParagraph;
+pub use minimap::Minimap;
+pub use selectable_paragraph::{SelectableParagraph, WrapMode};
@@ -2,11 +2,22 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Style},
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, Padding, Widget},
 };
 use unicode_width::UnicodeWidthStr;
 
+/// How lines longer than the available width are broken
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrapMode {
+    /// Break at the last character that fits
+    #[default]
+    Char,
+    /// Break after the last whitespace that fits, falling back to a
+    /// character break for words longer than the line
+    Word,
+}
+
 /// A paragraph widget that wraps at character boundaries and supports line selection
 pub struct SelectableParagraph<'a> {
     lines: Vec<Line<'a>>,
@@ -17,6 +28,8 @@ pub struct SelectableParagraph<'a> {
     padding: Padding,
     dim_max_distance: Option<usize>,
     dim_min_opacity: f32,
+    wrap_mode: WrapMode,
+    scroll: usize,
 }
 
 impl<'a> SelectableParagraph<'a> {
@@ -30,6 +43,8 @@ impl<'a> SelectableParagraph<'a> {
             padding: Padding::ZERO,
             dim_max_distance: None,
             dim_min_opacity: 0.6,
+            wrap_mode: WrapMode::Char,
+            scroll: 0,
         }
     }
 
@@ -64,16 +79,55 @@ impl<'a> SelectableParagraph<'a> {
         self
     }
 
+    pub fn wrap_mode(mut self, wrap_mode: WrapMode) -> Self {
+        self.wrap_mode = wrap_mode;
+        self
+    }
+
+    /// First display line shown when no line is selected; clamped so the
+    /// last line stays at the bottom
+    pub fn scroll(mut self, offset: usize) -> Self {
+        self.scroll = offset;
+        self
+    }
+
+    /// Display lines the text wraps to in `width` columns, not counting the
+    /// block or vertical padding
+    pub fn line_count(&self, width: u16) -> usize {
+        let first_line_width = width.saturating_sub(self.padding.left) as usize;
+        self.lines
+            .iter()
+            .map(|line| self.wrap(line, first_line_width, width as usize).len())
+            .sum()
+    }
+
+    fn wrap(
+        &self,
+        line: &Line<'a>,
+        first_line_width: usize,
+        continuation_width: usize,
+    ) -> Vec<Line<'a>> {
+        match self.wrap_mode {
+            WrapMode::Char => Self::wrap_line(line, first_line_width, continuation_width),
+            WrapMode::Word => Self::wrap_line_words(line, first_line_width, continuation_width),
+        }
+    }
+
     fn apply_opacity(&self, foreground: Color, opacity: f32, background: Color) -> Color {
-        match (foreground, background) {
-            (Color::Rgb(fr, fg, fb), Color::Rgb(br, bg, bb)) => {
-                // Blend foreground and background: result = fg * opacity + bg * (1 - opacity)
-                let r = (fr as f32 * opacity + br as f32 * (1.0 - opacity)) as u8;
-                let g = (fg as f32 * opacity + bg as f32 * (1.0 - opacity)) as u8;
-                let b = (fb as f32 * opacity + bb as f32 * (1.0 - opacity)) as u8;
-                Color::Rgb(r, g, b)
-            }
-            _ => foreground, // For non-RGB colors, return as-is
+        let (Some((fr, fg, fb)), Some((br, bg, bb))) = (to_rgb(foreground), to_rgb(background))
+        else {
+            return foreground; // Reset/default colors have no known RGB value
+        };
+
+        // Blend foreground and background: result = fg * opacity + bg * (1 - opacity)
+        let r = (fr as f32 * opacity + br as f32 * (1.0 - opacity)) as u8;
+        let g = (fg as f32 * opacity + bg as f32 * (1.0 - opacity)) as u8;
+        let b = (fb as f32 * opacity + bb as f32 * (1.0 - opacity)) as u8;
+
+        match foreground {
+            Color::Rgb(..) => Color::Rgb(r, g, b),
+            // Stay within the palette for terminals without truecolor support
+            _ => Color::Indexed(nearest_indexed(r, g, b)),
         }
     }
 
@@ -176,10 +230,84 @@ impl<'a> SelectableParagraph<'a> {
             wrapped_lines
         }
     }
+
+    fn wrap_line_words(
+        line: &Line<'a>,
+        first_line_width: usize,
+        continuation_width: usize,
+    ) -> Vec<Line<'a>> {
+        if first_line_width == 0 {
+            return vec![line.clone()];
+        }
+
+        // Flatten spans into styled characters so breaks can backtrack across spans
+        let cells: Vec<(char, Style, usize)> = line
+            .spans
+            .iter()
+            .flat_map(|span| {
+                span.content.chars().map(move |ch| {
+                    let width = UnicodeWidthStr::width(ch.to_string().as_str());
+                    (ch, span.style, width)
+                })
+            })
+            .collect();
+
+        let mut ranges = Vec::new();
+        let mut line_start = 0;
+        let mut current_width = 0;
+        let mut last_space = None;
+
+        for (idx, &(ch, _, ch_width)) in cells.iter().enumerate() {
+            let max_width = if ranges.is_empty() {
+                first_line_width
+            } else {
+                continuation_width
+            };
+
+            if current_width + ch_width > max_width && current_width > 0 {
+                // Break after the last whitespace if there is one, otherwise mid-word
+                let break_at = last_space.map_or(idx, |space| space + 1);
+                ranges.push(line_start..break_at);
+                line_start = break_at;
+                current_width = cells[break_at..idx].iter().map(|cell| cell.2).sum();
+                last_space = None;
+            }
+
+            if ch.is_whitespace() {
+                last_space = Some(idx);
+            }
+            current_width += ch_width;
+        }
+
+        if line_start < cells.len() {
+            ranges.push(line_start..cells.len());
+        }
+
+        if ranges.is_empty() {
+            return vec![Line::from(vec![])];
+        }
+
+        ranges
+            .into_iter()
+            .map(|range| {
+                // Regroup consecutive characters with the same style into spans
+                let mut spans: Vec<Span<'a>> = Vec::new();
+                for &(ch, style, _) in &cells[range] {
+                    match spans.last_mut() {
+                        Some(span) if span.style == style => span.content.to_mut().push(ch),
+                        _ => spans.push(Span::styled(ch.to_string(), style)),
+                    }
+                }
+                Line::from(spans)
+            })
+            .collect()
+    }
 }
 
 impl<'a> Widget for SelectableParagraph<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        // Never write outside the buffer, even if handed a larger area
+        let area = area.intersection(buf.area);
         let area = match self.block {
             Some(ref block) => {
                 let inner = block.inner(area);
@@ -220,7 +348,7 @@ impl<'a> Widget for SelectableParagraph<'a> {
         // Also track if this is the first wrapped line and if wrapping occurred
         let mut wrapped_lines_with_indices = Vec::new();
         for (original_idx, line) in self.lines.iter().enumerate() {
-            let wrapped = Self::wrap_line(line, first_line_width, continuation_width);
+            let wrapped = self.wrap(line, first_line_width, continuation_width);
             let has_wrap = wrapped.len() > 1;
             for (wrap_idx, wrapped_line) in wrapped.into_iter().enumerate() {
                 let is_first_wrap = wrap_idx == 0;
@@ -254,7 +382,8 @@ impl<'a> Widget for SelectableParagraph<'a> {
                 offset.min(max_offset)
             }
         } else {
-            0
+            let max_offset = wrapped_lines_with_indices.len().saturating_sub(height);
+            self.scroll.min(max_offset)
         };
 
         let visible_lines: Vec<_> = wrapped_lines_with_indices
@@ -267,18 +396,23 @@ impl<'a> Widget for SelectableParagraph<'a> {
         for (y, (original_idx, line, is_first_wrap, has_wrap)) in visible_lines.iter().enumerate() {
             let is_selected = self.selected_line == Some(*original_idx);
             let dim_opacity = self.calculate_dim_opacity(*original_idx);
+            // A line's own background (Line::style) replaces the default one
+            let line_bg = self.lines[*original_idx].style.bg;
 
             let bg_color = if is_selected {
                 self.selected_style.bg
             } else {
-                self.background_style.bg
+                line_bg.or(self.background_style.bg)
             }
             .unwrap_or(Color::Reset);
 
             let fill_style = if is_selected {
                 self.selected_style
             } else {
-                self.background_style
+                match line_bg {
+                    Some(bg) => self.background_style.bg(bg),
+                    None => self.background_style,
+                }
             };
 
             if y >= height {
@@ -313,6 +447,8 @@ impl<'a> Widget for SelectableParagraph<'a> {
                         if span.style.fg.is_none() {
                             style.fg = self.selected_style.fg;
                         }
+                    } else if span.style.bg.is_none() {
+                        style.bg = line_bg;
                     }
 
                     // Apply dim to foreground color
@@ -374,6 +510,8 @@ impl<'a> Widget for SelectableParagraph<'a> {
                         if span.style.fg.is_none() {
                             style.fg = self.selected_style.fg;
                         }
+                    } else if span.style.bg.is_none() {
+                        style.bg = line_bg;
                     }
 
                     // Apply dim to foreground color
@@ -415,6 +553,8 @@ impl<'a> Widget for SelectableParagraph<'a> {
                         if span.style.fg.is_none() {
                             style.fg = self.selected_style.fg;
                         }
+                    } else if span.style.bg.is_none() {
+                        style.bg = line_bg;
                     }
 
                     // Apply dim to foreground color
@@ -442,3 +582,243 @@ impl<'a> Widget for SelectableParagraph<'a> {
         }
     }
 }
+
+/// Approximate RGB values of the 16 ANSI colors (xterm defaults)
+const ANSI_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Channel levels of the 6x6x6 color cube (indices 16-231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Resolve a color to an approximate RGB value, if it has one
+fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let index = match color {
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Reset => return None,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        Color::Indexed(i) => i,
+    };
+
+    Some(match index {
+        0..=15 => ANSI_RGB[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[((i / 6) % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    })
+}
+
+/// Find the closest color in the 256-color palette, skipping the
+/// user-configurable ANSI colors (0-15)
+fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        let dr = cr as i32 - r as i32;
+        let dg = cg as i32 - g as i32;
+        let db = cb as i32 - b as i32;
+        dr * dr + dg * dg + db * db
+    };
+
+    (16..=255u8)
+        .min_by_key(|&i| to_rgb(Color::Indexed(i)).map_or(i32::MAX, distance))
+        .unwrap_or(16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dim(foreground: Color, background: Color) -> Color {
+        SelectableParagraph::new(Vec::new()).apply_opacity(foreground, 0.3, background)
+    }
+
+    fn line_texts(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_word_wrap_breaks_at_whitespace() {
+        let line = Line::from("hello brave new world");
+        let wrapped = SelectableParagraph::wrap_line_words(&line, 12, 12);
+        assert_eq!(line_texts(&wrapped), ["hello brave ", "new world"]);
+    }
+
+    #[test]
+    fn test_word_wrap_falls_back_to_char_break() {
+        let line = Line::from("abcdefghij xy");
+        let wrapped = SelectableParagraph::wrap_line_words(&line, 4, 4);
+        assert_eq!(line_texts(&wrapped), ["abcd", "efgh", "ij ", "xy"]);
+    }
+
+    #[test]
+    fn test_word_wrap_keeps_span_styles() {
+        let bold = Style::default().add_modifier(ratatui::style::Modifier::BOLD);
+        let line = Line::from(vec![Span::raw("one "), Span::styled("two three", bold)]);
+        let wrapped = SelectableParagraph::wrap_line_words(&line, 8, 8);
+        assert_eq!(line_texts(&wrapped), ["one two ", "three"]);
+        assert_eq!(wrapped[0].spans[1].style, bold);
+        assert_eq!(wrapped[1].spans[0].style, bold);
+    }
+
+    #[test]
+    fn test_dims_indexed_colors() {
+        let dimmed = dim(Color::Indexed(196), Color::Indexed(16));
+        assert_ne!(dimmed, Color::Indexed(196));
+        assert!(matches!(dimmed, Color::Indexed(_)));
+    }
+
+    #[test]
+    fn test_dims_named_colors() {
+        let named = [
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Magenta,
+            Color::Cyan,
+            Color::Gray,
+            Color::DarkGray,
+            Color::LightRed,
+            Color::LightGreen,
+            Color::LightYellow,
+            Color::LightBlue,
+            Color::LightMagenta,
+            Color::LightCyan,
+            Color::White,
+        ];
+        for color in named {
+            let dimmed = dim(color, Color::Black);
+            assert!(
+                matches!(dimmed, Color::Indexed(_)),
+                "{color:?} was not dimmed: {dimmed:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset_background_leaves_color_unchanged() {
+        assert_eq!(dim(Color::Red, Color::Reset), Color::Red);
+    }
+
+    #[test]
+    fn test_line_background_fills_row_under_span_colors() {
+        let tint = Color::Rgb(0, 40, 0);
+        let lines = vec![
+            Line::from(Span::styled(
+                "ab",
+                Style::default().fg(Color::Rgb(200, 0, 0)),
+            ))
+            .style(Style::default().bg(tint)),
+            Line::from("cd"),
+        ];
+        let paragraph = SelectableParagraph::new(lines)
+            .background_style(Style::default().bg(Color::Black))
+            .padding(Padding::horizontal(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        paragraph.render(buf.area, &mut buf);
+
+        assert!((0..6).all(|x| buf[(x, 0)].bg == tint));
+        assert_eq!(buf[(1, 0)].fg, Color::Rgb(200, 0, 0));
+        assert!([0, 3, 4, 5].iter().all(|&x| buf[(x, 1)].bg == Color::Black));
+    }
+
+    #[test]
+    fn test_renders_into_tiny_buffers_with_padding() {
+        let lines = vec![
+            Line::from("a long line that has to wrap several times"),
+            Line::from("short"),
+        ];
+        for area in [
+            Rect::new(0, 0, 1, 1),
+            Rect::new(0, 0, 2, 1),
+            Rect::new(3, 2, 2, 1),
+        ] {
+            for wrap_mode in [WrapMode::Char, WrapMode::Word] {
+                let paragraph = SelectableParagraph::new(lines.clone())
+                    .block(Block::default().padding(Padding::vertical(1)))
+                    .padding(Padding::new(1, 2, 1, 0))
+                    .selected_line(Some(0))
+                    .wrap_mode(wrap_mode)
+                    .dim(5, 0.5);
+                let mut buf = Buffer::empty(area);
+                paragraph.render(area, &mut buf);
+
+                // An area larger than the buffer is clipped to it
+                let paragraph = SelectableParagraph::new(lines.clone())
+                    .padding(Padding::new(1, 2, 0, 0))
+                    .wrap_mode(wrap_mode);
+                let mut buf = Buffer::empty(area);
+                paragraph.render(Rect::new(0, 0, 10, 4), &mut buf);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scrolls_without_selection() {
+        let lines: Vec<Line> = ["one", "two", "three four five", "six"]
+            .into_iter()
+            .map(Line::from)
+            .collect();
+        let paragraph = || SelectableParagraph::new(lines.clone()).wrap_mode(WrapMode::Word);
+        assert_eq!(paragraph().line_count(10), 5);
+
+        let area = Rect::new(0, 0, 10, 2);
+        let rows = |offset| {
+            let mut buf = Buffer::empty(area);
+            paragraph().scroll(offset).render(area, &mut buf);
+            (0..2)
+                .map(|y| (0..10).map(|x| buf[(x, y)].symbol()).collect::<String>())
+                .map(|row| row.trim_end().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(rows(1), ["two", "three"]);
+        // Scrolling past the end keeps the last lines at the bottom
+        assert_eq!(rows(10), ["four five", "six"]);
+    }
+
+    #[test]
+    fn test_rgb_blend_stays_rgb() {
+        assert_eq!(
+            dim(Color::Rgb(200, 100, 0), Color::Rgb(0, 0, 0)),
+            Color::Rgb(60, 30, 0)
+        );
+    }
+}